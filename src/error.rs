@@ -70,10 +70,14 @@ pub enum PdfiumError {
     PageObjectIndexOutOfBounds,
     PageObjectNotAttachedToPage,
     PageObjectAlreadyAttachedToDifferentPage,
+    PageObjectMarkIndexOutOfBounds,
     PageAnnotationIndexOutOfBounds,
     PageObjectNotAttachedToAnnotation,
     FormFieldOptionIndexOutOfBounds,
+    FormFieldOptionNotFound,
     FormFieldAppearanceStreamUndefined,
+    NoFormInDocument,
+    DocumentDataNotAvailable,
     PageFlattenFailure,
     PageMissingEmbeddedThumbnail,
     UnknownPdfPageObjectType,
@@ -93,7 +97,13 @@ pub enum PdfiumError {
     UnknownPdfColorSpace,
     InvalidTransformationMatrix,
     SignatureIndexOutOfBounds,
+    JavaScriptActionIndexOutOfBounds,
+    WebLinkIndexOutOfBounds,
     AttachmentIndexOutOfBounds,
+    NamedDestinationIndexOutOfBounds,
+    NamedDestinationUnresolvable,
+    RevisionIndexOutOfBounds,
+    NoRevisionsInDocument,
     NoDataInAttachment,
     FontGlyphIndexOutOfBounds,
     UnknownPathSegmentType,
@@ -112,7 +122,14 @@ pub enum PdfiumError {
     DestinationPageLocationNotAvailable,
     PageAnnotationAttachmentPointIndexOutOfBounds,
     NoAttachmentPointsInPageAnnotation,
+    PageAnnotationInkStrokeIndexOutOfBounds,
+    NoInkStrokesInPageAnnotation,
+    PageAnnotationBorderDashPatternInvalid,
+    PageAnnotationCloudyBorderIntensityOutOfRange,
+    PageAnnotationCalloutLineInvalid,
+    PageAnnotationLinkedAnnotationNotOnPage,
     CoordinateConversionFunctionIndicatedError,
+    StructElementIndexOutOfBounds,
 
     /// A call to `FPDFDest_GetView()` returned a valid `FPDFDEST_VIEW_*` value, but the number
     /// of view parameters returned does not match the PDF specification.
@@ -130,9 +147,42 @@ pub enum PdfiumError {
     /// a hexadecimal string in `PdfColor::from_hex()`.
     ParseHexadecimalColorMissingLeadingHash,
 
+    /// The data given to `PdfFormDataFormat::parse()` could not be parsed as form field data
+    /// in the expected format. The wrapped string value contains more information.
+    ParseFormDataError(String),
+
+    /// Document data written by Pdfium could not be parsed while reading or writing
+    /// a dictionary entry that Pdfium does not provide access to. The wrapped string value
+    /// contains more information.
+    ParseDocumentDataError(String),
+
+    /// A `PdfSignature` could not be verified, because the signature could not be parsed or
+    /// uses an unsupported format or algorithm. The wrapped string value contains more information.
+    #[cfg(feature = "verify")]
+    SignatureVerificationError(String),
+
     /// An error occurred converting a byte stream into a `CString`.
     CStringConversionError(IntoStringError),
 
+    /// A `PdfSaveOptions` object requested both an incremental save and the removal of
+    /// document security. Removing security requires the document to be rewritten in full.
+    IncrementalSaveCannotRemoveSecurity,
+
+    /// A `PdfSaveOptions` object requested both a `PdfSaveMode::RewriteWithoutOriginal` save
+    /// and the removal of document security. Pdfium always reuses the original file data of
+    /// unchanged objects when removing security, so the two cannot be combined.
+    RewriteWithoutOriginalCannotRemoveSecurity,
+
+    /// The file data of a `PdfRevision` could not be retrieved, because Pdfium did not save
+    /// the containing document incrementally.
+    RevisionDataNotAvailable,
+
+    /// A form XObject overlaps an area covered by a redaction annotation. Pdfium does not
+    /// provide a way to change the content of a form XObject, and removing a form XObject
+    /// from a page does not remove its content from the document, so the redaction cannot
+    /// be applied.
+    RedactionOfFormObjectNotSupported,
+
     /// Two data buffers are expected to have the same size, but they do not.
     DataBufferLengthMismatch,
 
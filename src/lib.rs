@@ -92,15 +92,27 @@ pub mod prelude {
         pdf::document::bookmark::*,
         pdf::document::bookmarks::*,
         pdf::document::fonts::*,
+        pdf::document::form::data::*,
         pdf::document::form::*,
+        pdf::document::javascript_action::*,
+        pdf::document::javascript_actions::*,
         pdf::document::metadata::*,
+        pdf::document::named_destination::*,
+        pdf::document::named_destinations::*,
         pdf::document::page::annotation::attachment_points::*,
+        pdf::document::page::annotation::border::*,
+        pdf::document::page::annotation::caret::*,
         pdf::document::page::annotation::circle::*,
+        pdf::document::page::annotation::file_attachment::*,
         pdf::document::page::annotation::free_text::*,
         pdf::document::page::annotation::highlight::*,
         pdf::document::page::annotation::ink::*,
+        pdf::document::page::annotation::ink_strokes::*,
+        pdf::document::page::annotation::line::*,
         pdf::document::page::annotation::link::*,
         pdf::document::page::annotation::objects::*,
+        pdf::document::page::annotation::polygon::*,
+        pdf::document::page::annotation::polyline::*,
         pdf::document::page::annotation::popup::*,
         pdf::document::page::annotation::redacted::*,
         pdf::document::page::annotation::square::*,
@@ -113,7 +125,8 @@ pub mod prelude {
         pdf::document::page::annotation::widget::*,
         pdf::document::page::annotation::xfa_widget::*,
         pdf::document::page::annotation::{
-            PdfPageAnnotation, PdfPageAnnotationCommon, PdfPageAnnotationType,
+            PdfPageAnnotation, PdfPageAnnotationCommon, PdfPageAnnotationFlags,
+            PdfPageAnnotationType,
         },
         pdf::document::page::annotations::*,
         pdf::document::page::boundaries::*,
@@ -127,10 +140,15 @@ pub mod prelude {
         pdf::document::page::field::signature::*,
         pdf::document::page::field::text::*,
         pdf::document::page::field::unknown::*,
-        pdf::document::page::field::{PdfFormField, PdfFormFieldCommon, PdfFormFieldType},
+        pdf::document::page::field::{
+            PdfFormField, PdfFormFieldAdditionalActionEvent, PdfFormFieldCommon, PdfFormFieldType,
+        },
+        pdf::document::page::form_session::*,
         pdf::document::page::links::*,
         pdf::document::page::object::group::*,
         pdf::document::page::object::image::*,
+        pdf::document::page::object::mark::*,
+        pdf::document::page::object::marks::*,
         pdf::document::page::object::path::*,
         pdf::document::page::object::shading::*,
         pdf::document::page::object::text::*,
@@ -143,12 +161,18 @@ pub mod prelude {
         pdf::document::page::objects::common::*,
         pdf::document::page::objects::*,
         pdf::document::page::render_config::*,
+        pdf::document::page::render_progressive::*,
         pdf::document::page::size::*,
+        pdf::document::page::struct_tree::attribute::*,
+        pdf::document::page::struct_tree::element::*,
+        pdf::document::page::struct_tree::*,
         pdf::document::page::text::char::*,
         pdf::document::page::text::chars::*,
         pdf::document::page::text::search::*,
         pdf::document::page::text::segment::*,
         pdf::document::page::text::segments::*,
+        pdf::document::page::text::web_link::*,
+        pdf::document::page::text::web_links::*,
         pdf::document::page::text::*,
         pdf::document::page::{
             PdfBitmapRotation, PdfPage, PdfPageContentRegenerationStrategy, PdfPageOrientation,
@@ -156,8 +180,13 @@ pub mod prelude {
         },
         pdf::document::pages::*,
         pdf::document::permissions::*,
+        pdf::document::progressive::*,
+        pdf::document::revision::*,
+        pdf::document::revisions::*,
+        pdf::document::save_options::*,
         pdf::document::signature::*,
         pdf::document::signatures::*,
+        pdf::document::viewer_preferences::*,
         pdf::document::{PdfDocument, PdfDocumentVersion},
         pdf::font::glyph::*,
         pdf::font::glyphs::*,
@@ -171,6 +200,9 @@ pub mod prelude {
         pdf::rect::*,
         pdfium::*,
     };
+
+    #[cfg(feature = "verify")]
+    pub use crate::pdf::document::signature::verification::*;
 }
 
 #[cfg(test)]
@@ -348,6 +348,16 @@ impl PdfColor {
         ((alpha << 24) | (b << 16) | (g << 8) | r) as FPDF_DWORD
     }
 
+    /// Returns this color encoded as a 32-bit 0xAARRGGBB value with the red and blue components
+    /// in their natural positions, suitable for passing to Pdfium functions that expect
+    /// an `FX_ARGB` value, such as the colors in an `FPDF_COLORSCHEME`.
+    #[inline]
+    pub(crate) fn as_pdfium_argb(&self) -> FPDF_DWORD {
+        let (alpha, r, g, b) = self.color_components();
+
+        ((alpha << 24) | (r << 16) | (g << 8) | b) as FPDF_DWORD
+    }
+
     /// Returns a tuple comprising this color encoded as a 32-bit hexadecimal 0xFFRRGGBB value
     /// and this alpha encoded as an 8-bit value, suitable for passing to Pdfium.
     #[inline]
@@ -402,4 +412,17 @@ mod tests {
             "40800080"
         );
     }
+
+    #[test]
+    fn test_as_pdfium_argb() {
+        assert_eq!(
+            PdfColor::new(0x12, 0x34, 0x56, 0x78).as_pdfium_argb(),
+            0x78123456
+        );
+        assert_eq!(
+            PdfColor::from_pdfium(PdfColor::PURPLE.with_alpha(64).as_pdfium_argb())
+                .color_components(),
+            PdfColor::PURPLE.with_alpha(64).color_components()
+        );
+    }
 }
@@ -7,14 +7,27 @@ pub mod bookmark;
 pub mod bookmarks;
 pub mod fonts;
 pub mod form;
+pub mod javascript_action;
+pub mod javascript_actions;
 pub mod metadata;
+pub mod named_destination;
+pub mod named_destinations;
 pub mod page;
 pub mod pages;
 pub mod permissions;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod progressive;
+
+pub mod revision;
+pub mod revisions;
+pub mod save_options;
 pub mod signature;
 pub mod signatures;
+pub(crate) mod syntax;
+pub mod viewer_preferences;
 
-use crate::bindgen::FPDF_DOCUMENT;
+use crate::bindgen::{FPDF_DOCUMENT, FPDF_DWORD};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
 use crate::error::PdfiumInternalError;
@@ -22,10 +35,16 @@ use crate::pdf::document::attachments::PdfAttachments;
 use crate::pdf::document::bookmarks::PdfBookmarks;
 use crate::pdf::document::fonts::PdfFonts;
 use crate::pdf::document::form::PdfForm;
+use crate::pdf::document::javascript_actions::PdfJavaScriptActions;
 use crate::pdf::document::metadata::PdfMetadata;
+use crate::pdf::document::named_destinations::PdfNamedDestinations;
+use crate::pdf::document::page::annotation::edit_cache::PdfPageAnnotationEditCache;
 use crate::pdf::document::pages::PdfPages;
 use crate::pdf::document::permissions::PdfPermissions;
+use crate::pdf::document::revisions::PdfRevisions;
+use crate::pdf::document::save_options::PdfSaveOptions;
 use crate::pdf::document::signatures::PdfSignatures;
+use crate::pdf::document::viewer_preferences::PdfViewerPreferences;
 use crate::utils::files::get_pdfium_file_writer_from_writer;
 use crate::utils::files::FpdfFileAccessExt;
 use std::fmt::{Debug, Formatter};
@@ -146,12 +165,20 @@ impl PdfDocumentVersion {
 /// * [PdfDocument::fonts()], an immutable collection of all the [PdfFonts] in the document.
 /// * [PdfDocument::fonts_mut()], a mutable collection of all the [PdfFonts] in the document.
 /// * [PdfDocument::form()], an immutable reference to the [PdfForm] embedded in the document, if any.
+/// * [PdfDocument::javascript()], an immutable collection of all the document-level
+///   [PdfJavaScriptActions] in the document.
 /// * [PdfDocument::metadata()], an immutable collection of all the [PdfMetadata] tags in the document.
+/// * [PdfDocument::named_destinations()], an immutable collection of all the
+///   [PdfNamedDestinations] in the document.
 /// * [PdfDocument::pages()], an immutable collection of all the [PdfPages] in the document.
 /// * [PdfDocument::pages_mut()], a mutable collection of all the [PdfPages] in the document.
 /// * [PdfDocument::permissions()], settings relating to security handlers and document permissions
 ///   for the document.
+/// * [PdfDocument::revisions()], an immutable collection of all the [PdfRevisions] in the document.
 /// * [PdfDocument::signatures()], an immutable collection of all the [PdfSignatures] in the document.
+/// * [PdfDocument::viewer_preferences()], the [PdfViewerPreferences] and print settings
+///   for the document.
+/// * [PdfDocument::viewer_preferences_mut()], mutable [PdfViewerPreferences] for the document.
 pub struct PdfDocument<'a> {
     handle: FPDF_DOCUMENT,
     output_version: Option<PdfDocumentVersion>,
@@ -159,10 +186,14 @@ pub struct PdfDocument<'a> {
     bookmarks: PdfBookmarks<'a>,
     form: Option<PdfForm<'a>>,
     fonts: PdfFonts<'a>,
+    javascript: PdfJavaScriptActions<'a>,
     metadata: PdfMetadata<'a>,
+    named_destinations: PdfNamedDestinations<'a>,
     pages: PdfPages<'a>,
     permissions: PdfPermissions<'a>,
+    revisions: PdfRevisions<'a>,
     signatures: PdfSignatures<'a>,
+    viewer_preferences: PdfViewerPreferences<'a>,
     bindings: &'a dyn PdfiumLibraryBindings,
     source_byte_buffer: Option<Vec<u8>>,
 
@@ -189,10 +220,14 @@ impl<'a> PdfDocument<'a> {
             bookmarks: PdfBookmarks::from_pdfium(handle, bindings),
             form,
             fonts: PdfFonts::from_pdfium(handle, bindings),
+            javascript: PdfJavaScriptActions::from_pdfium(handle, bindings),
             metadata: PdfMetadata::from_pdfium(handle, bindings),
+            named_destinations: PdfNamedDestinations::from_pdfium(handle, bindings),
             pages,
             permissions: PdfPermissions::from_pdfium(handle, bindings),
+            revisions: PdfRevisions::from_pdfium(handle, bindings),
             signatures: PdfSignatures::from_pdfium(handle, bindings),
+            viewer_preferences: PdfViewerPreferences::from_pdfium(handle, bindings),
             bindings,
             source_byte_buffer: None,
             file_access_reader: None,
@@ -279,12 +314,27 @@ impl<'a> PdfDocument<'a> {
         &mut self.fonts
     }
 
+    /// Returns an immutable collection of all the document-level [PdfJavaScriptActions]
+    /// embedded in this [PdfDocument].
+    ///
+    /// JavaScript attached to individual form fields is not included in this collection.
+    #[inline]
+    pub fn javascript(&self) -> &PdfJavaScriptActions<'a> {
+        &self.javascript
+    }
+
     /// Returns an immutable collection of all the [PdfMetadata] tags in this [PdfDocument].
     #[inline]
     pub fn metadata(&self) -> &PdfMetadata {
         &self.metadata
     }
 
+    /// Returns an immutable collection of all the [PdfNamedDestinations] in this [PdfDocument].
+    #[inline]
+    pub fn named_destinations(&self) -> &PdfNamedDestinations<'a> {
+        &self.named_destinations
+    }
+
     /// Returns an immutable collection of all the [PdfPages] in this [PdfDocument].
     #[inline]
     pub fn pages(&self) -> &PdfPages<'a> {
@@ -303,24 +353,86 @@ impl<'a> PdfDocument<'a> {
         &self.permissions
     }
 
+    /// Returns an immutable collection of all the [PdfRevisions] of this [PdfDocument],
+    /// one for each time the document was written or incrementally updated.
+    #[inline]
+    pub fn revisions(&self) -> &PdfRevisions<'a> {
+        &self.revisions
+    }
+
     /// Returns an immutable collection of all the [PdfSignatures] attached to this [PdfDocument].
     #[inline]
     pub fn signatures(&self) -> &PdfSignatures {
         &self.signatures
     }
 
+    /// Returns the [PdfViewerPreferences] and print settings of this [PdfDocument].
+    #[inline]
+    pub fn viewer_preferences(&self) -> &PdfViewerPreferences<'a> {
+        &self.viewer_preferences
+    }
+
+    /// Returns the mutable [PdfViewerPreferences] and print settings of this [PdfDocument].
+    #[inline]
+    pub fn viewer_preferences_mut(&mut self) -> &mut PdfViewerPreferences<'a> {
+        &mut self.viewer_preferences
+    }
+
     /// Writes this [PdfDocument] to the given writer.
+    ///
+    /// The document is rewritten in full. To save the document incrementally, or to remove
+    /// its security settings, use the [PdfDocument::save_to_writer_with_options()] function.
+    #[inline]
     pub fn save_to_writer<W: Write + 'static>(&self, writer: &mut W) -> Result<(), PdfiumError> {
-        // TODO: AJRC - 25/5/22 - investigate supporting the FPDF_INCREMENTAL, FPDF_NO_INCREMENTAL,
-        // and FPDF_REMOVE_SECURITY flags defined in fpdf_save.h. There's not a lot of information
-        // on what they actually do, however.
-        // Some small info at https://forum.patagames.com/posts/t155-PDF-SaveFlags.
+        self.save_to_writer_with_options(writer, &PdfSaveOptions::default())
+    }
 
-        let flags = 0;
+    /// Writes this [PdfDocument] to the given writer, using the given [PdfSaveOptions].
+    pub fn save_to_writer_with_options<W: Write + 'static>(
+        &self,
+        writer: &mut W,
+        options: &PdfSaveOptions,
+    ) -> Result<(), PdfiumError> {
+        let flags = options.as_pdfium_flags()?;
 
+        if PdfPageAnnotationEditCache::has_edits_for_document(self.handle) {
+            // Some annotations have changes to dictionary entries that Pdfium cannot write.
+            // Have Pdfium write the document to a buffer, append the changes to the buffer,
+            // then pass the buffer on to the given writer.
+
+            let mut cursor = Cursor::new(Vec::new());
+
+            self.save_to_writer_with_flags(&mut cursor, flags, options)?;
+
+            let mut data = cursor.into_inner();
+
+            PdfPageAnnotationEditCache::apply_edits_to_saved_document(
+                self.bindings,
+                self.handle,
+                &mut data,
+                options.mode(),
+            )?;
+
+            return writer
+                .write_all(data.as_slice())
+                .and_then(|_| writer.flush())
+                .map_err(PdfiumError::IoError);
+        }
+
+        self.save_to_writer_with_flags(writer, flags, options)
+    }
+
+    /// Writes this [PdfDocument] to the given writer using Pdfium, passing the given
+    /// Pdfium save flags.
+    fn save_to_writer_with_flags<W: Write + 'static>(
+        &self,
+        writer: &mut W,
+        flags: FPDF_DWORD,
+        options: &PdfSaveOptions,
+    ) -> Result<(), PdfiumError> {
         let mut pdfium_file_writer = get_pdfium_file_writer_from_writer(writer);
 
-        let result = match self.output_version {
+        let result = match options.version().or(self.output_version) {
             Some(version) => self.bindings.FPDF_SaveWithVersion(
                 self.handle,
                 pdfium_file_writer.as_fpdf_file_write_mut_ptr(),
@@ -361,15 +473,43 @@ impl<'a> PdfDocument<'a> {
     /// * Use the [PdfDocument::save_to_blob()] function to save document data directly into a new
     ///   Javascript `Blob` object. This function is only available when compiling to WASM.
     #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
     pub fn save_to_file(&self, path: &(impl AsRef<Path> + ?Sized)) -> Result<(), PdfiumError> {
-        self.save_to_writer(&mut File::create(path).map_err(PdfiumError::IoError)?)
+        self.save_to_file_with_options(path, &PdfSaveOptions::default())
+    }
+
+    /// Writes this [PdfDocument] to the file at the given path, using the given [PdfSaveOptions].
+    ///
+    /// This function is not available when compiling to WASM. Use either the
+    /// [PdfDocument::save_to_bytes_with_options()] or the [PdfDocument::save_to_blob_with_options()]
+    /// functions instead.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_to_file_with_options(
+        &self,
+        path: &(impl AsRef<Path> + ?Sized),
+        options: &PdfSaveOptions,
+    ) -> Result<(), PdfiumError> {
+        self.save_to_writer_with_options(
+            &mut File::create(path).map_err(PdfiumError::IoError)?,
+            options,
+        )
     }
 
     /// Writes this [PdfDocument] to a new byte buffer, returning the byte buffer.
+    #[inline]
     pub fn save_to_bytes(&self) -> Result<Vec<u8>, PdfiumError> {
+        self.save_to_bytes_with_options(&PdfSaveOptions::default())
+    }
+
+    /// Writes this [PdfDocument] to a new byte buffer using the given [PdfSaveOptions],
+    /// returning the byte buffer.
+    pub fn save_to_bytes_with_options(
+        &self,
+        options: &PdfSaveOptions,
+    ) -> Result<Vec<u8>, PdfiumError> {
         let mut cursor = Cursor::new(Vec::new());
 
-        self.save_to_writer(&mut cursor)?;
+        self.save_to_writer_with_options(&mut cursor, options)?;
 
         Ok(cursor.into_inner())
     }
@@ -378,8 +518,18 @@ impl<'a> PdfDocument<'a> {
     ///
     /// This function is only available when compiling to WASM.
     #[cfg(any(doc, target_arch = "wasm32"))]
+    #[inline]
     pub fn save_to_blob(&self) -> Result<Blob, PdfiumError> {
-        let bytes = self.save_to_bytes()?;
+        self.save_to_blob_with_options(&PdfSaveOptions::default())
+    }
+
+    /// Writes this [PdfDocument] to a new `Blob` using the given [PdfSaveOptions],
+    /// returning the `Blob`.
+    ///
+    /// This function is only available when compiling to WASM.
+    #[cfg(any(doc, target_arch = "wasm32"))]
+    pub fn save_to_blob_with_options(&self, options: &PdfSaveOptions) -> Result<Blob, PdfiumError> {
+        let bytes = self.save_to_bytes_with_options(options)?;
 
         let array = Uint8Array::new_with_length(bytes.len() as u32);
 
@@ -403,6 +553,7 @@ impl<'a> Drop for PdfDocument<'a> {
         // avoiding a segmentation fault when using Pdfium builds compiled with V8/XFA support.
 
         self.form = None;
+        PdfPageAnnotationEditCache::remove_edits_for_document(self.handle);
         self.bindings.FPDF_CloseDocument(self.handle);
     }
 }
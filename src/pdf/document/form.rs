@@ -1,16 +1,25 @@
 //! Defines the [PdfForm] struct, exposing functionality related to a form
 //! embedded in a `PdfDocument`.
 
+pub mod data;
+
 use crate::bindgen::{
     FORMTYPE_ACRO_FORM, FORMTYPE_NONE, FORMTYPE_XFA_FOREGROUND, FORMTYPE_XFA_FULL, FPDF_DOCUMENT,
     FPDF_FORMFILLINFO, FPDF_FORMHANDLE,
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
+use crate::pdf::document::form::data::{PdfFormDataField, PdfFormDataFormat};
+use crate::pdf::document::page::field::option::PdfFormFieldOption;
+use crate::pdf::document::page::field::options::{PdfFormFieldOptionIndex, PdfFormFieldOptions};
+use crate::pdf::document::page::field::private::internal::PdfFormFieldPrivate;
 use crate::pdf::document::page::field::PdfFormFieldCommon;
-use crate::pdf::document::page::field::PdfFormFieldType;
+use crate::pdf::document::page::field::{PdfFormField, PdfFormFieldType};
 use crate::pdf::document::pages::PdfPages;
-use std::collections::HashMap;
+use crate::pdf::document::syntax::{
+    decode_text_string, save_document, PdfSyntaxDocument, PdfSyntaxObject,
+};
+use std::collections::{HashMap, HashSet};
 use std::ops::DerefMut;
 use std::pin::Pin;
 use std::ptr::null_mut;
@@ -70,7 +79,11 @@ impl PdfFormType {
 /// ```
 ///
 /// Alternatively, use the [PdfForm::field_values()] function to eagerly retrieve the values of all
-/// fields in the document as a map of (field name, field value) pairs.
+/// fields in the document as a map of (field name, field value) pairs, and the
+/// [PdfForm::fill_from()] function to set the values of many fields at once from such a map.
+///
+/// Field values can be exchanged with other applications in FDF and XFDF format using the
+/// [PdfForm::export_data()] and [PdfForm::import_data()] functions.
 pub struct PdfForm<'a> {
     form_handle: FPDF_FORMHANDLE,
     document_handle: FPDF_DOCUMENT,
@@ -266,6 +279,380 @@ impl<'a> PdfForm<'a> {
 
         result
     }
+
+    /// Sets the value of every form field on every page of the given [PdfPages] collection
+    /// whose name appears in the given map of (field name, field value) pairs. Fields whose
+    /// names do not appear in the map are left unchanged.
+    ///
+    /// Values are interpreted in the same way as they are returned by [PdfForm::field_values()]:
+    ///
+    /// * A checkbox is checked if the given value matches its export value, and cleared
+    ///   otherwise, so that only the matching checkbox in a control group is checked.
+    ///   A checkbox without an export value is cleared if its value is `None`, an empty string,
+    ///   `"false"`, or `"Off"`, and checked otherwise.
+    /// * The radio button in a control group whose export value matches the given value is
+    ///   selected. Radio buttons cannot be cleared.
+    /// * Combo boxes and list boxes select the option whose export value matches the given
+    ///   value or, failing that, the option whose displayed label matches the given value.
+    /// * Text fields are set to the given value, or cleared if the value is `None`.
+    ///
+    /// Push buttons, signatures, and fields of unknown type are ignored. This function
+    /// returns the first error encountered, leaving any remaining fields unchanged.
+    ///
+    /// To select more than one option in a list box that allows multiple selection,
+    /// use the [PdfForm::fill_from_values()] function.
+    pub fn fill_from(
+        &self,
+        pages: &'a PdfPages<'a>,
+        values: &HashMap<String, Option<String>>,
+    ) -> Result<(), PdfiumError> {
+        self.fill_from_values(
+            pages,
+            &values
+                .iter()
+                .map(|(name, value)| (name.clone(), value.iter().cloned().collect()))
+                .collect(),
+        )
+    }
+
+    /// Sets the value of every form field on every page of the given [PdfPages] collection
+    /// whose name appears in the given map of (field name, field values) pairs. Fields whose
+    /// names do not appear in the map are left unchanged.
+    ///
+    /// Values are interpreted as described in [PdfForm::fill_from()], taking the first value
+    /// given for each field, except for list boxes that allow multiple selection: these select
+    /// the options matching all the given values, and deselect all other options.
+    /// Returns [PdfiumError::FormFieldOptionNotFound] if no option in a combo box or list box
+    /// matches a given value.
+    pub fn fill_from_values(
+        &self,
+        pages: &'a PdfPages<'a>,
+        values: &HashMap<String, Vec<String>>,
+    ) -> Result<(), PdfiumError> {
+        let mut data = None;
+
+        for (page_index, page) in pages.iter().enumerate() {
+            for (annotation_index, mut annotation) in page.annotations().iter().enumerate() {
+                let field = match annotation.as_form_field_mut() {
+                    Some(field) => field,
+                    None => continue,
+                };
+
+                let field_values = match field.name().and_then(|name| values.get(&name)) {
+                    Some(field_values) => field_values,
+                    None => continue,
+                };
+
+                let value = field_values.first().map(|value| value.as_str());
+
+                match field {
+                    PdfFormField::Checkbox(field) => {
+                        let is_checked = match (value, field.export_value()) {
+                            (None | Some("") | Some("false") | Some("Off"), _) => false,
+                            (Some(value), Some(export_value)) => value == export_value,
+                            (Some(_), None) => true,
+                        };
+
+                        if is_checked {
+                            // A checkbox is checked by setting its value to its export value.
+
+                            let export_value =
+                                field.export_value().unwrap_or_else(|| "Yes".to_string());
+
+                            field.set_value_impl(export_value.as_str())?
+                        } else {
+                            field.set_checked(false)?
+                        }
+                    }
+                    PdfFormField::RadioButton(field) => {
+                        if let Some(export_value) = field.export_value() {
+                            if value == Some(export_value.as_str()) {
+                                field.set_value_impl(export_value.as_str())?;
+                            }
+                        }
+                    }
+                    PdfFormField::ComboBox(field) => {
+                        if let Some(value) = value {
+                            let export_values =
+                                self.option_export_values(&mut data, page_index, annotation_index);
+
+                            match find_option(field.options(), &export_values, value) {
+                                Some(index) => field.select_option(index)?,
+                                None => field.set_value(value)?,
+                            }
+                        }
+                    }
+                    PdfFormField::ListBox(field) => {
+                        let export_values =
+                            self.option_export_values(&mut data, page_index, annotation_index);
+
+                        if field.is_multi_select() {
+                            let selected = field_values
+                                .iter()
+                                .map(|value| {
+                                    find_option(field.options(), &export_values, value)
+                                        .ok_or(PdfiumError::FormFieldOptionNotFound)
+                                })
+                                .collect::<Result<HashSet<_>, _>>()?;
+
+                            let changes = field
+                                .options()
+                                .iter()
+                                .filter(|option| {
+                                    option.is_set() != selected.contains(&option.index())
+                                })
+                                .map(|option| (option.index(), option.is_set()))
+                                .collect::<Vec<_>>();
+
+                            for (index, is_set) in changes {
+                                if is_set {
+                                    field.deselect_option(index)?;
+                                } else {
+                                    field.select_option(index)?;
+                                }
+                            }
+                        } else if let Some(value) = value {
+                            match find_option(field.options(), &export_values, value) {
+                                Some(index) => field.select_option(index)?,
+                                None => return Err(PdfiumError::FormFieldOptionNotFound),
+                            }
+                        }
+                    }
+                    PdfFormField::Text(field) => field.set_value(value.unwrap_or_default())?,
+                    PdfFormField::PushButton(_)
+                    | PdfFormField::Signature(_)
+                    | PdfFormField::Unknown(_) => {}
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Exports the value of every form field on every page of the given [PdfPages] collection
+    /// into a new byte buffer in the given [PdfFormDataFormat].
+    ///
+    /// Unlike [PdfForm::field_values()], fields are exported using the values expected by
+    /// other PDF processors such as Adobe Acrobat:
+    ///
+    /// * Checkbox and radio button values are exported using the field's own export value,
+    ///   or `Off` for cleared fields.
+    /// * Combo box and list box values are exported using the export values of the
+    ///   selected options, rather than their displayed labels. All selected options
+    ///   of a list box that allows multiple selection are exported.
+    pub fn export_data(&self, pages: &'a PdfPages<'a>, format: PdfFormDataFormat) -> Vec<u8> {
+        let mut fields: HashMap<String, PdfFormDataField> = HashMap::new();
+
+        let off = || vec!["Off".to_string()];
+
+        let mut data = None;
+
+        for (page_index, page) in pages.iter().enumerate() {
+            for (annotation_index, annotation) in page.annotations().iter().enumerate() {
+                if let Some(field) = annotation.as_form_field() {
+                    let (values, is_name) = match field {
+                        PdfFormField::Checkbox(field) => {
+                            if field.is_checked().unwrap_or(false) {
+                                (
+                                    vec![field.export_value().unwrap_or_else(|| "Yes".to_string())],
+                                    true,
+                                )
+                            } else {
+                                (off(), true)
+                            }
+                        }
+                        PdfFormField::RadioButton(field) => {
+                            if field.is_checked().unwrap_or(false) {
+                                (
+                                    field
+                                        .export_value()
+                                        .or_else(|| field.group_value())
+                                        .into_iter()
+                                        .collect(),
+                                    true,
+                                )
+                            } else {
+                                (off(), true)
+                            }
+                        }
+                        PdfFormField::ComboBox(field) => {
+                            let export_values =
+                                self.option_export_values(&mut data, page_index, annotation_index);
+
+                            // An editable combo box may hold a custom value that does not
+                            // match any of its options.
+
+                            let values = match field.options().iter().find(|option| option.is_set())
+                            {
+                                Some(option) => option_value(&option, &export_values),
+                                None => field.value_impl(),
+                            };
+
+                            (values.into_iter().collect(), false)
+                        }
+                        PdfFormField::ListBox(field) => {
+                            let export_values =
+                                self.option_export_values(&mut data, page_index, annotation_index);
+
+                            (
+                                field
+                                    .options()
+                                    .iter()
+                                    .filter(|option| option.is_set())
+                                    .filter_map(|option| option_value(&option, &export_values))
+                                    .collect(),
+                                false,
+                            )
+                        }
+                        PdfFormField::Text(field) => (field.value().into_iter().collect(), false),
+                        PdfFormField::PushButton(_)
+                        | PdfFormField::Signature(_)
+                        | PdfFormField::Unknown(_) => continue,
+                    };
+
+                    let name = field.name().unwrap_or_default();
+
+                    // A group of checkbox or radio button controls all share the same name.
+                    // As in field_values(), we prefer the value of a checked control in the
+                    // group to that of an unchecked control.
+
+                    if is_name && values == off() && fields.contains_key(&name) {
+                        continue;
+                    }
+
+                    fields.insert(
+                        name.clone(),
+                        PdfFormDataField {
+                            name,
+                            values,
+                            is_name,
+                        },
+                    );
+                }
+            }
+        }
+
+        format.serialize_fields(fields.into_values().collect())
+    }
+
+    /// Parses the given byte buffer in the given [PdfFormDataFormat], then sets the value of
+    /// every form field on every page of the given [PdfPages] collection whose name appears
+    /// in the parsed data. Values are interpreted as described in [PdfForm::fill_from_values()].
+    #[inline]
+    pub fn import_data(
+        &self,
+        pages: &'a PdfPages<'a>,
+        format: PdfFormDataFormat,
+        data: &[u8],
+    ) -> Result<(), PdfiumError> {
+        self.fill_from_values(pages, &format.parse(data)?)
+    }
+
+    /// Returns the export values of the options of the combo box or list box at the given
+    /// annotation index on the page at the given page index, in option order.
+    ///
+    /// Pdfium only provides access to the displayed labels of options, so the document is
+    /// written to the given buffer by Pdfium, if it has not been already, and the field's
+    /// `/Opt` array is read from the written data. An empty list is returned if the
+    /// export values cannot be read.
+    fn option_export_values(
+        &self,
+        data: &mut Option<Vec<u8>>,
+        page_index: usize,
+        annotation_index: usize,
+    ) -> Vec<String> {
+        let data = data.get_or_insert_with(|| {
+            save_document(self.bindings, self.document_handle).unwrap_or_default()
+        });
+
+        let syntax = match PdfSyntaxDocument::new(data.as_slice()) {
+            Ok(syntax) => syntax,
+            Err(_) => return Vec::new(),
+        };
+
+        let mut dictionary = match syntax.annotation(page_index, annotation_index) {
+            Ok(annotation) => annotation.dictionary,
+            Err(_) => return Vec::new(),
+        };
+
+        // The /Opt entry is inheritable, so it may be set on a parent field
+        // rather than on the widget annotation itself.
+
+        for _ in 0..MAXIMUM_FIELD_DEPTH {
+            if let Some(options) = dictionary.get(b"Opt") {
+                return match syntax.resolve_all(options) {
+                    Ok(PdfSyntaxObject::Array(options)) => {
+                        options.iter().map(option_export_value).collect()
+                    }
+                    _ => Vec::new(),
+                };
+            }
+
+            dictionary = match dictionary
+                .get(b"Parent")
+                .map(|parent| syntax.resolve(parent))
+            {
+                Some(Ok(parent)) => match parent.as_dictionary() {
+                    Some(parent) => parent.clone(),
+                    None => break,
+                },
+                _ => break,
+            };
+        }
+
+        Vec::new()
+    }
+}
+
+/// The maximum depth of the form field hierarchy that will be searched for
+/// inherited field attributes.
+const MAXIMUM_FIELD_DEPTH: usize = 64;
+
+/// Returns the export value of the given entry in a choice field's `/Opt` array. Each entry is
+/// either a text string, used as both the export value and the displayed label, or an array
+/// of two text strings, the first being the export value and the second the displayed label.
+fn option_export_value(option: &PdfSyntaxObject) -> String {
+    match option {
+        PdfSyntaxObject::String(value) => decode_text_string(value),
+        PdfSyntaxObject::Array(pair) => match pair.first() {
+            Some(PdfSyntaxObject::String(value)) => decode_text_string(value),
+            _ => String::new(),
+        },
+        _ => String::new(),
+    }
+}
+
+/// Returns the export value of the given option, taken from the given list of export values,
+/// or the option's displayed label if its export value is not known.
+fn option_value(option: &PdfFormFieldOption, export_values: &[String]) -> Option<String> {
+    export_values
+        .get(option.index())
+        .cloned()
+        .or_else(|| option.label().cloned())
+}
+
+/// Returns the index of the option in the given collection whose export value, taken from the
+/// given list of export values, matches the given value or, failing that, whose displayed label
+/// matches the given value.
+fn find_option(
+    options: &PdfFormFieldOptions,
+    export_values: &[String],
+    value: &str,
+) -> Option<PdfFormFieldOptionIndex> {
+    options
+        .iter()
+        .find(|option| {
+            export_values
+                .get(option.index())
+                .map(|export| export.as_str())
+                == Some(value)
+        })
+        .or_else(|| {
+            options
+                .iter()
+                .find(|option| option.label().map(|label| label.as_str()) == Some(value))
+        })
+        .map(|option| option.index())
 }
 
 impl<'a> Drop for PdfForm<'a> {
@@ -276,3 +663,60 @@ impl<'a> Drop for PdfForm<'a> {
             .FPDFDOC_ExitFormFillEnvironment(self.form_handle);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_export_import_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut values = HashMap::new();
+
+        values.insert(
+            "colors".to_string(),
+            vec!["Red".to_string(), "Blue".to_string()],
+        );
+        values.insert("size".to_string(), vec!["Large".to_string()]);
+        values.insert("fruit".to_string(), vec!["Cherry".to_string()]);
+        values.insert("country".to_string(), vec!["us".to_string()]);
+        values.insert("comments".to_string(), vec!["Zoë (née Smith)".to_string()]);
+
+        for format in [PdfFormDataFormat::Fdf, PdfFormDataFormat::Xfdf] {
+            let source = pdfium.load_pdf_from_file("./test/choice-fields-test.pdf", None)?;
+
+            let form = source.form().unwrap();
+
+            form.fill_from_values(source.pages(), &values)?;
+
+            let exported = form.export_data(source.pages(), format);
+
+            // Choice fields are exported using the export values of all selected options,
+            // not their displayed labels.
+
+            assert_eq!(format.parse(&exported)?, values);
+
+            let target = pdfium.load_pdf_from_file("./test/choice-fields-test.pdf", None)?;
+
+            let form = target.form().unwrap();
+
+            assert_eq!(
+                form.field_values(target.pages()).get("country"),
+                Some(&Some("France".to_string()))
+            );
+
+            form.import_data(target.pages(), format, &exported)?;
+
+            assert_eq!(
+                form.field_values(target.pages()).get("country"),
+                Some(&Some("United States".to_string()))
+            );
+            assert_eq!(form.export_data(target.pages(), format), exported);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,283 @@
+//! Defines the [PdfFormDataFormat] enum, exposing functionality related to exchanging
+//! form field values with other applications using the FDF and XFDF file formats.
+
+mod fdf;
+mod xfdf;
+
+use crate::error::PdfiumError;
+use std::collections::HashMap;
+
+#[cfg(doc)]
+use crate::pdf::document::form::PdfForm;
+
+/// A file format used to exchange form field values with other applications,
+/// such as Adobe Acrobat, without exchanging the PDF document containing the form itself.
+///
+/// Form field values can be exported from and imported into a [PdfForm] using the
+/// [PdfForm::export_data()] and [PdfForm::import_data()] functions, or converted to and from
+/// a map of (field name, field values) pairs using the [PdfFormDataFormat::serialize()] and
+/// [PdfFormDataFormat::parse()] functions.
+///
+/// Both formats represent the form field hierarchy using nested fields; hierarchical field names
+/// are flattened into fully qualified, period-separated field names when parsing,
+/// and split apart again when serializing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PdfFormDataFormat {
+    /// Forms Data Format, as defined in section 12.7.8 of The PDF Reference, 7th edition.
+    Fdf,
+
+    /// XML Forms Data Format, as defined in Adobe's XFDF specification, version 3.0.
+    Xfdf,
+}
+
+impl PdfFormDataFormat {
+    /// Serializes the given map of (field name, field values) pairs into a new byte buffer
+    /// in this [PdfFormDataFormat]. All values are serialized as text strings.
+    ///
+    /// A field with no values is serialized without a value. A field with more than one value,
+    /// such as a multiple selection list box, is serialized with all its values.
+    pub fn serialize(&self, values: &HashMap<String, Vec<String>>) -> Vec<u8> {
+        self.serialize_fields(
+            values
+                .iter()
+                .map(|(name, value)| PdfFormDataField {
+                    name: name.clone(),
+                    values: value.clone(),
+                    is_name: false,
+                })
+                .collect(),
+        )
+    }
+
+    /// Parses the given byte buffer in this [PdfFormDataFormat], returning a map of
+    /// (field name, field values) pairs.
+    ///
+    /// Fields without a value are returned with an empty list of values. Fields that carry
+    /// more than one value, such as multiple selection list boxes, are returned with
+    /// all their values.
+    pub fn parse(&self, data: &[u8]) -> Result<HashMap<String, Vec<String>>, PdfiumError> {
+        match self {
+            PdfFormDataFormat::Fdf => fdf::parse(data),
+            PdfFormDataFormat::Xfdf => xfdf::parse(data),
+        }
+    }
+
+    /// Serializes the given list of fields into a new byte buffer in this [PdfFormDataFormat].
+    pub(crate) fn serialize_fields(&self, fields: Vec<PdfFormDataField>) -> Vec<u8> {
+        let tree = PdfFormDataNode::tree(fields);
+
+        match self {
+            PdfFormDataFormat::Fdf => fdf::serialize(&tree),
+            PdfFormDataFormat::Xfdf => xfdf::serialize(&tree).into_bytes(),
+        }
+    }
+}
+
+/// A single form field value to be serialized.
+pub(crate) struct PdfFormDataField {
+    /// The fully qualified, period-separated name of the field.
+    pub(crate) name: String,
+
+    /// The values of the field. Most fields have at most one value; multiple selection
+    /// list boxes may have several.
+    pub(crate) values: Vec<String>,
+
+    /// `true` if the value should be serialized as a PDF name rather than a text string.
+    /// Checkbox and radio button values must be names in FDF files.
+    pub(crate) is_name: bool,
+}
+
+/// A single node in the tree of form fields built from fully qualified field names.
+struct PdfFormDataNode {
+    partial_name: String,
+    values: Vec<String>,
+    is_name: bool,
+    kids: Vec<PdfFormDataNode>,
+}
+
+impl PdfFormDataNode {
+    /// Arranges the given fields into a tree, splitting each fully qualified field name
+    /// into its period-separated partial names. Fields are sorted by name so that
+    /// serialized output is stable.
+    fn tree(mut fields: Vec<PdfFormDataField>) -> Vec<PdfFormDataNode> {
+        fields.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut roots: Vec<PdfFormDataNode> = Vec::new();
+
+        for field in fields {
+            let mut nodes = &mut roots;
+
+            let mut partial_names = field.name.split('.').peekable();
+
+            while let Some(partial_name) = partial_names.next() {
+                let index = match nodes
+                    .iter()
+                    .position(|node| node.partial_name == partial_name)
+                {
+                    Some(index) => index,
+                    None => {
+                        nodes.push(PdfFormDataNode {
+                            partial_name: partial_name.to_string(),
+                            values: Vec::new(),
+                            is_name: false,
+                            kids: Vec::new(),
+                        });
+
+                        nodes.len() - 1
+                    }
+                };
+
+                if partial_names.peek().is_none() {
+                    nodes[index].values = field.values.clone();
+                    nodes[index].is_name = field.is_name;
+                }
+
+                nodes = &mut nodes[index].kids;
+            }
+        }
+
+        roots
+    }
+}
+
+/// Returns the fully qualified name of a field with the given partial name
+/// and the given parent field name, if any.
+fn qualified_name(parent: &str, partial_name: &str) -> String {
+    if parent.is_empty() {
+        partial_name.to_string()
+    } else if partial_name.is_empty() {
+        parent.to_string()
+    } else {
+        format!("{}.{}", parent, partial_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> HashMap<String, Vec<String>> {
+        let mut values = HashMap::new();
+
+        values.insert(
+            "name".to_string(),
+            vec!["Zoë (née Smith) \\ 100%".to_string()],
+        );
+        values.insert("address.street".to_string(), vec!["1 Main St".to_string()]);
+        values.insert(
+            "address.city".to_string(),
+            vec!["<Springfield & Co>".to_string()],
+        );
+        values.insert("empty".to_string(), vec![]);
+        values.insert(
+            "colors".to_string(),
+            vec!["Red".to_string(), "Blue".to_string()],
+        );
+
+        values
+    }
+
+    #[test]
+    fn test_fdf_round_trip() -> Result<(), PdfiumError> {
+        let values = sample();
+
+        let data = PdfFormDataFormat::Fdf.serialize(&values);
+
+        assert!(data.starts_with(b"%FDF-1.2"));
+        assert_eq!(PdfFormDataFormat::Fdf.parse(&data)?, values);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_xfdf_round_trip() -> Result<(), PdfiumError> {
+        let values = sample();
+
+        let data = PdfFormDataFormat::Xfdf.serialize(&values);
+
+        assert_eq!(PdfFormDataFormat::Xfdf.parse(&data)?, values);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_fdf_with_indirect_fields() -> Result<(), PdfiumError> {
+        let data = b"%FDF-1.2\n\
+            1 0 obj << /FDF << /Fields [2 0 R] /F (form.pdf) >> >> endobj\n\
+            2 0 obj << /T (agree) /V /Yes /Kids [] >> endobj\n\
+            trailer << /Root 1 0 R >>\n%%EOF";
+
+        let values = PdfFormDataFormat::Fdf.parse(data)?;
+
+        assert_eq!(values.get("agree"), Some(&vec!["Yes".to_string()]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_fdf_with_repeated_references() -> Result<(), PdfiumError> {
+        // A field that lists itself as its own kid, more than once, must not cause
+        // the parser to revisit it.
+
+        let data = b"%FDF-1.2\n\
+            1 0 obj << /FDF << /Fields [2 0 R] >> >> endobj\n\
+            2 0 obj << /T (a) /Kids [2 0 R 2 0 R 3 0 R 3 0 R] >> endobj\n\
+            3 0 obj << /T (b) /V (value) /Kids [2 0 R] >> endobj\n\
+            trailer << /Root 1 0 R >>\n%%EOF";
+
+        let values = PdfFormDataFormat::Fdf.parse(data)?;
+
+        assert_eq!(values.len(), 1);
+        assert_eq!(values.get("a.b"), Some(&vec!["value".to_string()]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_fdf_deeply_nested_objects() {
+        let mut data = b"%FDF-1.2\n1 0 obj << /FDF << /Fields ".to_vec();
+
+        data.extend(std::iter::repeat(b'[').take(1_000_000));
+
+        assert!(matches!(
+            PdfFormDataFormat::Fdf.parse(&data),
+            Err(PdfiumError::ParseFormDataError(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_xfdf_nested_fields() -> Result<(), PdfiumError> {
+        let data = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <xfdf xmlns="http://ns.adobe.com/xfdf/" xml:space="preserve">
+                <!-- exported by a partner -->
+                <fields>
+                    <field name="contact">
+                        <field name="email"><value>a&amp;b@example.com</value></field>
+                        <field name="phone"/>
+                    </field>
+                    <field name="colors"><value>Red</value><value>Blue</value></field>
+                </fields>
+            </xfdf>"#;
+
+        let values = PdfFormDataFormat::Xfdf.parse(data.as_bytes())?;
+
+        assert_eq!(values.len(), 3);
+        assert_eq!(
+            values.get("contact.email"),
+            Some(&vec!["a&b@example.com".to_string()])
+        );
+        assert_eq!(values.get("contact.phone"), Some(&vec![]));
+        assert_eq!(
+            values.get("colors"),
+            Some(&vec!["Red".to_string(), "Blue".to_string()])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_invalid_data() {
+        assert!(PdfFormDataFormat::Fdf.parse(b"not an fdf file").is_err());
+        assert!(PdfFormDataFormat::Xfdf.parse(b"<fields><field>").is_err());
+    }
+}
@@ -0,0 +1,289 @@
+//! Serialization and parsing of form field values in Forms Data Format (FDF).
+
+use crate::error::PdfiumError;
+use crate::pdf::document::form::data::{qualified_name, PdfFormDataNode};
+use crate::pdf::document::syntax::{
+    decode_text_string, name_to_bytes, PdfSyntaxObject, PdfSyntaxParser,
+};
+use std::collections::{HashMap, HashSet};
+
+/// The maximum depth of nested field dictionaries we will follow when parsing. Reference cycles
+/// are detected separately; this limits the recursion depth of deep but acyclic field trees.
+const MAX_FIELD_DEPTH: usize = 64;
+
+/// Serializes the given tree of fields into a new FDF byte buffer.
+pub(super) fn serialize(fields: &[PdfFormDataNode]) -> Vec<u8> {
+    let mut result = Vec::new();
+
+    result.extend_from_slice(b"%FDF-1.2\n%\xE2\xE3\xCF\xD3\n");
+    result.extend_from_slice(b"1 0 obj\n<< /FDF << /Fields [\n");
+
+    for field in fields {
+        write_field(&mut result, field);
+    }
+
+    result.extend_from_slice(b"] >> >>\nendobj\ntrailer\n<< /Root 1 0 R >>\n%%EOF\n");
+
+    result
+}
+
+fn write_field(output: &mut Vec<u8>, field: &PdfFormDataNode) {
+    output.extend_from_slice(b"<< /T ");
+    write_text_string(output, field.partial_name.as_str());
+
+    // A field with more than one value, such as a multiple selection list box,
+    // takes an array of values.
+
+    let write_value = |output: &mut Vec<u8>, value: &String| {
+        if field.is_name {
+            output.extend_from_slice(&name_to_bytes(value.as_bytes()));
+        } else {
+            write_text_string(output, value.as_str());
+        }
+    };
+
+    match field.values.as_slice() {
+        [] => {}
+        [value] => {
+            output.extend_from_slice(b" /V ");
+            write_value(output, value);
+        }
+        values => {
+            output.extend_from_slice(b" /V [");
+
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    output.push(b' ');
+                }
+
+                write_value(output, value);
+            }
+
+            output.push(b']');
+        }
+    }
+
+    if !field.kids.is_empty() {
+        output.extend_from_slice(b" /Kids [\n");
+
+        for kid in field.kids.iter() {
+            write_field(output, kid);
+        }
+
+        output.push(b']');
+    }
+
+    output.extend_from_slice(b" >>\n");
+}
+
+/// Writes the given text as a PDF text string. Printable ASCII text is written as a literal
+/// string; any other text is written as a hexadecimal string in UTF-16BE with a byte order mark.
+fn write_text_string(output: &mut Vec<u8>, text: &str) {
+    if text.chars().all(|c| (' '..='~').contains(&c)) {
+        output.push(b'(');
+
+        for byte in text.bytes() {
+            if matches!(byte, b'(' | b')' | b'\\') {
+                output.push(b'\\');
+            }
+
+            output.push(byte);
+        }
+
+        output.push(b')');
+    } else {
+        output.extend_from_slice(b"<FEFF");
+
+        for unit in text.encode_utf16() {
+            output.extend_from_slice(format!("{:04X}", unit).as_bytes());
+        }
+
+        output.push(b'>');
+    }
+}
+
+/// Returns the values represented by the given field value object. Arrays of values
+/// are used by fields with more than one value, such as multiple selection list boxes.
+fn values_of(value: &PdfSyntaxObject) -> Vec<String> {
+    match value {
+        PdfSyntaxObject::String(bytes) => vec![decode_text_string(bytes)],
+        PdfSyntaxObject::Name(name) => vec![String::from_utf8_lossy(name).into_owned()],
+        PdfSyntaxObject::Number(number) => vec![number.to_string()],
+        PdfSyntaxObject::Boolean(value) => vec![value.to_string()],
+        PdfSyntaxObject::Array(values) => values.iter().flat_map(values_of).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Converts an error raised while parsing FDF data with a [PdfSyntaxParser] into
+/// a [PdfiumError::ParseFormDataError].
+fn to_form_data_error(error: PdfiumError) -> PdfiumError {
+    match error {
+        PdfiumError::ParseDocumentDataError(message) => PdfiumError::ParseFormDataError(message),
+        error => error,
+    }
+}
+
+/// Parses an indirect object header of the form "object generation obj" at the current
+/// position of the given parser, returning the object number.
+fn parse_object_header(parser: &mut PdfSyntaxParser) -> Option<u32> {
+    let number = parser.parse_integer().ok()?;
+
+    let generation = parser.parse_integer().ok()?;
+
+    if number <= u32::MAX as u64 && generation <= u16::MAX as u64 && parser.parse_keyword(b"obj") {
+        Some(number as u32)
+    } else {
+        None
+    }
+}
+
+/// Parses the given FDF byte buffer, returning a map of (field name, field values) pairs.
+pub(super) fn parse(data: &[u8]) -> Result<HashMap<String, Vec<String>>, PdfiumError> {
+    let mut objects = HashMap::new();
+
+    let mut trailer = None;
+
+    let mut position = 0;
+
+    // Collect all indirect objects and the trailer dictionary. Anything else at the top level
+    // of the file, such as a cross-reference table, is skipped.
+
+    loop {
+        let mut parser = PdfSyntaxParser::new(data, position);
+
+        if parser.is_at_end() {
+            break;
+        }
+
+        let start = parser.position();
+
+        if let Some(number) = parse_object_header(&mut parser) {
+            objects.insert(number, parser.parse_object().map_err(to_form_data_error)?);
+
+            // Stream data is skipped in its entirety, since it may contain anything.
+
+            position = if parser.parse_keyword(b"stream") {
+                data[parser.position()..]
+                    .windows(b"endstream".len())
+                    .position(|window| window == b"endstream")
+                    .map(|end| parser.position() + end + b"endstream".len())
+                    .unwrap_or(data.len())
+            } else {
+                parser.position()
+            };
+
+            continue;
+        }
+
+        let mut parser = PdfSyntaxParser::new(data, start);
+
+        if parser.parse_keyword(b"trailer") {
+            trailer = Some(parser.parse_object().map_err(to_form_data_error)?);
+        } else {
+            // Skip over whatever is here, whether or not it can be parsed.
+
+            let _ = parser.parse_object();
+        }
+
+        position = parser.position().max(start + 1);
+    }
+
+    let resolve = |object: &PdfSyntaxObject| -> Option<PdfSyntaxObject> {
+        match object {
+            PdfSyntaxObject::Reference(number, _) => objects.get(number).cloned(),
+            object => Some(object.clone()),
+        }
+    };
+
+    let get = |object: &PdfSyntaxObject, key: &[u8]| -> Option<PdfSyntaxObject> {
+        object
+            .as_dictionary()
+            .and_then(|dictionary| dictionary.get(key))
+            .and_then(resolve)
+    };
+
+    // The FDF dictionary is referenced from the catalog given in the trailer's /Root entry.
+    // Some writers omit the trailer, so fall back to searching all objects.
+
+    let fdf = trailer
+        .as_ref()
+        .and_then(|trailer| get(trailer, b"Root"))
+        .and_then(|root| get(&root, b"FDF"))
+        .or_else(|| {
+            let mut numbers = objects.keys().collect::<Vec<_>>();
+
+            numbers.sort();
+
+            numbers
+                .into_iter()
+                .find_map(|number| get(&objects[number], b"FDF"))
+        })
+        .ok_or_else(|| PdfiumError::ParseFormDataError("No /FDF dictionary found".to_string()))?;
+
+    let mut result = HashMap::new();
+
+    let mut visited = HashSet::new();
+
+    if let Some(PdfSyntaxObject::Array(fields)) = get(&fdf, b"Fields") {
+        for field in fields.iter() {
+            collect_field(field, "", &resolve, &mut result, &mut visited, 0);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Adds the values of the given field dictionary, and the values of all its descendants,
+/// to the given map of (field name, field values) pairs.
+///
+/// Each indirect field dictionary is visited at most once; any further references to an
+/// already visited object, whether they form a cycle or not, are ignored.
+fn collect_field(
+    field: &PdfSyntaxObject,
+    parent: &str,
+    resolve: &dyn Fn(&PdfSyntaxObject) -> Option<PdfSyntaxObject>,
+    result: &mut HashMap<String, Vec<String>>,
+    visited: &mut HashSet<u32>,
+    depth: usize,
+) {
+    if depth > MAX_FIELD_DEPTH {
+        return;
+    }
+
+    if let PdfSyntaxObject::Reference(number, _) = field {
+        if !visited.insert(*number) {
+            return;
+        }
+    }
+
+    let field = match resolve(field) {
+        Some(PdfSyntaxObject::Dictionary(field)) => field,
+        _ => return,
+    };
+
+    let partial_name = match field.get(b"T").and_then(resolve) {
+        Some(PdfSyntaxObject::String(bytes)) => decode_text_string(&bytes),
+        _ => String::new(),
+    };
+
+    let name = qualified_name(parent, partial_name.as_str());
+
+    let value = field.get(b"V").and_then(resolve);
+
+    let kids = match field.get(b"Kids").and_then(resolve) {
+        Some(PdfSyntaxObject::Array(kids)) => kids,
+        _ => Vec::new(),
+    };
+
+    if value.is_some() || kids.is_empty() {
+        result.insert(
+            name.clone(),
+            value.map(|value| values_of(&value)).unwrap_or_default(),
+        );
+    }
+
+    for kid in kids.iter() {
+        collect_field(kid, name.as_str(), resolve, result, visited, depth + 1);
+    }
+}
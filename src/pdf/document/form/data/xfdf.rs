@@ -0,0 +1,347 @@
+//! Serialization and parsing of form field values in XML Forms Data Format (XFDF).
+
+use crate::error::PdfiumError;
+use crate::pdf::document::form::data::{qualified_name, PdfFormDataNode};
+use std::collections::HashMap;
+
+/// Serializes the given tree of fields into a new XFDF document.
+pub(super) fn serialize(fields: &[PdfFormDataNode]) -> String {
+    let mut result = String::new();
+
+    result.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    result.push_str("<xfdf xmlns=\"http://ns.adobe.com/xfdf/\" xml:space=\"preserve\">\n");
+    result.push_str("  <fields>\n");
+
+    for field in fields {
+        write_field(&mut result, field, 2);
+    }
+
+    result.push_str("  </fields>\n");
+    result.push_str("</xfdf>\n");
+
+    result
+}
+
+fn write_field(output: &mut String, field: &PdfFormDataNode, depth: usize) {
+    let indent = "  ".repeat(depth);
+
+    output.push_str(&indent);
+    output.push_str("<field name=\"");
+    output.push_str(&escape(field.partial_name.as_str()));
+    output.push_str("\">");
+
+    if !field.kids.is_empty() {
+        output.push('\n');
+
+        for kid in field.kids.iter() {
+            write_field(output, kid, depth + 1);
+        }
+    }
+
+    for value in field.values.iter() {
+        if !field.kids.is_empty() {
+            output.push_str(&indent);
+            output.push_str("  ");
+        }
+
+        output.push_str("<value>");
+        output.push_str(&escape(value.as_str()));
+        output.push_str("</value>");
+
+        if !field.kids.is_empty() {
+            output.push('\n');
+        }
+    }
+
+    if !field.kids.is_empty() {
+        output.push_str(&indent);
+    }
+
+    output.push_str("</field>\n");
+}
+
+/// Escapes the given text for use in XML character data or attribute values.
+fn escape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&apos;"),
+            '\r' => result.push_str("&#13;"),
+            c => result.push(c),
+        }
+    }
+
+    result
+}
+
+/// Replaces the XML character and entity references in the given text.
+fn unescape(text: &str) -> Result<String, PdfiumError> {
+    let mut result = String::with_capacity(text.len());
+
+    let mut remaining = text;
+
+    while let Some(start) = remaining.find('&') {
+        result.push_str(&remaining[..start]);
+
+        let end = remaining[start..]
+            .find(';')
+            .map(|end| start + end)
+            .ok_or_else(|| error("Unterminated entity reference"))?;
+
+        let entity = &remaining[start + 1..end];
+
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => {
+                if let Some(hex) = entity.strip_prefix("#x") {
+                    u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+                } else if let Some(decimal) = entity.strip_prefix('#') {
+                    decimal.parse::<u32>().ok().and_then(char::from_u32)
+                } else {
+                    None
+                }
+            }
+        };
+
+        result.push(decoded.ok_or_else(|| error(&format!("Unknown entity &{};", entity)))?);
+
+        remaining = &remaining[end + 1..];
+    }
+
+    result.push_str(remaining);
+
+    Ok(result)
+}
+
+#[inline]
+fn error(message: &str) -> PdfiumError {
+    PdfiumError::ParseFormDataError(message.to_string())
+}
+
+/// A single field element encountered while parsing.
+struct OpenField {
+    name: String,
+    values: Vec<String>,
+    has_kids: bool,
+}
+
+/// Parses the given XFDF document, returning a map of (field name, field values) pairs.
+pub(super) fn parse(data: &[u8]) -> Result<HashMap<String, Vec<String>>, PdfiumError> {
+    let text = std::str::from_utf8(data).map_err(|err| error(&err.to_string()))?;
+
+    let mut result = HashMap::new();
+
+    // The names of all currently open elements.
+
+    let mut elements: Vec<String> = Vec::new();
+
+    let mut fields: Vec<OpenField> = Vec::new();
+
+    let mut value: Option<String> = None;
+
+    let mut is_xfdf = false;
+
+    let mut remaining = text;
+
+    while !remaining.is_empty() {
+        let start = match remaining.find('<') {
+            Some(start) => start,
+            None => break,
+        };
+
+        if let Some(value) = value.as_mut() {
+            value.push_str(&unescape(&remaining[..start])?);
+        }
+
+        remaining = &remaining[start..];
+
+        if let Some(rest) = remaining.strip_prefix("<?") {
+            remaining = skip_past(rest, "?>")?;
+        } else if let Some(rest) = remaining.strip_prefix("<!--") {
+            remaining = skip_past(rest, "-->")?;
+        } else if let Some(rest) = remaining.strip_prefix("<![CDATA[") {
+            let end = rest
+                .find("]]>")
+                .ok_or_else(|| error("Unterminated CDATA section"))?;
+
+            if let Some(value) = value.as_mut() {
+                value.push_str(&rest[..end]);
+            }
+
+            remaining = &rest[end + 3..];
+        } else if let Some(rest) = remaining.strip_prefix("<!") {
+            remaining = skip_past(rest, ">")?;
+        } else if let Some(rest) = remaining.strip_prefix("</") {
+            let end = rest
+                .find('>')
+                .ok_or_else(|| error("Unterminated end tag"))?;
+
+            let name = rest[..end].trim();
+
+            match elements.pop() {
+                Some(open) if open == name => {}
+                _ => return Err(error(&format!("Unexpected end tag </{}>", name))),
+            }
+
+            match local_name(name) {
+                "value" => {
+                    // A multiple selection field has one value element for each
+                    // selected value.
+
+                    if let (Some(field), Some(value)) = (fields.last_mut(), value.take()) {
+                        field.values.push(value);
+                    }
+                }
+                "field" => close_field(&mut fields, &mut result),
+                _ => {}
+            }
+
+            remaining = &rest[end + 1..];
+        } else {
+            let rest = &remaining[1..];
+
+            let end = find_tag_end(rest).ok_or_else(|| error("Unterminated start tag"))?;
+
+            let is_empty_element = rest[..end].ends_with('/');
+
+            let tag = rest[..end].trim_end_matches('/');
+
+            let name_end = tag.find(|c: char| c.is_whitespace()).unwrap_or(tag.len());
+
+            let name = &tag[..name_end];
+
+            if name.is_empty() {
+                return Err(error("Missing element name"));
+            }
+
+            match local_name(name) {
+                "xfdf" => is_xfdf = true,
+                "field" => {
+                    if let Some(parent) = fields.last_mut() {
+                        parent.has_kids = true;
+                    }
+
+                    fields.push(OpenField {
+                        name: attribute(&tag[name_end..], "name")?.unwrap_or_default(),
+                        values: Vec::new(),
+                        has_kids: false,
+                    });
+
+                    if is_empty_element {
+                        close_field(&mut fields, &mut result);
+                    }
+                }
+                "value" if !fields.is_empty() && !is_empty_element => {
+                    value = Some(String::new());
+                }
+                _ => {}
+            }
+
+            if !is_empty_element {
+                elements.push(name.to_string());
+            }
+
+            remaining = &rest[end + 1..];
+        }
+    }
+
+    if !is_xfdf {
+        return Err(error("Missing <xfdf> root element"));
+    }
+
+    if let Some(open) = elements.last() {
+        return Err(error(&format!("Unclosed element <{}>", open)));
+    }
+
+    Ok(result)
+}
+
+/// Closes the innermost open field, adding its values to the given map of
+/// (field name, field values) pairs.
+fn close_field(fields: &mut Vec<OpenField>, result: &mut HashMap<String, Vec<String>>) {
+    if let Some(field) = fields.pop() {
+        if !field.values.is_empty() || !field.has_kids {
+            let parent = fields.iter().fold(String::new(), |parent, open| {
+                qualified_name(parent.as_str(), open.name.as_str())
+            });
+
+            result.insert(
+                qualified_name(parent.as_str(), field.name.as_str()),
+                field.values,
+            );
+        }
+    }
+}
+
+/// Returns the given text after the first occurrence of the given terminator.
+fn skip_past<'a>(text: &'a str, terminator: &str) -> Result<&'a str, PdfiumError> {
+    text.find(terminator)
+        .map(|end| &text[end + terminator.len()..])
+        .ok_or_else(|| error(&format!("Expected {}", terminator)))
+}
+
+/// Returns the position of the closing `>` of a start tag, ignoring any `>` characters
+/// inside quoted attribute values.
+fn find_tag_end(text: &str) -> Option<usize> {
+    let mut quote = None;
+
+    for (index, c) in text.char_indices() {
+        match (quote, c) {
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (Some(open), c) if c == open => quote = None,
+            (None, '>') => return Some(index),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Returns the local part of the given element name, stripping any namespace prefix.
+#[inline]
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+/// Returns the unescaped value of the attribute with the given name in the given
+/// attribute list, if any.
+fn attribute(attributes: &str, name: &str) -> Result<Option<String>, PdfiumError> {
+    let mut remaining = attributes.trim_start();
+
+    while !remaining.is_empty() {
+        let equals = remaining
+            .find('=')
+            .ok_or_else(|| error("Malformed attribute"))?;
+
+        let key = remaining[..equals].trim();
+
+        let rest = remaining[equals + 1..].trim_start();
+
+        let quote = rest
+            .chars()
+            .next()
+            .filter(|c| *c == '"' || *c == '\'')
+            .ok_or_else(|| error("Unquoted attribute value"))?;
+
+        let end = rest[1..]
+            .find(quote)
+            .ok_or_else(|| error("Unterminated attribute value"))?;
+
+        if key == name {
+            return unescape(&rest[1..end + 1]).map(Some);
+        }
+
+        remaining = rest[end + 2..].trim_start();
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,104 @@
+//! Defines the [PdfJavaScriptAction] struct, exposing functionality related to a single
+//! document-level JavaScript action in a `PdfJavaScriptActions` collection.
+
+use crate::bindgen::{FPDF_JAVASCRIPT_ACTION, FPDF_WCHAR};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::utils::mem::create_byte_buffer;
+use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+
+/// A single named JavaScript action embedded at the document level in a `PdfDocument`.
+///
+/// Document-level JavaScript actions are stored in the /JavaScript entry of the document's
+/// name dictionary. They are executed by conforming viewers when the document is opened.
+pub struct PdfJavaScriptAction<'a> {
+    handle: FPDF_JAVASCRIPT_ACTION,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfJavaScriptAction<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        handle: FPDF_JAVASCRIPT_ACTION,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfJavaScriptAction { handle, bindings }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfJavaScriptAction].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the name of this [PdfJavaScriptAction], if any.
+    pub fn name(&self) -> Option<String> {
+        // Retrieving the name from Pdfium is a two-step operation. First, we call
+        // FPDFJavaScriptAction_GetName() with a null buffer; this will retrieve the length of
+        // the name in bytes, including the trailing nul. If the length is zero, then
+        // the action handle is invalid.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDFJavaScriptAction_GetName() again with a pointer to the buffer;
+        // this will write the name to the buffer in UTF16-LE format.
+
+        let buffer_length =
+            self.bindings()
+                .FPDFJavaScriptAction_GetName(self.handle, std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings().FPDFJavaScriptAction_GetName(
+            self.handle,
+            buffer.as_mut_ptr() as *mut FPDF_WCHAR,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        get_string_from_pdfium_utf16le_bytes(buffer)
+    }
+
+    /// Returns the JavaScript source code of this [PdfJavaScriptAction], if any.
+    pub fn script(&self) -> Option<String> {
+        // Retrieving the script from Pdfium is a two-step operation. First, we call
+        // FPDFJavaScriptAction_GetScript() with a null buffer; this will retrieve the length of
+        // the script in bytes, including the trailing nul. If the length is zero, then
+        // the action handle is invalid.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDFJavaScriptAction_GetScript() again with a pointer to the buffer;
+        // this will write the script to the buffer in UTF16-LE format.
+
+        let buffer_length =
+            self.bindings()
+                .FPDFJavaScriptAction_GetScript(self.handle, std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings().FPDFJavaScriptAction_GetScript(
+            self.handle,
+            buffer.as_mut_ptr() as *mut FPDF_WCHAR,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        get_string_from_pdfium_utf16le_bytes(buffer)
+    }
+}
+
+impl<'a> Drop for PdfJavaScriptAction<'a> {
+    /// Closes this [PdfJavaScriptAction], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFDoc_CloseJavaScriptAction(self.handle);
+    }
+}
@@ -0,0 +1,191 @@
+//! Defines the [PdfJavaScriptActions] struct, a collection of all the document-level
+//! `PdfJavaScriptAction` objects in a `PdfDocument`.
+
+use crate::bindgen::FPDF_DOCUMENT;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::javascript_action::PdfJavaScriptAction;
+use std::ops::{Range, RangeInclusive};
+use std::os::raw::c_int;
+
+pub type PdfJavaScriptActionIndex = usize;
+
+/// The collection of document-level [PdfJavaScriptAction] objects inside a `PdfDocument`.
+///
+/// JavaScript attached to individual form fields is not included in this collection;
+/// it can be retrieved from each form field using the `PdfFormFieldCommon::javascript()` function.
+pub struct PdfJavaScriptActions<'a> {
+    document_handle: FPDF_DOCUMENT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfJavaScriptActions<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        document_handle: FPDF_DOCUMENT,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfJavaScriptActions {
+            document_handle,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfJavaScriptActions] collection.
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the number of JavaScript actions in this [PdfJavaScriptActions] collection.
+    pub fn len(&self) -> PdfJavaScriptActionIndex {
+        // FPDFDoc_GetJavaScriptActionCount() returns -1 on error.
+
+        self.bindings()
+            .FPDFDoc_GetJavaScriptActionCount(self.document_handle)
+            .max(0) as PdfJavaScriptActionIndex
+    }
+
+    /// Returns `true` if this [PdfJavaScriptActions] collection is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a Range from `0..(number of JavaScript actions)` for this
+    /// [PdfJavaScriptActions] collection.
+    #[inline]
+    pub fn as_range(&self) -> Range<PdfJavaScriptActionIndex> {
+        0..self.len()
+    }
+
+    /// Returns an inclusive Range from `0..=(number of JavaScript actions - 1)`
+    /// for this [PdfJavaScriptActions] collection.
+    #[inline]
+    pub fn as_range_inclusive(&self) -> RangeInclusive<PdfJavaScriptActionIndex> {
+        if self.is_empty() {
+            0..=0
+        } else {
+            0..=(self.len() - 1)
+        }
+    }
+
+    /// Returns a single [PdfJavaScriptAction] from this [PdfJavaScriptActions] collection.
+    pub fn get(
+        &self,
+        index: PdfJavaScriptActionIndex,
+    ) -> Result<PdfJavaScriptAction<'a>, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::JavaScriptActionIndexOutOfBounds);
+        }
+
+        let handle = self
+            .bindings()
+            .FPDFDoc_GetJavaScriptAction(self.document_handle, index as c_int);
+
+        if handle.is_null() {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        } else {
+            Ok(PdfJavaScriptAction::from_pdfium(handle, self.bindings()))
+        }
+    }
+
+    /// Returns an iterator over all the JavaScript actions in this [PdfJavaScriptActions] collection.
+    #[inline]
+    pub fn iter(&self) -> PdfJavaScriptActionsIterator<'_> {
+        PdfJavaScriptActionsIterator::new(self)
+    }
+}
+
+/// An iterator over all the [PdfJavaScriptAction] objects in a [PdfJavaScriptActions] collection.
+pub struct PdfJavaScriptActionsIterator<'a> {
+    actions: &'a PdfJavaScriptActions<'a>,
+    next_index: PdfJavaScriptActionIndex,
+}
+
+impl<'a> PdfJavaScriptActionsIterator<'a> {
+    #[inline]
+    pub(crate) fn new(actions: &'a PdfJavaScriptActions<'a>) -> Self {
+        PdfJavaScriptActionsIterator {
+            actions,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfJavaScriptActionsIterator<'a> {
+    type Item = PdfJavaScriptAction<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.actions.get(self.next_index);
+
+        self.next_index += 1;
+
+        next.ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_document_javascript_actions() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/javascript-test.pdf", None)?;
+
+        let actions = document.javascript();
+
+        assert_eq!(actions.len(), 1);
+
+        let action = actions.get(0)?;
+
+        assert_eq!(action.name(), Some("init".to_string()));
+        assert_eq!(
+            action.script(),
+            Some("app.alert(\"document opened\");".to_string())
+        );
+        assert_eq!(actions.iter().count(), 1);
+        assert!(actions.get(1).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_form_field_javascript_actions() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/javascript-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let annotation = page.annotations().get(0)?;
+
+        let field = annotation.as_form_field().unwrap();
+
+        assert!(field.has_field_event_javascript());
+        assert_eq!(
+            field.field_event_javascripts(),
+            vec![
+                (
+                    PdfFormFieldAdditionalActionEvent::KeyStroke,
+                    "AFNumber_Keystroke(2, 0, 0, 0, \"\", true);".to_string()
+                ),
+                (
+                    PdfFormFieldAdditionalActionEvent::Format,
+                    "AFNumber_Format(2, 0, 0, 0, \"\", true);".to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            field.javascript(PdfFormFieldAdditionalActionEvent::Validate),
+            None
+        );
+
+        Ok(())
+    }
+}
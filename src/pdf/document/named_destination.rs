@@ -0,0 +1,50 @@
+//! Defines the [PdfNamedDestination] struct, exposing functionality related to a single
+//! named destination in a `PdfNamedDestinations` collection.
+
+use crate::bindings::PdfiumLibraryBindings;
+use crate::pdf::destination::PdfDestination;
+
+/// A single named destination in a `PdfDocument`.
+///
+/// Named destinations are stored either in the /Dests entry of the document's name dictionary
+/// or in the older /Dests entry of the document catalog. They allow links, bookmarks, and
+/// external references (such as the `#nameddest=` fragment of a URL) to refer to a location
+/// in the document by name rather than by explicit page index.
+pub struct PdfNamedDestination<'a> {
+    name: String,
+    destination: PdfDestination<'a>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfNamedDestination<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        name: String,
+        destination: PdfDestination<'a>,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfNamedDestination {
+            name,
+            destination,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfNamedDestination].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the name of this [PdfNamedDestination].
+    #[inline]
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns the [PdfDestination] targeted by this [PdfNamedDestination].
+    #[inline]
+    pub fn destination(&self) -> &PdfDestination<'a> {
+        &self.destination
+    }
+}
@@ -0,0 +1,256 @@
+//! Defines the [PdfNamedDestinations] struct, a collection of all the `PdfNamedDestination`
+//! objects in a `PdfDocument`.
+
+use crate::bindgen::FPDF_DOCUMENT;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::destination::PdfDestination;
+use crate::pdf::document::named_destination::PdfNamedDestination;
+use crate::utils::mem::create_byte_buffer;
+use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use std::ops::{Range, RangeInclusive};
+use std::os::raw::{c_int, c_long, c_void};
+
+pub type PdfNamedDestinationIndex = usize;
+
+/// The collection of [PdfNamedDestination] objects inside a `PdfDocument`.
+pub struct PdfNamedDestinations<'a> {
+    document_handle: FPDF_DOCUMENT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfNamedDestinations<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        document_handle: FPDF_DOCUMENT,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfNamedDestinations {
+            document_handle,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfNamedDestinations] collection.
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the number of named destinations in this [PdfNamedDestinations] collection.
+    pub fn len(&self) -> PdfNamedDestinationIndex {
+        self.bindings().FPDF_CountNamedDests(self.document_handle) as PdfNamedDestinationIndex
+    }
+
+    /// Returns `true` if this [PdfNamedDestinations] collection is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a Range from `0..(number of named destinations)` for this
+    /// [PdfNamedDestinations] collection.
+    #[inline]
+    pub fn as_range(&self) -> Range<PdfNamedDestinationIndex> {
+        0..self.len()
+    }
+
+    /// Returns an inclusive Range from `0..=(number of named destinations - 1)`
+    /// for this [PdfNamedDestinations] collection.
+    #[inline]
+    pub fn as_range_inclusive(&self) -> RangeInclusive<PdfNamedDestinationIndex> {
+        if self.is_empty() {
+            0..=0
+        } else {
+            0..=(self.len() - 1)
+        }
+    }
+
+    /// Returns a single [PdfNamedDestination] from this [PdfNamedDestinations] collection.
+    ///
+    /// Returns [PdfiumError::NamedDestinationUnresolvable] if the entry at the given index
+    /// does not resolve to a valid destination. Pdfium does not provide the name of
+    /// such an entry.
+    pub fn get(
+        &self,
+        index: PdfNamedDestinationIndex,
+    ) -> Result<PdfNamedDestination<'a>, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::NamedDestinationIndexOutOfBounds);
+        }
+
+        // Retrieving the name from Pdfium is a two-step operation. First, we call
+        // FPDF_GetNamedDest() with a null buffer; this will retrieve the length of
+        // the name in bytes, including the trailing nul.
+
+        // We then reserve a byte buffer of the given length and call FPDF_GetNamedDest() again
+        // with a pointer to the buffer; this will write the name to the buffer in UTF16-LE format
+        // and return the destination handle. If the buffer is too small, the buffer length
+        // will be set to -1.
+
+        let mut buffer_length: c_long = 0;
+
+        self.bindings().FPDF_GetNamedDest(
+            self.document_handle,
+            index as c_int,
+            std::ptr::null_mut(),
+            &mut buffer_length,
+        );
+
+        if buffer_length <= 0 {
+            // Pdfium only reports the length of the name if the entry resolves to
+            // a valid destination array.
+
+            return Err(PdfiumError::NamedDestinationUnresolvable);
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let handle = self.bindings().FPDF_GetNamedDest(
+            self.document_handle,
+            index as c_int,
+            buffer.as_mut_ptr() as *mut c_void,
+            &mut buffer_length,
+        );
+
+        if handle.is_null() || buffer_length <= 0 {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        buffer.truncate(buffer_length as usize);
+
+        Ok(PdfNamedDestination::from_pdfium(
+            get_string_from_pdfium_utf16le_bytes(buffer).unwrap_or_default(),
+            PdfDestination::from_pdfium(self.document_handle, handle, self.bindings()),
+            self.bindings(),
+        ))
+    }
+
+    /// Returns the [PdfNamedDestination] with the given name from this [PdfNamedDestinations]
+    /// collection, if any.
+    ///
+    /// Names are matched exactly; no case folding or normalization is performed.
+    pub fn get_by_name(&self, name: &str) -> Option<PdfNamedDestination<'a>> {
+        let handle = self
+            .bindings()
+            .FPDF_GetNamedDestByName(self.document_handle, name);
+
+        if handle.is_null() {
+            None
+        } else {
+            Some(PdfNamedDestination::from_pdfium(
+                name.to_string(),
+                PdfDestination::from_pdfium(self.document_handle, handle, self.bindings()),
+                self.bindings(),
+            ))
+        }
+    }
+
+    /// Returns `true` if this [PdfNamedDestinations] collection contains a named destination
+    /// with the given name.
+    #[inline]
+    pub fn contains(&self, name: &str) -> bool {
+        self.get_by_name(name).is_some()
+    }
+
+    /// Returns an iterator over all the named destinations in this [PdfNamedDestinations]
+    /// collection.
+    ///
+    /// Entries that do not resolve to a valid destination are yielded as
+    /// [PdfiumError::NamedDestinationUnresolvable] errors, so that every entry counted by
+    /// [PdfNamedDestinations::len()] is visited.
+    #[inline]
+    pub fn iter(&self) -> PdfNamedDestinationsIterator<'_> {
+        PdfNamedDestinationsIterator::new(self)
+    }
+}
+
+/// An iterator over all the [PdfNamedDestination] objects in a [PdfNamedDestinations] collection.
+pub struct PdfNamedDestinationsIterator<'a> {
+    destinations: &'a PdfNamedDestinations<'a>,
+    next_index: PdfNamedDestinationIndex,
+}
+
+impl<'a> PdfNamedDestinationsIterator<'a> {
+    #[inline]
+    pub(crate) fn new(destinations: &'a PdfNamedDestinations<'a>) -> Self {
+        PdfNamedDestinationsIterator {
+            destinations,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfNamedDestinationsIterator<'a> {
+    type Item = Result<PdfNamedDestination<'a>, PdfiumError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_index >= self.destinations.len() {
+            return None;
+        }
+
+        let next = self.destinations.get(self.next_index);
+
+        self.next_index += 1;
+
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_named_destinations() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/named-destinations-test.pdf", None)?;
+
+        let destinations = document.named_destinations();
+
+        // The test document defines three entries in its name dictionary, one of which
+        // does not resolve to a destination, and one in the older /Dests dictionary of its
+        // document catalog.
+
+        assert_eq!(destinations.len(), 4);
+
+        let (resolved, unresolved): (Vec<_>, Vec<_>) = destinations
+            .iter()
+            .partition(|destination| destination.is_ok());
+
+        let mut names = resolved
+            .into_iter()
+            .map(|destination| destination.unwrap().name().to_string())
+            .collect::<Vec<_>>();
+
+        names.sort();
+
+        assert_eq!(names, vec!["Appendix", "Chapter1", "Chapter2"]);
+        assert_eq!(unresolved.len(), 1);
+        assert!(matches!(
+            unresolved[0],
+            Err(PdfiumError::NamedDestinationUnresolvable)
+        ));
+
+        for (name, page_index) in [("Chapter1", 0), ("Appendix", 1), ("Chapter2", 2)] {
+            let destination = destinations.get_by_name(name).unwrap();
+
+            assert_eq!(destination.name(), name);
+            assert_eq!(destination.destination().page_index()?, page_index);
+        }
+
+        assert!(destinations.contains("Chapter2"));
+        assert!(!destinations.contains("chapter2"));
+        assert!(destinations.get_by_name("Missing").is_none());
+        assert!(matches!(
+            destinations.get(4),
+            Err(PdfiumError::NamedDestinationIndexOutOfBounds)
+        ));
+
+        Ok(())
+    }
+}
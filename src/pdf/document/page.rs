@@ -5,12 +5,15 @@ pub mod annotation;
 pub mod annotations;
 pub mod boundaries;
 pub mod field;
+pub mod form_session;
 pub(crate) mod index_cache;
 pub mod links;
 pub mod object;
 pub mod objects;
 pub mod render_config;
+pub mod render_progressive;
 pub mod size;
+pub mod struct_tree;
 pub mod text;
 
 #[cfg(feature = "paragraph")]
@@ -19,22 +22,27 @@ pub mod paragraph;
 #[cfg(feature = "flatten")]
 mod flatten; // Keep internal flatten operation private.
 
-use crate::bindgen::{
-    FLATTEN_FAIL, FLATTEN_NOTHINGTODO, FLATTEN_SUCCESS, FLAT_PRINT, FPDF_DOCUMENT, FPDF_FORMHANDLE,
-    FPDF_PAGE,
-};
+mod redaction; // Keep internal redaction operation private.
+
+use crate::bindgen::{FPDF_BITMAP, FPDF_DOCUMENT, FPDF_FORMHANDLE, FPDF_PAGE};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::create_transform_setters;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::bitmap::{PdfBitmap, PdfBitmapFormat, Pixels};
 use crate::pdf::document::page::annotations::PdfPageAnnotations;
 use crate::pdf::document::page::boundaries::PdfPageBoundaries;
+use crate::pdf::document::page::form_session::PdfFormSession;
 use crate::pdf::document::page::index_cache::PdfPageIndexCache;
 use crate::pdf::document::page::links::PdfPageLinks;
 use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
 use crate::pdf::document::page::objects::PdfPageObjects;
+use crate::pdf::document::page::redaction::apply_redactions;
 use crate::pdf::document::page::render_config::{PdfRenderConfig, PdfRenderSettings};
+use crate::pdf::document::page::render_progressive::{
+    PdfPageProgressiveRender, PdfPageProgressiveRenderPause, PdfPageProgressiveRenderStatus,
+};
 use crate::pdf::document::page::size::PdfPagePaperSize;
+use crate::pdf::document::page::struct_tree::PdfStructTree;
 use crate::pdf::document::page::text::PdfPageText;
 use crate::pdf::font::PdfFont;
 use crate::pdf::matrix::{PdfMatrix, PdfMatrixValue};
@@ -44,6 +52,12 @@ use std::collections::{hash_map::Entry, HashMap};
 use std::f32::consts::{FRAC_PI_2, PI};
 use std::os::raw::{c_double, c_int};
 
+#[cfg(feature = "flatten")]
+use crate::pdf::document::page::flatten::flatten_page;
+
+#[cfg(not(feature = "flatten"))]
+use crate::bindgen::{FLATTEN_FAIL, FLATTEN_NOTHINGTODO, FLATTEN_SUCCESS, FLAT_PRINT};
+
 #[cfg(doc)]
 use crate::pdf::document::PdfDocument;
 
@@ -177,6 +191,10 @@ pub enum PdfPageContentRegenerationStrategy {
 /// * [PdfPage::links_mut()], a mutable collection of the links on the [PdfPage].
 /// * [PdfPage::objects()], an immutable collection of all the displayable objects on the [PdfPage].
 /// * [PdfPage::objects_mut()], a mutable collection of all the displayable objects on the [PdfPage].
+/// * [PdfPage::structure_tree()], the logical structure tree of the [PdfPage], if the containing
+///   document is tagged.
+/// * [PdfPage::form_session()], an interactive form filling session for the [PdfPage], if the
+///   containing document has an embedded form.
 pub struct PdfPage<'a> {
     document_handle: FPDF_DOCUMENT,
     page_handle: FPDF_PAGE,
@@ -397,6 +415,36 @@ impl<'a> PdfPage<'a> {
         }
     }
 
+    /// Returns the logical structure tree of this [PdfPage].
+    ///
+    /// Tagged PDF documents use the structure tree to describe the logical organisation of
+    /// the page content, such as headings, paragraphs, figures, and tables. If the containing
+    /// document is not tagged, the returned [PdfStructTree] will be empty.
+    pub fn structure_tree(&self) -> Result<PdfStructTree<'_>, PdfiumError> {
+        let handle = self.bindings().FPDF_StructTree_GetForPage(self.page_handle);
+
+        if handle.is_null() {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        } else {
+            Ok(PdfStructTree::from_pdfium(handle, self, self.bindings))
+        }
+    }
+
+    /// Starts a new interactive form filling session for this [PdfPage], allowing the
+    /// form fields on the page to be filled in by sending simulated mouse and keyboard events
+    /// to Pdfium's own form filling logic.
+    ///
+    /// Returns [PdfiumError::NoFormInDocument] if the containing document does not
+    /// have an embedded form.
+    pub fn form_session(&self) -> Result<PdfFormSession<'_>, PdfiumError> {
+        match self.form_handle {
+            Some(form_handle) => Ok(PdfFormSession::new(self, form_handle, self.bindings)),
+            None => Err(PdfiumError::NoFormInDocument),
+        }
+    }
+
     /// Returns an immutable collection of the annotations that have been added to this [PdfPage].
     pub fn annotations(&self) -> &PdfPageAnnotations<'a> {
         if self.regeneration_strategy == PdfPageContentRegenerationStrategy::AutomaticOnEveryChange
@@ -692,11 +740,17 @@ impl<'a> PdfPage<'a> {
             );
         }
 
-        if settings.do_render_form_data {
-            // Render the PDF page into the bitmap buffer, ignoring any custom transformation matrix.
-            // (Custom transforms cannot be applied to the rendering of form fields.)
+        if let Some(color_scheme) = settings.color_scheme.as_ref() {
+            // Render the PDF page into the bitmap buffer using the color scheme, ignoring any
+            // custom transformation matrix. Pdfium only supports color schemes in its progressive
+            // rendering pipeline; a pause callback that never requests a pause ensures the page
+            // is rendered in a single pass.
 
-            self.bindings.FPDF_RenderPageBitmap(
+            let mut never_pause = || false;
+
+            let mut pause = PdfPageProgressiveRenderPause::new(&mut never_pause);
+
+            let status = self.bindings.FPDF_RenderPageBitmapWithColorScheme_Start(
                 bitmap_handle,
                 self.page_handle,
                 0,
@@ -705,36 +759,40 @@ impl<'a> PdfPage<'a> {
                 settings.height,
                 settings.rotate,
                 settings.render_flags,
+                color_scheme,
+                pause.as_ifsdk_pause_mut_ptr(),
             );
 
-            if let Some(form_handle) = self.form_handle {
-                // Render user-supplied form data, if any, as an overlay on top of the page.
+            self.bindings.FPDF_RenderPage_Close(self.page_handle);
 
-                if let Some(form_field_highlight) = settings.form_field_highlight.as_ref() {
-                    for (form_field_type, (color, alpha)) in form_field_highlight.iter() {
-                        self.bindings.FPDF_SetFormFieldHighlightColor(
-                            form_handle,
-                            *form_field_type,
-                            *color,
-                        );
+            // Since rendering is never paused, any status other than completion indicates
+            // that Pdfium was unable to render the page.
 
-                        self.bindings
-                            .FPDF_SetFormFieldHighlightAlpha(form_handle, *alpha);
-                    }
-                }
+            if PdfPageProgressiveRenderStatus::from_pdfium(status)?
+                != PdfPageProgressiveRenderStatus::Complete
+            {
+                return Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure);
+            }
 
-                self.bindings.FPDF_FFLDraw(
-                    form_handle,
-                    bitmap_handle,
-                    self.page_handle,
-                    0,
-                    0,
-                    settings.width,
-                    settings.height,
-                    settings.rotate,
-                    settings.render_flags,
-                );
+            if settings.do_render_form_data {
+                self.render_form_data_into_bitmap(bitmap_handle, &settings);
             }
+        } else if settings.do_render_form_data {
+            // Render the PDF page into the bitmap buffer, ignoring any custom transformation matrix.
+            // (Custom transforms cannot be applied to the rendering of form fields.)
+
+            self.bindings.FPDF_RenderPageBitmap(
+                bitmap_handle,
+                self.page_handle,
+                0,
+                0,
+                settings.width,
+                settings.height,
+                settings.rotate,
+                settings.render_flags,
+            );
+
+            self.render_form_data_into_bitmap(bitmap_handle, &settings);
         } else {
             // Render the PDF page into the bitmap buffer, applying any custom transformation matrix.
 
@@ -752,6 +810,72 @@ impl<'a> PdfPage<'a> {
         Ok(())
     }
 
+    /// Renders user-supplied form data, if any, into the bitmap with the given handle
+    /// as an overlay on top of the page, using the given [PdfRenderSettings].
+    pub(crate) fn render_form_data_into_bitmap(
+        &self,
+        bitmap_handle: FPDF_BITMAP,
+        settings: &PdfRenderSettings,
+    ) {
+        if let Some(form_handle) = self.form_handle {
+            if let Some(form_field_highlight) = settings.form_field_highlight.as_ref() {
+                for (form_field_type, (color, alpha)) in form_field_highlight.iter() {
+                    self.bindings.FPDF_SetFormFieldHighlightColor(
+                        form_handle,
+                        *form_field_type,
+                        *color,
+                    );
+
+                    self.bindings
+                        .FPDF_SetFormFieldHighlightAlpha(form_handle, *alpha);
+                }
+            }
+
+            self.bindings.FPDF_FFLDraw(
+                form_handle,
+                bitmap_handle,
+                self.page_handle,
+                0,
+                0,
+                settings.width,
+                settings.height,
+                settings.rotate,
+                settings.render_flags,
+            );
+        }
+    }
+
+    /// Starts rendering this [PdfPage] into a new [PdfBitmap] using pixel dimensions,
+    /// page rotation settings, and rendering options configured in the given [PdfRenderConfig],
+    /// returning a [PdfPageProgressiveRender] handle that can be used to continue,
+    /// time-limit, or cancel the rendering operation.
+    ///
+    /// Only the first step of rendering is performed by this function. Call one of the
+    /// [PdfPageProgressiveRender::step()], [PdfPageProgressiveRender::step_for()],
+    /// [PdfPageProgressiveRender::step_until()], or [PdfPageProgressiveRender::finish()] functions
+    /// to continue rendering, and then retrieve the rendered image using
+    /// [PdfPageProgressiveRender::into_bitmap()]. Dropping the returned handle cancels rendering.
+    ///
+    /// Pdfium's progressive rendering API does not support custom transformation matrices.
+    /// Any transformation matrix or clipping rectangle configured in the given [PdfRenderConfig]
+    /// will be ignored.
+    pub fn render_progressively(
+        &self,
+        config: &PdfRenderConfig,
+    ) -> Result<PdfPageProgressiveRender<'_>, PdfiumError> {
+        let settings = config.apply_to_page(self);
+
+        let bitmap = PdfBitmap::empty(
+            settings.width as Pixels,
+            settings.height as Pixels,
+            PdfBitmapFormat::from_pdfium(settings.format as u32)
+                .unwrap_or_else(|_| PdfBitmapFormat::default()),
+            self.bindings,
+        )?;
+
+        PdfPageProgressiveRender::start(self, bitmap, settings, &mut || true)
+    }
+
     // TODO: AJRC - 29/7/22 - remove deprecated PdfPage::get_bitmap_*() functions in 0.9.0
     // as part of tracking issue https://github.com/ajrcarey/pdfium-render/issues/36
     /// Renders this [PdfPage] into a new [PdfBitmap] using pixel dimensions, rotation settings,
@@ -913,7 +1037,7 @@ impl<'a> PdfPage<'a> {
     // Use a custom-written flatten operation, rather than Pdfium's built-in flatten. See:
     // https://github.com/ajrcarey/pdfium-render/issues/140
     pub fn flatten(&mut self) -> Result<(), PdfiumError> {
-        flatten_page(self.handle())
+        flatten_page(self)
     }
 
     /// Flattens all annotations and form fields on this [PdfPage] into the page contents.
@@ -946,6 +1070,56 @@ impl<'a> PdfPage<'a> {
         }
     }
 
+    /// Applies all the `PdfPageAnnotationType::Redacted` annotations on this [PdfPage],
+    /// permanently removing the page content lying underneath each redaction, and returns
+    /// the number of redaction annotations applied.
+    ///
+    /// The area covered by each redaction is taken from the redaction annotation's attachment
+    /// points, or from its bounds if the annotation has no attachment points. Each attachment
+    /// point is treated as the smallest rectangle containing all four of its vertices.
+    /// Page content is removed as follows:
+    /// * Characters in text objects are removed if their loose bounds overlap a redacted area.
+    ///   The remaining characters in a partially redacted text object are re-created as new
+    ///   individual text objects at their original positions, added to the top of the page.
+    /// * Pixels in image objects are overwritten if they lie inside a redacted area.
+    ///   Image objects lying entirely inside a redacted area are removed, after their
+    ///   image data has been replaced with a single pixel of the redaction's fill color.
+    ///   Image data that is also used by another image object elsewhere in the document
+    ///   is left unchanged; a partially redacted image object using such image data is
+    ///   instead replaced by a redacted copy, added to the top of the page.
+    /// * Form XObjects cannot be redacted. If any form XObject overlaps a redacted area,
+    ///   a `PdfiumError::RedactionOfFormObjectNotSupported` error is returned and the page
+    ///   is left unchanged.
+    /// * All other page objects overlapping a redacted area, including path objects and
+    ///   shading objects, are removed entirely.
+    /// * Form field widgets overlapping a redacted area have their value and appearance
+    ///   cleared, along with the value of their parent form field, if any, and are removed.
+    /// * All other annotations overlapping a redacted area are removed, with the exception
+    ///   of popup annotations.
+    ///
+    /// Each redacted area is then painted over using the redaction annotation's fill color,
+    /// or black if no fill color is set, and the redaction annotations are removed from the page.
+    /// Content is removed whenever its bounds overlap a redacted area, so redaction may
+    /// remove more content than is visibly covered by the redaction annotations.
+    ///
+    /// The page content will be regenerated once all redactions have been applied,
+    /// irrespective of the content regeneration strategy of this [PdfPage].
+    ///
+    /// Pdfium does not provide a way to edit a page's resource dictionary, so entries in the
+    /// page's `/Resources` dictionary for fonts, images, and form XObjects that are no longer
+    /// used by the page are not removed, and will still be written out when the document
+    /// is saved. Removed image objects are not affected by this, since their image data is
+    /// replaced before they are removed.
+    ///
+    /// Redaction only removes content from the document when it is saved using
+    /// `PdfSaveMode::Rewrite` or `PdfSaveMode::RewriteWithoutOriginal`. Saving the document
+    /// using `PdfSaveMode::Incremental` appends the redacted page to the document's original
+    /// file data, which still contains the original, unredacted page content.
+    #[inline]
+    pub fn apply_redactions(&mut self) -> Result<usize, PdfiumError> {
+        apply_redactions(self)
+    }
+
     /// Deletes this [PdfPage] from its containing `PdfPages` collection, consuming this [PdfPage].
     pub fn delete(self) -> Result<(), PdfiumError> {
         let index = PdfPageIndexCache::get_index_for_page(self.document_handle, self.page_handle)
@@ -1153,4 +1327,91 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_progressive_rendering_matches_synchronous_rendering() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/export-test.pdf", None)?;
+
+        let render_config = PdfRenderConfig::new()
+            .set_target_width(1000)
+            .set_maximum_height(1000);
+
+        let page = document.pages().first()?;
+
+        let mut render = page.render_progressively(&render_config)?;
+
+        while render.step()? == PdfPageProgressiveRenderStatus::InProgress {}
+
+        assert!(render.is_complete());
+
+        let progressive = render.into_bitmap();
+
+        let synchronous = page.render_with_config(&render_config)?;
+
+        assert_eq!(progressive.as_raw_bytes(), synchronous.as_raw_bytes());
+
+        // A cancelled render should release the page for a subsequent render.
+
+        page.render_progressively(&render_config)?.cancel();
+
+        assert_eq!(
+            page.render_progressively(&render_config)?.finish()?,
+            PdfPageProgressiveRenderStatus::Complete
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_color_scheme_rendering() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::Custom(
+                PdfPoints::new(200.0),
+                PdfPoints::new(200.0),
+            ))?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(50.0, 50.0, 150.0, 150.0),
+            None,
+            None,
+            Some(PdfColor::BLUE),
+        )?;
+
+        let render_config = PdfRenderConfig::new().set_target_width(200);
+
+        let pixel = |config: &PdfRenderConfig, x: u32, y: u32| {
+            page.render_with_config(config)
+                .map(|bitmap| bitmap.as_image().get_pixel(x, y).0)
+        };
+
+        assert_eq!(pixel(&render_config, 100, 100)?, [0, 0, 255, 255]);
+
+        // A forced color scheme should override the fill color of the path,
+        // leaving the page background untouched.
+
+        let dark_config = PdfRenderConfig::new()
+            .set_target_width(200)
+            .set_color_scheme(PdfRenderColorScheme::monochrome(PdfColor::RED));
+
+        assert_eq!(pixel(&dark_config, 100, 100)?, [255, 0, 0, 255]);
+        assert_eq!(pixel(&dark_config, 10, 10)?, pixel(&render_config, 10, 10)?);
+
+        // Clearing the color scheme should restore the original rendering.
+
+        let restored_config = dark_config.clear_color_scheme();
+
+        assert_eq!(
+            page.render_with_config(&restored_config)?.as_raw_bytes(),
+            page.render_with_config(&render_config)?.as_raw_bytes()
+        );
+
+        Ok(())
+    }
 }
@@ -1,12 +1,20 @@
 //! Defines the [PdfPageAnnotation] struct, exposing functionality related to a single annotation.
 
 pub mod attachment_points;
+pub mod border;
+pub mod caret;
 pub mod circle;
+pub(crate) mod edit_cache;
+pub mod file_attachment;
 pub mod free_text;
 pub mod highlight;
 pub mod ink;
+pub mod ink_strokes;
+pub mod line;
 pub mod link;
 pub mod objects;
+pub mod polygon;
+pub mod polyline;
 pub mod popup;
 pub(crate) mod private; // Keep private so that the PdfPageAnnotationPrivate trait is not exposed.
 pub mod redacted;
@@ -22,7 +30,10 @@ pub mod xfa_widget;
 
 use crate::bindgen::{
     FPDF_ANNOTATION, FPDF_ANNOTATION_SUBTYPE, FPDF_ANNOT_CARET, FPDF_ANNOT_CIRCLE,
-    FPDF_ANNOT_FILEATTACHMENT, FPDF_ANNOT_FREETEXT, FPDF_ANNOT_HIGHLIGHT, FPDF_ANNOT_INK,
+    FPDF_ANNOT_FILEATTACHMENT, FPDF_ANNOT_FLAG_HIDDEN, FPDF_ANNOT_FLAG_INVISIBLE,
+    FPDF_ANNOT_FLAG_LOCKED, FPDF_ANNOT_FLAG_NOROTATE, FPDF_ANNOT_FLAG_NOVIEW,
+    FPDF_ANNOT_FLAG_NOZOOM, FPDF_ANNOT_FLAG_PRINT, FPDF_ANNOT_FLAG_READONLY,
+    FPDF_ANNOT_FLAG_TOGGLENOVIEW, FPDF_ANNOT_FREETEXT, FPDF_ANNOT_HIGHLIGHT, FPDF_ANNOT_INK,
     FPDF_ANNOT_LINE, FPDF_ANNOT_LINK, FPDF_ANNOT_MOVIE, FPDF_ANNOT_POLYGON, FPDF_ANNOT_POLYLINE,
     FPDF_ANNOT_POPUP, FPDF_ANNOT_PRINTERMARK, FPDF_ANNOT_REDACT, FPDF_ANNOT_RICHMEDIA,
     FPDF_ANNOT_SCREEN, FPDF_ANNOT_SOUND, FPDF_ANNOT_SQUARE, FPDF_ANNOT_SQUIGGLY, FPDF_ANNOT_STAMP,
@@ -32,14 +43,24 @@ use crate::bindgen::{
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
+use crate::pdf::appearance_mode::PdfAppearanceMode;
 use crate::pdf::color::PdfColor;
 use crate::pdf::document::page::annotation::attachment_points::PdfPageAnnotationAttachmentPoints;
+use crate::pdf::document::page::annotation::border::{
+    PdfPageAnnotationBorder, PdfPageAnnotationBorderEffect, PdfPageAnnotationBorderStyle,
+};
+use crate::pdf::document::page::annotation::caret::PdfPageCaretAnnotation;
 use crate::pdf::document::page::annotation::circle::PdfPageCircleAnnotation;
+use crate::pdf::document::page::annotation::edit_cache::PdfPageAnnotationEditCache;
+use crate::pdf::document::page::annotation::file_attachment::PdfPageFileAttachmentAnnotation;
 use crate::pdf::document::page::annotation::free_text::PdfPageFreeTextAnnotation;
 use crate::pdf::document::page::annotation::highlight::PdfPageHighlightAnnotation;
 use crate::pdf::document::page::annotation::ink::PdfPageInkAnnotation;
+use crate::pdf::document::page::annotation::line::PdfPageLineAnnotation;
 use crate::pdf::document::page::annotation::link::PdfPageLinkAnnotation;
 use crate::pdf::document::page::annotation::objects::PdfPageAnnotationObjects;
+use crate::pdf::document::page::annotation::polygon::PdfPagePolygonAnnotation;
+use crate::pdf::document::page::annotation::polyline::PdfPagePolylineAnnotation;
 use crate::pdf::document::page::annotation::popup::PdfPagePopupAnnotation;
 use crate::pdf::document::page::annotation::private::internal::PdfPageAnnotationPrivate;
 use crate::pdf::document::page::annotation::redacted::PdfPageRedactedAnnotation;
@@ -53,20 +74,23 @@ use crate::pdf::document::page::annotation::unsupported::PdfPageUnsupportedAnnot
 use crate::pdf::document::page::annotation::widget::PdfPageWidgetAnnotation;
 use crate::pdf::document::page::annotation::xfa_widget::PdfPageXfaWidgetAnnotation;
 use crate::pdf::document::page::field::PdfFormField;
+use crate::pdf::document::page::objects::private::internal::PdfPageObjectsPrivate;
 use crate::pdf::points::PdfPoints;
 use crate::pdf::rect::PdfRect;
+use bitflags::bitflags;
 use chrono::prelude::*;
+use std::os::raw::c_int;
 
 /// The type of a single [PdfPageAnnotation], as defined in table 8.20 of the PDF Reference,
 /// version 1.7, on page 615.
 ///
 /// Not all PDF annotation types are supported by Pdfium. For example, Pdfium does not
-/// currently support embedded sound or movie file annotations, embedded 3D animations, or
-/// annotations containing embedded file attachments.
+/// currently support embedded sound or movie file annotations or embedded 3D animations.
 ///
 /// Pdfium currently supports creating, editing, and rendering the following types of annotations:
 ///
 /// * [PdfPageAnnotationType::Circle]
+/// * [PdfPageAnnotationType::FileAttachment]
 /// * [PdfPageAnnotationType::FreeText]
 /// * [PdfPageAnnotationType::Highlight]
 /// * [PdfPageAnnotationType::Ink]
@@ -82,6 +106,17 @@ use chrono::prelude::*;
 /// * [PdfPageAnnotationType::Widget]
 /// * [PdfPageAnnotationType::XfaWidget]
 ///
+/// Pdfium can also edit and render, but not create, the following types of annotations:
+///
+/// * [PdfPageAnnotationType::Caret]
+/// * [PdfPageAnnotationType::Line]
+/// * [PdfPageAnnotationType::Polygon]
+/// * [PdfPageAnnotationType::Polyline]
+///
+/// Pdfium refuses to create annotations of these types, and provides no way to set an existing
+/// annotation's subtype, line end points, or vertices, so annotations of these types can only be
+/// read from, and edited in, documents that already contain them.
+///
 /// Note that a `FreeText` annotation is rendered directly on the page, whereas a `Text` annotation
 /// floats over the page inside its own enclosed area. Adobe often uses the term "sticky note"
 /// in reference to `Text` annotations to distinguish them from `FreeText` annotations.
@@ -193,13 +228,55 @@ impl PdfPageAnnotationType {
     }
 }
 
+bitflags! {
+    /// The flags controlling the visibility and behavior of a single [PdfPageAnnotation],
+    /// as defined in table 8.16 of the PDF Reference, version 1.7, on page 608.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+    pub struct PdfPageAnnotationFlags: u32 {
+        /// Do not display the annotation if it is of a type not supported by the viewer.
+        const INVISIBLE = FPDF_ANNOT_FLAG_INVISIBLE;
+
+        /// Do not display, print, or allow interaction with the annotation.
+        const HIDDEN = FPDF_ANNOT_FLAG_HIDDEN;
+
+        /// Include the annotation when the page is printed.
+        const PRINT = FPDF_ANNOT_FLAG_PRINT;
+
+        /// Do not scale the annotation's appearance to match the page's magnification.
+        const NO_ZOOM = FPDF_ANNOT_FLAG_NOZOOM;
+
+        /// Do not rotate the annotation's appearance to match the page's rotation.
+        const NO_ROTATE = FPDF_ANNOT_FLAG_NOROTATE;
+
+        /// Do not display or allow interaction with the annotation on screen,
+        /// but still allow it to be printed.
+        const NO_VIEW = FPDF_ANNOT_FLAG_NOVIEW;
+
+        /// Do not allow the user to interact with the annotation.
+        const READ_ONLY = FPDF_ANNOT_FLAG_READONLY;
+
+        /// Do not allow the annotation to be deleted, or its properties to be modified,
+        /// by the user. The annotation's contents may still be modified.
+        const LOCKED = FPDF_ANNOT_FLAG_LOCKED;
+
+        /// Invert the interpretation of the [PdfPageAnnotationFlags::NO_VIEW] flag
+        /// for certain events.
+        const TOGGLE_NO_VIEW = FPDF_ANNOT_FLAG_TOGGLENOVIEW;
+    }
+}
+
 /// A single user annotation on a `PdfPage`.
 pub enum PdfPageAnnotation<'a> {
+    Caret(PdfPageCaretAnnotation<'a>),
     Circle(PdfPageCircleAnnotation<'a>),
+    FileAttachment(PdfPageFileAttachmentAnnotation<'a>),
     FreeText(PdfPageFreeTextAnnotation<'a>),
     Highlight(PdfPageHighlightAnnotation<'a>),
     Ink(PdfPageInkAnnotation<'a>),
+    Line(PdfPageLineAnnotation<'a>),
     Link(PdfPageLinkAnnotation<'a>),
+    Polygon(PdfPagePolygonAnnotation<'a>),
+    Polyline(PdfPagePolylineAnnotation<'a>),
     Popup(PdfPagePopupAnnotation<'a>),
     Square(PdfPageSquareAnnotation<'a>),
     Squiggly(PdfPageSquigglyAnnotation<'a>),
@@ -230,6 +307,14 @@ impl<'a> PdfPageAnnotation<'a> {
                 .unwrap_or(PdfPageAnnotationType::Unknown);
 
         match annotation_type {
+            PdfPageAnnotationType::Caret => {
+                PdfPageAnnotation::Caret(PdfPageCaretAnnotation::from_pdfium(
+                    document_handle,
+                    page_handle,
+                    annotation_handle,
+                    bindings,
+                ))
+            }
             PdfPageAnnotationType::Circle => {
                 PdfPageAnnotation::Circle(PdfPageCircleAnnotation::from_pdfium(
                     document_handle,
@@ -238,11 +323,20 @@ impl<'a> PdfPageAnnotation<'a> {
                     bindings,
                 ))
             }
+            PdfPageAnnotationType::FileAttachment => {
+                PdfPageAnnotation::FileAttachment(PdfPageFileAttachmentAnnotation::from_pdfium(
+                    document_handle,
+                    page_handle,
+                    annotation_handle,
+                    bindings,
+                ))
+            }
             PdfPageAnnotationType::FreeText => {
                 PdfPageAnnotation::FreeText(PdfPageFreeTextAnnotation::from_pdfium(
                     document_handle,
                     page_handle,
                     annotation_handle,
+                    form_handle,
                     bindings,
                 ))
             }
@@ -262,6 +356,14 @@ impl<'a> PdfPageAnnotation<'a> {
                     bindings,
                 ))
             }
+            PdfPageAnnotationType::Line => {
+                PdfPageAnnotation::Line(PdfPageLineAnnotation::from_pdfium(
+                    document_handle,
+                    page_handle,
+                    annotation_handle,
+                    bindings,
+                ))
+            }
             PdfPageAnnotationType::Link => {
                 PdfPageAnnotation::Link(PdfPageLinkAnnotation::from_pdfium(
                     document_handle,
@@ -270,6 +372,22 @@ impl<'a> PdfPageAnnotation<'a> {
                     bindings,
                 ))
             }
+            PdfPageAnnotationType::Polygon => {
+                PdfPageAnnotation::Polygon(PdfPagePolygonAnnotation::from_pdfium(
+                    document_handle,
+                    page_handle,
+                    annotation_handle,
+                    bindings,
+                ))
+            }
+            PdfPageAnnotationType::Polyline => {
+                PdfPageAnnotation::Polyline(PdfPagePolylineAnnotation::from_pdfium(
+                    document_handle,
+                    page_handle,
+                    annotation_handle,
+                    bindings,
+                ))
+            }
             PdfPageAnnotationType::Popup => {
                 PdfPageAnnotation::Popup(PdfPagePopupAnnotation::from_pdfium(
                     document_handle,
@@ -365,11 +483,16 @@ impl<'a> PdfPageAnnotation<'a> {
     #[inline]
     pub(crate) fn unwrap_as_trait(&self) -> &dyn PdfPageAnnotationPrivate<'a> {
         match self {
+            PdfPageAnnotation::Caret(annotation) => annotation,
             PdfPageAnnotation::Circle(annotation) => annotation,
+            PdfPageAnnotation::FileAttachment(annotation) => annotation,
             PdfPageAnnotation::FreeText(annotation) => annotation,
             PdfPageAnnotation::Highlight(annotation) => annotation,
             PdfPageAnnotation::Ink(annotation) => annotation,
+            PdfPageAnnotation::Line(annotation) => annotation,
             PdfPageAnnotation::Link(annotation) => annotation,
+            PdfPageAnnotation::Polygon(annotation) => annotation,
+            PdfPageAnnotation::Polyline(annotation) => annotation,
             PdfPageAnnotation::Popup(annotation) => annotation,
             PdfPageAnnotation::Square(annotation) => annotation,
             PdfPageAnnotation::Squiggly(annotation) => annotation,
@@ -387,11 +510,16 @@ impl<'a> PdfPageAnnotation<'a> {
     #[inline]
     pub(crate) fn unwrap_as_trait_mut(&mut self) -> &mut dyn PdfPageAnnotationPrivate<'a> {
         match self {
+            PdfPageAnnotation::Caret(annotation) => annotation,
             PdfPageAnnotation::Circle(annotation) => annotation,
+            PdfPageAnnotation::FileAttachment(annotation) => annotation,
             PdfPageAnnotation::FreeText(annotation) => annotation,
             PdfPageAnnotation::Highlight(annotation) => annotation,
             PdfPageAnnotation::Ink(annotation) => annotation,
+            PdfPageAnnotation::Line(annotation) => annotation,
             PdfPageAnnotation::Link(annotation) => annotation,
+            PdfPageAnnotation::Polygon(annotation) => annotation,
+            PdfPageAnnotation::Polyline(annotation) => annotation,
             PdfPageAnnotation::Popup(annotation) => annotation,
             PdfPageAnnotation::Square(annotation) => annotation,
             PdfPageAnnotation::Squiggly(annotation) => annotation,
@@ -409,34 +537,22 @@ impl<'a> PdfPageAnnotation<'a> {
     /// The type of this [PdfPageAnnotation].
     ///
     /// Not all PDF annotation types are supported by Pdfium. For example, Pdfium does not
-    /// currently support embedded sound or movie file annotations, embedded 3D animations, or
-    /// annotations containing embedded file attachments.
-    ///
-    /// Pdfium currently supports creating, editing, and rendering the following types of annotations:
-    ///
-    /// * [PdfPageAnnotationType::Circle]
-    /// * [PdfPageAnnotationType::FreeText]
-    /// * [PdfPageAnnotationType::Highlight]
-    /// * [PdfPageAnnotationType::Ink]
-    /// * [PdfPageAnnotationType::Link]
-    /// * [PdfPageAnnotationType::Popup]
-    /// * [PdfPageAnnotationType::Redacted]
-    /// * [PdfPageAnnotationType::Square]
-    /// * [PdfPageAnnotationType::Squiggly]
-    /// * [PdfPageAnnotationType::Stamp]
-    /// * [PdfPageAnnotationType::Strikeout]
-    /// * [PdfPageAnnotationType::Text]
-    /// * [PdfPageAnnotationType::Underline]
-    /// * [PdfPageAnnotationType::Widget]
-    /// * [PdfPageAnnotationType::XfaWidget]
+    /// currently support embedded sound or movie file annotations or embedded 3D animations.
+    /// See [PdfPageAnnotationType] for the types of annotations Pdfium can create, edit,
+    /// and render.
     #[inline]
     pub fn annotation_type(&self) -> PdfPageAnnotationType {
         match self {
+            PdfPageAnnotation::Caret(_) => PdfPageAnnotationType::Caret,
             PdfPageAnnotation::Circle(_) => PdfPageAnnotationType::Circle,
+            PdfPageAnnotation::FileAttachment(_) => PdfPageAnnotationType::FileAttachment,
             PdfPageAnnotation::FreeText(_) => PdfPageAnnotationType::FreeText,
             PdfPageAnnotation::Highlight(_) => PdfPageAnnotationType::Highlight,
             PdfPageAnnotation::Ink(_) => PdfPageAnnotationType::Ink,
+            PdfPageAnnotation::Line(_) => PdfPageAnnotationType::Line,
             PdfPageAnnotation::Link(_) => PdfPageAnnotationType::Link,
+            PdfPageAnnotation::Polygon(_) => PdfPageAnnotationType::Polygon,
+            PdfPageAnnotation::Polyline(_) => PdfPageAnnotationType::Polyline,
             PdfPageAnnotation::Popup(_) => PdfPageAnnotationType::Popup,
             PdfPageAnnotation::Square(_) => PdfPageAnnotationType::Square,
             PdfPageAnnotation::Squiggly(_) => PdfPageAnnotationType::Squiggly,
@@ -455,26 +571,9 @@ impl<'a> PdfPageAnnotation<'a> {
     /// [PdfPageAnnotation].
     ///
     /// Not all PDF annotation types are supported by Pdfium. For example, Pdfium does not
-    /// currently support embedded sound or movie file annotations, embedded 3D animations, or
-    /// annotations containing embedded file attachments.
-    ///
-    /// Pdfium currently supports creating, editing, and rendering the following types of annotations:
-    ///
-    /// * [PdfPageAnnotationType::Circle]
-    /// * [PdfPageAnnotationType::FreeText]
-    /// * [PdfPageAnnotationType::Highlight]
-    /// * [PdfPageAnnotationType::Ink]
-    /// * [PdfPageAnnotationType::Link]
-    /// * [PdfPageAnnotationType::Popup]
-    /// * [PdfPageAnnotationType::Redacted]
-    /// * [PdfPageAnnotationType::Square]
-    /// * [PdfPageAnnotationType::Squiggly]
-    /// * [PdfPageAnnotationType::Stamp]
-    /// * [PdfPageAnnotationType::Strikeout]
-    /// * [PdfPageAnnotationType::Text]
-    /// * [PdfPageAnnotationType::Underline]
-    /// * [PdfPageAnnotationType::Widget]
-    /// * [PdfPageAnnotationType::XfaWidget]
+    /// currently support embedded sound or movie file annotations or embedded 3D animations.
+    /// See [PdfPageAnnotationType] for the types of annotations Pdfium can create, edit,
+    /// and render.
     #[inline]
     pub fn is_supported(&self) -> bool {
         !self.is_unsupported()
@@ -484,31 +583,151 @@ impl<'a> PdfPageAnnotation<'a> {
     /// [PdfPageAnnotation].
     ///
     /// Not all PDF annotation types are supported by Pdfium. For example, Pdfium does not
-    /// currently support embedded sound or movie file annotations, embedded 3D animations, or
-    /// annotations containing embedded file attachments.
-    ///
-    /// Pdfium currently supports creating, editing, and rendering the following types of annotations:
-    ///
-    /// * [PdfPageAnnotationType::Circle]
-    /// * [PdfPageAnnotationType::FreeText]
-    /// * [PdfPageAnnotationType::Highlight]
-    /// * [PdfPageAnnotationType::Ink]
-    /// * [PdfPageAnnotationType::Link]
-    /// * [PdfPageAnnotationType::Popup]
-    /// * [PdfPageAnnotationType::Redacted]
-    /// * [PdfPageAnnotationType::Square]
-    /// * [PdfPageAnnotationType::Squiggly]
-    /// * [PdfPageAnnotationType::Stamp]
-    /// * [PdfPageAnnotationType::Strikeout]
-    /// * [PdfPageAnnotationType::Text]
-    /// * [PdfPageAnnotationType::Underline]
-    /// * [PdfPageAnnotationType::Widget]
-    /// * [PdfPageAnnotationType::XfaWidget]
+    /// currently support embedded sound or movie file annotations or embedded 3D animations.
+    /// See [PdfPageAnnotationType] for the types of annotations Pdfium can create, edit,
+    /// and render.
     #[inline]
     pub fn is_unsupported(&self) -> bool {
         matches!(self, PdfPageAnnotation::Unsupported(_))
     }
 
+    /// Returns the pop-up annotation used to display the text of this markup [PdfPageAnnotation]
+    /// and any replies to it, as recorded in the annotation's `/Popup` dictionary entry, if any.
+    #[inline]
+    pub fn popup(&self) -> Option<PdfPageAnnotation<'a>> {
+        self.linked_annotation("Popup")
+    }
+
+    /// Sets the pop-up annotation used to display the text of this markup [PdfPageAnnotation]
+    /// and any replies to it, or removes the link to any existing pop-up annotation if the
+    /// given annotation is `None`. The pop-up annotation must be on the same page as this
+    /// [PdfPageAnnotation].
+    ///
+    /// The pop-up annotation's own link back to this [PdfPageAnnotation] is not changed;
+    /// use the [PdfPageAnnotation::set_parent()] function on the pop-up annotation to set it.
+    ///
+    /// Each link between annotations is an indirect reference to another annotation dictionary.
+    /// Pdfium does not currently provide any way to write an indirect reference into an
+    /// annotation dictionary, so the change is applied when the containing document is saved.
+    /// The [PdfPageAnnotation::popup()] function reflects the change immediately.
+    #[inline]
+    pub fn set_popup(&mut self, popup: Option<&PdfPageAnnotation>) -> Result<(), PdfiumError> {
+        self.set_linked_annotation_impl("Popup", popup)
+    }
+
+    /// Returns the markup annotation that this pop-up [PdfPageAnnotation] belongs to,
+    /// as recorded in the annotation's `/Parent` dictionary entry, if any.
+    #[inline]
+    pub fn parent(&self) -> Option<PdfPageAnnotation<'a>> {
+        self.linked_annotation("Parent")
+    }
+
+    /// Sets the markup annotation that this pop-up [PdfPageAnnotation] belongs to, or removes
+    /// the link to any existing parent annotation if the given annotation is `None`.
+    /// The parent annotation must be on the same page as this [PdfPageAnnotation].
+    ///
+    /// The change is applied when the containing document is saved; see the
+    /// [PdfPageAnnotation::set_popup()] function for details.
+    #[inline]
+    pub fn set_parent(&mut self, parent: Option<&PdfPageAnnotation>) -> Result<(), PdfiumError> {
+        self.set_linked_annotation_impl("Parent", parent)
+    }
+
+    /// Returns the annotation that this [PdfPageAnnotation] is a reply to, as recorded in
+    /// the annotation's `/IRT` ("in reply to") dictionary entry, if any.
+    ///
+    /// To group all the annotations on a page into comment threads, use the
+    /// `PdfPageAnnotations::threads()` function.
+    #[inline]
+    pub fn in_reply_to(&self) -> Option<PdfPageAnnotation<'a>> {
+        self.linked_annotation("IRT")
+    }
+
+    /// Makes this [PdfPageAnnotation] a reply to the given annotation, or removes the link to
+    /// any existing annotation this [PdfPageAnnotation] replies to if the given annotation
+    /// is `None`. The given annotation must be on the same page as this [PdfPageAnnotation].
+    ///
+    /// The change is applied when the containing document is saved; see the
+    /// [PdfPageAnnotation::set_popup()] function for details.
+    #[inline]
+    pub fn set_in_reply_to(
+        &mut self,
+        annotation: Option<&PdfPageAnnotation>,
+    ) -> Result<(), PdfiumError> {
+        self.set_linked_annotation_impl("IRT", annotation)
+    }
+
+    /// Returns `true` if this [PdfPageAnnotation] is a reply to another annotation.
+    #[inline]
+    pub fn is_reply(&self) -> bool {
+        match PdfPageAnnotationEditCache::get_reference_for_annotation(
+            self.bindings(),
+            self.document_handle(),
+            self.page_handle(),
+            self.handle(),
+            "IRT",
+        ) {
+            Some(index) => index.is_some(),
+            None => self
+                .bindings()
+                .is_true(self.bindings().FPDFAnnot_HasKey(self.handle(), "IRT")),
+        }
+    }
+
+    /// Returns the annotation referenced by the given key in the annotation dictionary of
+    /// this [PdfPageAnnotation], if any, taking any pending change to the link into account.
+    fn linked_annotation(&self, key: &str) -> Option<PdfPageAnnotation<'a>> {
+        let handle = match PdfPageAnnotationEditCache::get_reference_for_annotation(
+            self.bindings(),
+            self.document_handle(),
+            self.page_handle(),
+            self.handle(),
+            key,
+        ) {
+            Some(Some(index)) => self
+                .bindings()
+                .FPDFPage_GetAnnot(self.page_handle(), index as c_int),
+            Some(None) => return None,
+            None => self.bindings().FPDFAnnot_GetLinkedAnnot(self.handle(), key),
+        };
+
+        if handle.is_null() {
+            None
+        } else {
+            let objects = self.objects_impl();
+
+            Some(PdfPageAnnotation::from_pdfium(
+                objects.document_handle(),
+                *objects.get_page_handle(),
+                handle,
+                None,
+                self.bindings(),
+            ))
+        }
+    }
+
+    /// Returns an immutable reference to the underlying [PdfPageCaretAnnotation]
+    /// for this [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::Caret].
+    #[inline]
+    pub fn as_caret_annotation(&self) -> Option<&PdfPageCaretAnnotation<'_>> {
+        match self {
+            PdfPageAnnotation::Caret(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the underlying [PdfPageCaretAnnotation]
+    /// for this [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::Caret].
+    #[inline]
+    pub fn as_caret_annotation_mut(&mut self) -> Option<&mut PdfPageCaretAnnotation<'a>> {
+        match self {
+            PdfPageAnnotation::Caret(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
     /// Returns an immutable reference to the underlying [PdfPageCircleAnnotation]
     /// for this [PdfPageAnnotation], if this annotation has an annotation type of
     /// [PdfPageAnnotationType::Circle].
@@ -531,6 +750,30 @@ impl<'a> PdfPageAnnotation<'a> {
         }
     }
 
+    /// Returns an immutable reference to the underlying [PdfPageFileAttachmentAnnotation]
+    /// for this [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::FileAttachment].
+    #[inline]
+    pub fn as_file_attachment_annotation(&self) -> Option<&PdfPageFileAttachmentAnnotation<'_>> {
+        match self {
+            PdfPageAnnotation::FileAttachment(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the underlying [PdfPageFileAttachmentAnnotation]
+    /// for this [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::FileAttachment].
+    #[inline]
+    pub fn as_file_attachment_annotation_mut(
+        &mut self,
+    ) -> Option<&mut PdfPageFileAttachmentAnnotation<'a>> {
+        match self {
+            PdfPageAnnotation::FileAttachment(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
     /// Returns an immutable reference to the underlying [PdfPageFreeTextAnnotation]
     /// for this [PdfPageAnnotation], if this annotation has an annotation type of
     /// [PdfPageAnnotationType::FreeText].
@@ -597,6 +840,28 @@ impl<'a> PdfPageAnnotation<'a> {
         }
     }
 
+    /// Returns an immutable reference to the underlying [PdfPageLineAnnotation]
+    /// for this [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::Line].
+    #[inline]
+    pub fn as_line_annotation(&self) -> Option<&PdfPageLineAnnotation<'_>> {
+        match self {
+            PdfPageAnnotation::Line(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the underlying [PdfPageLineAnnotation]
+    /// for this [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::Line].
+    #[inline]
+    pub fn as_line_annotation_mut(&mut self) -> Option<&mut PdfPageLineAnnotation<'a>> {
+        match self {
+            PdfPageAnnotation::Line(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
     /// Returns an immutable reference to the underlying [PdfPageLinkAnnotation]
     /// for this [PdfPageAnnotation], if this annotation has an annotation type of
     /// [PdfPageAnnotationType::Link].
@@ -619,6 +884,50 @@ impl<'a> PdfPageAnnotation<'a> {
         }
     }
 
+    /// Returns an immutable reference to the underlying [PdfPagePolygonAnnotation]
+    /// for this [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::Polygon].
+    #[inline]
+    pub fn as_polygon_annotation(&self) -> Option<&PdfPagePolygonAnnotation<'_>> {
+        match self {
+            PdfPageAnnotation::Polygon(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the underlying [PdfPagePolygonAnnotation]
+    /// for this [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::Polygon].
+    #[inline]
+    pub fn as_polygon_annotation_mut(&mut self) -> Option<&mut PdfPagePolygonAnnotation<'a>> {
+        match self {
+            PdfPageAnnotation::Polygon(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
+    /// Returns an immutable reference to the underlying [PdfPagePolylineAnnotation]
+    /// for this [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::Polyline].
+    #[inline]
+    pub fn as_polyline_annotation(&self) -> Option<&PdfPagePolylineAnnotation<'_>> {
+        match self {
+            PdfPageAnnotation::Polyline(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the underlying [PdfPagePolylineAnnotation]
+    /// for this [PdfPageAnnotation], if this annotation has an annotation type of
+    /// [PdfPageAnnotationType::Polyline].
+    #[inline]
+    pub fn as_polyline_annotation_mut(&mut self) -> Option<&mut PdfPagePolylineAnnotation<'a>> {
+        match self {
+            PdfPageAnnotation::Polyline(annotation) => Some(annotation),
+            _ => None,
+        }
+    }
+
     /// Returns an immutable reference to the underlying [PdfPagePopupAnnotation]
     /// for this [PdfPageAnnotation], if this annotation has an annotation type of
     /// [PdfPageAnnotationType::Popup].
@@ -911,6 +1220,110 @@ pub trait PdfPageAnnotationCommon {
     /// use the [PdfPageAnnotationCommon::set_bounds()] function.
     fn set_height(&mut self, width: PdfPoints) -> Result<(), PdfiumError>;
 
+    /// Returns the flags controlling the visibility and behavior of this [PdfPageAnnotation].
+    fn flags(&self) -> PdfPageAnnotationFlags;
+
+    /// Sets the flags controlling the visibility and behavior of this [PdfPageAnnotation],
+    /// replacing any existing flags. To change a single flag, retrieve the existing flags first:
+    /// ```
+    /// let mut flags = annotation.flags();
+    ///
+    /// flags.set(PdfPageAnnotationFlags::PRINT, false);
+    ///
+    /// annotation.set_flags(flags)?;
+    /// ```
+    fn set_flags(&mut self, flags: PdfPageAnnotationFlags) -> Result<(), PdfiumError>;
+
+    /// Returns the corner radii and width of the border drawn around this [PdfPageAnnotation],
+    /// as recorded in the annotation's `/Border` entry.
+    ///
+    /// If the annotation does not specify a border, the default border defined by
+    /// The PDF Reference, with square corners and a width of one point, will be returned.
+    ///
+    /// A border style dictionary takes precedence over the `/Border` entry. If the annotation
+    /// has a border style dictionary that specifies a width, that width is returned instead.
+    fn border(&self) -> Result<PdfPageAnnotationBorder, PdfiumError>;
+
+    /// Sets the corner radii and width of the border drawn around this [PdfPageAnnotation],
+    /// as recorded in the annotation's `/Border` entry.
+    ///
+    /// If this annotation has an appearance stream that overrides its border, Pdfium
+    /// will remove the appearance stream so that the new border takes effect. If this
+    /// annotation has a border style dictionary, its width is updated to match, as described
+    /// for [PdfPageAnnotationCommon::set_border_style()].
+    fn set_border(&mut self, border: PdfPageAnnotationBorder) -> Result<(), PdfiumError>;
+
+    /// Returns the [PdfPageAnnotationBorderStyle] used to draw the border of this
+    /// [PdfPageAnnotation], as recorded in the annotation's border style dictionary (the `/BS`
+    /// entry), or in the dash pattern of its `/Border` entry if it has no border style dictionary.
+    ///
+    /// Pdfium does not provide access to these entries, so if the annotation has either entry,
+    /// the containing document is written to a buffer by Pdfium and the entries are read from
+    /// the written data. This may be slow for large documents.
+    fn border_style(&self) -> Result<PdfPageAnnotationBorderStyle, PdfiumError>;
+
+    /// Sets the [PdfPageAnnotationBorderStyle] used to draw the border of this
+    /// [PdfPageAnnotation], replacing the annotation's border style dictionary
+    /// (the `/BS` entry). The width of the border is taken from
+    /// [PdfPageAnnotationCommon::border()].
+    ///
+    /// Pdfium does not provide a way to write the border style dictionary, so the new style is
+    /// held by `pdfium-render` and written to the document's file data, as an incremental update
+    /// following the data written by Pdfium, each time the document is saved. Pdfium itself
+    /// does not see the new style until the saved document is reloaded, so pages rendered
+    /// before then will not show it. To find the annotation when the document is saved,
+    /// `pdfium-render` gives the annotation a unique name if it does not already have one.
+    ///
+    /// As with [PdfPageAnnotationCommon::set_border()], any appearance stream is removed
+    /// so that viewers draw the border in the new style.
+    fn set_border_style(&mut self, style: PdfPageAnnotationBorderStyle) -> Result<(), PdfiumError>;
+
+    /// Returns the [PdfPageAnnotationBorderEffect] applied to the border of this
+    /// [PdfPageAnnotation], as recorded in the annotation's border effect dictionary
+    /// (the `/BE` entry).
+    ///
+    /// The entry is read in the same way as for [PdfPageAnnotationCommon::border_style()].
+    fn border_effect(&self) -> Result<PdfPageAnnotationBorderEffect, PdfiumError>;
+
+    /// Sets the [PdfPageAnnotationBorderEffect] applied to the border of this
+    /// [PdfPageAnnotation], replacing the annotation's border effect dictionary
+    /// (the `/BE` entry), or removing it if the effect is [PdfPageAnnotationBorderEffect::None].
+    ///
+    /// The entry is written in the same way as for [PdfPageAnnotationCommon::set_border_style()].
+    fn set_border_effect(
+        &mut self,
+        effect: PdfPageAnnotationBorderEffect,
+    ) -> Result<(), PdfiumError>;
+
+    /// Returns the raw content stream text of the appearance stream used to draw this
+    /// [PdfPageAnnotation] in the given [PdfAppearanceMode], if any.
+    fn appearance_stream(&self, appearance_mode: PdfAppearanceMode) -> Option<String>;
+
+    /// Sets the appearance stream used to draw this [PdfPageAnnotation] in the given
+    /// [PdfAppearanceMode] to the given raw content stream text, for example
+    /// `"1 0 0 rg 0 0 50 20 re f"`. Coordinates in the content stream are relative to
+    /// the bottom left corner of the annotation's bounding box, so the bounds of the annotation
+    /// should be set before setting its appearance stream.
+    ///
+    /// Viewers that honor appearance streams will draw the annotation using the given content
+    /// rather than generating their own appearance, giving the annotation a consistent look
+    /// in every viewer. To set the appearance of an ink or stamp annotation from a set of
+    /// page objects instead, use the `set_appearance_stream_from_objects()` function
+    /// on the unwrapped annotation.
+    fn set_appearance_stream(
+        &mut self,
+        appearance_mode: PdfAppearanceMode,
+        content: &str,
+    ) -> Result<(), PdfiumError>;
+
+    /// Removes the appearance stream used to draw this [PdfPageAnnotation] in the given
+    /// [PdfAppearanceMode]. Removing the [PdfAppearanceMode::Normal] appearance stream
+    /// removes the appearance streams for all modes.
+    fn clear_appearance_stream(
+        &mut self,
+        appearance_mode: PdfAppearanceMode,
+    ) -> Result<(), PdfiumError>;
+
     /// Returns the text to be displayed for this [PdfPageAnnotation], or, if this type of annotation
     /// does not display text, an alternate description of the annotation's contents in human-readable
     /// form. In either case this text is useful when extracting the document's contents in support
@@ -938,9 +1351,17 @@ pub trait PdfPageAnnotationCommon {
     fn set_modification_date(&mut self, date: DateTime<Utc>) -> Result<(), PdfiumError>;
 
     /// Returns the color of any filled paths in this [PdfPageAnnotation].
+    ///
+    /// For shape annotations such as [PdfPageAnnotationType::Circle] and
+    /// [PdfPageAnnotationType::Square], this is the interior color of the shape,
+    /// set independently of the color used to stroke the shape's border.
     fn fill_color(&self) -> Result<PdfColor, PdfiumError>;
 
     /// Sets the color of any filled paths in this [PdfPageAnnotation].
+    ///
+    /// For shape annotations such as [PdfPageAnnotationType::Circle] and
+    /// [PdfPageAnnotationType::Square], this is the interior color of the shape,
+    /// set independently of the color used to stroke the shape's border.
     fn set_fill_color(&mut self, fill_color: PdfColor) -> Result<(), PdfiumError>;
 
     /// Returns the color of any stroked paths in this [PdfPageAnnotation].
@@ -1026,6 +1447,71 @@ where
         self.set_height_impl(height)
     }
 
+    #[inline]
+    fn flags(&self) -> PdfPageAnnotationFlags {
+        self.flags_impl()
+    }
+
+    #[inline]
+    fn set_flags(&mut self, flags: PdfPageAnnotationFlags) -> Result<(), PdfiumError> {
+        self.set_flags_impl(flags)
+    }
+
+    #[inline]
+    fn border(&self) -> Result<PdfPageAnnotationBorder, PdfiumError> {
+        self.border_impl()
+    }
+
+    #[inline]
+    fn set_border(&mut self, border: PdfPageAnnotationBorder) -> Result<(), PdfiumError> {
+        self.set_border_impl(border)
+    }
+
+    #[inline]
+    fn border_style(&self) -> Result<PdfPageAnnotationBorderStyle, PdfiumError> {
+        self.border_style_impl()
+    }
+
+    #[inline]
+    fn set_border_style(&mut self, style: PdfPageAnnotationBorderStyle) -> Result<(), PdfiumError> {
+        self.set_border_style_impl(style)
+    }
+
+    #[inline]
+    fn border_effect(&self) -> Result<PdfPageAnnotationBorderEffect, PdfiumError> {
+        self.border_effect_impl()
+    }
+
+    #[inline]
+    fn set_border_effect(
+        &mut self,
+        effect: PdfPageAnnotationBorderEffect,
+    ) -> Result<(), PdfiumError> {
+        self.set_border_effect_impl(effect)
+    }
+
+    #[inline]
+    fn appearance_stream(&self, appearance_mode: PdfAppearanceMode) -> Option<String> {
+        self.appearance_stream_impl(appearance_mode)
+    }
+
+    #[inline]
+    fn set_appearance_stream(
+        &mut self,
+        appearance_mode: PdfAppearanceMode,
+        content: &str,
+    ) -> Result<(), PdfiumError> {
+        self.set_appearance_stream_impl(appearance_mode, content)
+    }
+
+    #[inline]
+    fn clear_appearance_stream(
+        &mut self,
+        appearance_mode: PdfAppearanceMode,
+    ) -> Result<(), PdfiumError> {
+        self.clear_appearance_stream_impl(appearance_mode)
+    }
+
     #[inline]
     fn contents(&self) -> Option<String> {
         self.contents_impl()
@@ -1099,7 +1585,7 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageAnnotation<'a> {
     }
 
     #[inline]
-    fn bindings(&self) -> &dyn PdfiumLibraryBindings {
+    fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
         self.unwrap_as_trait().bindings()
     }
 
@@ -1131,3 +1617,78 @@ impl<'a> Drop for PdfPageAnnotation<'a> {
         self.bindings().FPDFPage_CloseAnnot(self.handle());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_appearance_stream() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut annotation = page
+            .annotations_mut()
+            .create_free_text_annotation("Approved")?;
+
+        annotation.set_bounds(PdfRect::new_from_values(100.0, 100.0, 120.0, 200.0))?;
+
+        assert!(annotation
+            .appearance_stream(PdfAppearanceMode::RollOver)
+            .is_none());
+
+        let content = "1 0 0 rg 0 0 100 20 re f";
+
+        annotation.set_appearance_stream(PdfAppearanceMode::RollOver, content)?;
+
+        assert_eq!(
+            annotation.appearance_stream(PdfAppearanceMode::RollOver),
+            Some(content.to_string())
+        );
+
+        annotation.clear_appearance_stream(PdfAppearanceMode::RollOver)?;
+
+        assert!(annotation
+            .appearance_stream(PdfAppearanceMode::RollOver)
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_flags() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut annotation = page.annotations_mut().create_square_annotation()?;
+
+        annotation.set_flags(PdfPageAnnotationFlags::PRINT | PdfPageAnnotationFlags::LOCKED)?;
+
+        let mut flags = annotation.flags();
+
+        assert!(flags.contains(PdfPageAnnotationFlags::LOCKED));
+
+        flags.set(PdfPageAnnotationFlags::PRINT, false);
+        flags.insert(PdfPageAnnotationFlags::NO_VIEW);
+
+        annotation.set_flags(flags)?;
+
+        assert_eq!(
+            annotation.flags(),
+            PdfPageAnnotationFlags::LOCKED | PdfPageAnnotationFlags::NO_VIEW
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,437 @@
+//! Defines the [PdfPageAnnotationBorder] struct, describing the border drawn around
+//! a single `PdfPageAnnotation`, along with the [PdfPageAnnotationBorderStyle] and
+//! [PdfPageAnnotationBorderEffect] enums describing how the border is drawn.
+
+use crate::error::PdfiumError;
+use crate::pdf::document::syntax::{number_to_bytes, PdfSyntaxDictionary, PdfSyntaxObject};
+use crate::pdf::points::PdfPoints;
+
+/// The characteristics of the border drawn around a single `PdfPageAnnotation`,
+/// as recorded in the annotation's `/Border` dictionary entry.
+///
+/// The border is a rounded rectangle. A border with a width of zero is not drawn;
+/// a border with corner radii of zero is drawn with square corners.
+///
+/// The style of the border, and any effect applied to it, are described separately by
+/// the [PdfPageAnnotationBorderStyle] and [PdfPageAnnotationBorderEffect] enums.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PdfPageAnnotationBorder {
+    /// The horizontal corner radius of the border.
+    pub horizontal_radius: PdfPoints,
+
+    /// The vertical corner radius of the border.
+    pub vertical_radius: PdfPoints,
+
+    /// The width of the border.
+    pub width: PdfPoints,
+}
+
+impl PdfPageAnnotationBorder {
+    /// The default border applied to annotations that do not specify a `/Border` entry:
+    /// a border with square corners and a width of one point.
+    pub const DEFAULT: PdfPageAnnotationBorder =
+        PdfPageAnnotationBorder::from_width(PdfPoints::new(1.0));
+
+    /// A border that is not drawn.
+    pub const NONE: PdfPageAnnotationBorder = PdfPageAnnotationBorder::from_width(PdfPoints::ZERO);
+
+    /// Creates a new [PdfPageAnnotationBorder] with the given corner radii and width.
+    #[inline]
+    pub const fn new(
+        horizontal_radius: PdfPoints,
+        vertical_radius: PdfPoints,
+        width: PdfPoints,
+    ) -> Self {
+        PdfPageAnnotationBorder {
+            horizontal_radius,
+            vertical_radius,
+            width,
+        }
+    }
+
+    /// Creates a new [PdfPageAnnotationBorder] with square corners and the given width.
+    #[inline]
+    pub const fn from_width(width: PdfPoints) -> Self {
+        Self::new(PdfPoints::ZERO, PdfPoints::ZERO, width)
+    }
+
+    /// Returns `true` if this [PdfPageAnnotationBorder] has a non-zero width,
+    /// i.e. if the border will be drawn.
+    #[inline]
+    pub fn is_visible(&self) -> bool {
+        self.width.value > 0.0
+    }
+}
+
+impl Default for PdfPageAnnotationBorder {
+    #[inline]
+    fn default() -> Self {
+        PdfPageAnnotationBorder::DEFAULT
+    }
+}
+
+/// The style used to draw the border of a single `PdfPageAnnotation`, as recorded in the
+/// annotation's border style dictionary (the `/BS` entry).
+#[derive(Debug, Clone, PartialEq)]
+pub enum PdfPageAnnotationBorderStyle {
+    /// A solid rectangle surrounding the annotation. This is the default.
+    Solid,
+
+    /// A dashed rectangle surrounding the annotation, drawn using the given dash pattern:
+    /// the lengths of alternating dashes and gaps, starting with a dash. The pattern is
+    /// repeated along the length of the border.
+    Dashed(Vec<PdfPoints>),
+
+    /// A simulated embossed rectangle that appears to be raised above the surface of the page.
+    Beveled,
+
+    /// A simulated engraved rectangle that appears to be recessed below the surface of the page.
+    Inset,
+
+    /// A single line along the bottom of the annotation rectangle.
+    Underline,
+}
+
+impl PdfPageAnnotationBorderStyle {
+    /// The length of the dashes and gaps in the dash pattern of a dashed border that
+    /// does not specify a dash pattern, as defined by The PDF Reference.
+    pub const DEFAULT_DASH_LENGTH: PdfPoints = PdfPoints::new(3.0);
+
+    /// Creates a new [PdfPageAnnotationBorderStyle::Dashed] style with dashes and gaps
+    /// of the given lengths.
+    #[inline]
+    pub fn dashed(dash: PdfPoints, gap: PdfPoints) -> Self {
+        PdfPageAnnotationBorderStyle::Dashed(vec![dash, gap])
+    }
+
+    /// Creates a [PdfPageAnnotationBorderStyle] from the given border style dictionary.
+    pub(crate) fn from_dictionary(dictionary: &PdfSyntaxDictionary) -> Self {
+        match dictionary.get(b"S").and_then(|style| style.as_name()) {
+            Some(b"D") => PdfPageAnnotationBorderStyle::Dashed(
+                dictionary
+                    .get(b"D")
+                    .and_then(Self::dash_pattern_from_array)
+                    .unwrap_or_else(|| vec![Self::DEFAULT_DASH_LENGTH]),
+            ),
+            Some(b"B") => PdfPageAnnotationBorderStyle::Beveled,
+            Some(b"I") => PdfPageAnnotationBorderStyle::Inset,
+            Some(b"U") => PdfPageAnnotationBorderStyle::Underline,
+            _ => PdfPageAnnotationBorderStyle::Solid,
+        }
+    }
+
+    /// Creates a dash pattern from the given array of numbers, returning `None` if
+    /// the array is empty or contains any values that are not numbers.
+    pub(crate) fn dash_pattern_from_array(array: &PdfSyntaxObject) -> Option<Vec<PdfPoints>> {
+        let pattern = array
+            .as_array()?
+            .iter()
+            .map(|length| length.as_number().map(PdfPoints::new))
+            .collect::<Option<Vec<_>>>()?;
+
+        if pattern.is_empty() {
+            None
+        } else {
+            Some(pattern)
+        }
+    }
+
+    /// Returns a border style dictionary describing this [PdfPageAnnotationBorderStyle] and
+    /// the given border width, written in PDF object syntax.
+    pub(crate) fn to_dictionary_bytes(&self, width: PdfPoints) -> Result<Vec<u8>, PdfiumError> {
+        let mut result = b"<< /Type /Border /W ".to_vec();
+
+        result.extend_from_slice(&number_to_bytes(width.value));
+
+        result.extend_from_slice(match self {
+            PdfPageAnnotationBorderStyle::Solid => b" /S /S",
+            PdfPageAnnotationBorderStyle::Dashed(_) => b" /S /D /D [",
+            PdfPageAnnotationBorderStyle::Beveled => b" /S /B",
+            PdfPageAnnotationBorderStyle::Inset => b" /S /I",
+            PdfPageAnnotationBorderStyle::Underline => b" /S /U",
+        });
+
+        if let PdfPageAnnotationBorderStyle::Dashed(pattern) = self {
+            // The PDF Reference requires at least one dash length, and does not permit
+            // lengths that are negative or all zero.
+
+            if pattern.is_empty()
+                || pattern
+                    .iter()
+                    .any(|length| !length.value.is_finite() || length.value < 0.0)
+                || pattern.iter().all(|length| length.value == 0.0)
+            {
+                return Err(PdfiumError::PageAnnotationBorderDashPatternInvalid);
+            }
+
+            for (index, length) in pattern.iter().enumerate() {
+                if index > 0 {
+                    result.push(b' ');
+                }
+
+                result.extend_from_slice(&number_to_bytes(length.value));
+            }
+
+            result.push(b']');
+        }
+
+        result.extend_from_slice(b" >>");
+
+        Ok(result)
+    }
+}
+
+impl Default for PdfPageAnnotationBorderStyle {
+    #[inline]
+    fn default() -> Self {
+        PdfPageAnnotationBorderStyle::Solid
+    }
+}
+
+/// An effect applied to the border of a single `PdfPageAnnotation`, as recorded in the
+/// annotation's border effect dictionary (the `/BE` entry).
+///
+/// The PDF Reference defines border effects only for square, circle, polygon,
+/// and free text annotations.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PdfPageAnnotationBorderEffect {
+    /// No effect. The border is drawn as described by its [PdfPageAnnotationBorderStyle].
+    /// This is the default.
+    None,
+
+    /// The border is drawn as a series of convex curves, resembling a cloud. The wrapped value
+    /// is the intensity of the effect, between 0.0 and 2.0; larger values produce larger curves.
+    Cloudy(f32),
+}
+
+impl PdfPageAnnotationBorderEffect {
+    /// Creates a [PdfPageAnnotationBorderEffect] from the given border effect dictionary.
+    pub(crate) fn from_dictionary(dictionary: &PdfSyntaxDictionary) -> Self {
+        match dictionary.get(b"S").and_then(|effect| effect.as_name()) {
+            Some(b"C") => PdfPageAnnotationBorderEffect::Cloudy(
+                dictionary
+                    .get(b"I")
+                    .and_then(|intensity| intensity.as_number())
+                    .unwrap_or(0.0),
+            ),
+            _ => PdfPageAnnotationBorderEffect::None,
+        }
+    }
+
+    /// Returns a border effect dictionary describing this [PdfPageAnnotationBorderEffect],
+    /// written in PDF object syntax, or `None` if no border effect dictionary is needed.
+    pub(crate) fn to_dictionary_bytes(self) -> Result<Option<Vec<u8>>, PdfiumError> {
+        match self {
+            PdfPageAnnotationBorderEffect::None => Ok(None),
+            PdfPageAnnotationBorderEffect::Cloudy(intensity) => {
+                if !(0.0..=2.0).contains(&intensity) {
+                    return Err(PdfiumError::PageAnnotationCloudyBorderIntensityOutOfRange);
+                }
+
+                let mut result = b"<< /S /C /I ".to_vec();
+
+                result.extend_from_slice(&number_to_bytes(intensity));
+                result.extend_from_slice(b" >>");
+
+                Ok(Some(result))
+            }
+        }
+    }
+}
+
+impl Default for PdfPageAnnotationBorderEffect {
+    #[inline]
+    fn default() -> Self {
+        PdfPageAnnotationBorderEffect::None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_annotation_border() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut annotation = page.annotations_mut().create_square_annotation()?;
+
+        assert_eq!(annotation.border()?, PdfPageAnnotationBorder::DEFAULT);
+
+        let border = PdfPageAnnotationBorder::new(
+            PdfPoints::new(4.0),
+            PdfPoints::new(2.0),
+            PdfPoints::new(3.0),
+        );
+
+        annotation.set_border(border)?;
+
+        assert_eq!(annotation.border()?, border);
+
+        annotation.set_border(PdfPageAnnotationBorder::NONE)?;
+
+        assert!(!annotation.border()?.is_visible());
+        assert_eq!(
+            annotation.border_style()?,
+            PdfPageAnnotationBorderStyle::Solid
+        );
+        assert_eq!(
+            annotation.border_effect()?,
+            PdfPageAnnotationBorderEffect::None
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_border_styles_from_file() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/border-style-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let annotations = page.annotations();
+
+        let dashed = annotations.get(0)?;
+
+        assert_eq!(
+            dashed.border_style()?,
+            PdfPageAnnotationBorderStyle::dashed(PdfPoints::new(3.0), PdfPoints::new(2.0))
+        );
+        assert_eq!(
+            dashed.border_effect()?,
+            PdfPageAnnotationBorderEffect::Cloudy(1.0)
+        );
+
+        // The width in the border style dictionary takes precedence over the /Border entry.
+
+        assert_eq!(dashed.border()?.width, PdfPoints::new(2.0));
+
+        let beveled = annotations.get(1)?;
+
+        assert_eq!(
+            beveled.border_style()?,
+            PdfPageAnnotationBorderStyle::Beveled
+        );
+        assert_eq!(beveled.border()?.width, PdfPoints::new(3.0));
+
+        assert_eq!(
+            annotations.get(2)?.border_style()?,
+            PdfPageAnnotationBorderStyle::Inset
+        );
+        assert_eq!(
+            annotations.get(3)?.border_style()?,
+            PdfPageAnnotationBorderStyle::Underline
+        );
+
+        // A dash pattern may also be given as the fourth element of the /Border entry.
+
+        let legacy = annotations.get(4)?;
+
+        assert_eq!(
+            legacy.border_style()?,
+            PdfPageAnnotationBorderStyle::dashed(PdfPoints::new(4.0), PdfPoints::new(1.0))
+        );
+        assert_eq!(legacy.border()?.width, PdfPoints::new(2.0));
+
+        // A border style dictionary may be an indirect object, and a dashed style may omit
+        // its dash pattern.
+
+        let indirect = annotations.get(5)?;
+
+        assert_eq!(
+            indirect.border_style()?,
+            PdfPageAnnotationBorderStyle::Dashed(vec![
+                PdfPageAnnotationBorderStyle::DEFAULT_DASH_LENGTH
+            ])
+        );
+        assert_eq!(
+            indirect.border_effect()?,
+            PdfPageAnnotationBorderEffect::None
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_border_style_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        {
+            let mut page = document
+                .pages_mut()
+                .create_page_at_start(PdfPagePaperSize::a4())?;
+
+            let mut annotation = page.annotations_mut().create_square_annotation()?;
+
+            annotation.set_border_style(PdfPageAnnotationBorderStyle::dashed(
+                PdfPoints::new(4.0),
+                PdfPoints::new(2.0),
+            ))?;
+            annotation.set_border_effect(PdfPageAnnotationBorderEffect::Cloudy(1.5))?;
+            annotation.set_border(PdfPageAnnotationBorder::from_width(PdfPoints::new(5.0)))?;
+
+            assert_eq!(
+                annotation.border_style()?,
+                PdfPageAnnotationBorderStyle::dashed(PdfPoints::new(4.0), PdfPoints::new(2.0))
+            );
+            assert_eq!(annotation.border()?.width, PdfPoints::new(5.0));
+
+            let mut underlined = page.annotations_mut().create_square_annotation()?;
+
+            underlined.set_border_effect(PdfPageAnnotationBorderEffect::Cloudy(1.0))?;
+            underlined.set_border_style(PdfPageAnnotationBorderStyle::Underline)?;
+            underlined.set_border_effect(PdfPageAnnotationBorderEffect::None)?;
+
+            assert!(underlined
+                .set_border_style(PdfPageAnnotationBorderStyle::Dashed(vec![]))
+                .is_err());
+            assert!(underlined
+                .set_border_effect(PdfPageAnnotationBorderEffect::Cloudy(3.0))
+                .is_err());
+        }
+
+        let bytes = document.save_to_bytes()?;
+
+        drop(document);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().first()?;
+
+        let annotation = page.annotations().get(0)?;
+
+        assert_eq!(
+            annotation.border_style()?,
+            PdfPageAnnotationBorderStyle::dashed(PdfPoints::new(4.0), PdfPoints::new(2.0))
+        );
+        assert_eq!(
+            annotation.border_effect()?,
+            PdfPageAnnotationBorderEffect::Cloudy(1.5)
+        );
+        assert_eq!(annotation.border()?.width, PdfPoints::new(5.0));
+
+        let underlined = page.annotations().get(1)?;
+
+        assert_eq!(
+            underlined.border_style()?,
+            PdfPageAnnotationBorderStyle::Underline
+        );
+        assert_eq!(
+            underlined.border_effect()?,
+            PdfPageAnnotationBorderEffect::None
+        );
+
+        Ok(())
+    }
+}
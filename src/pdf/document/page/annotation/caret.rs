@@ -0,0 +1,72 @@
+//! Defines the [PdfPageCaretAnnotation] struct, exposing functionality related to a single
+//! user annotation of type `PdfPageAnnotationType::Caret`.
+
+use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::pdf::document::page::annotation::attachment_points::PdfPageAnnotationAttachmentPoints;
+use crate::pdf::document::page::annotation::objects::PdfPageAnnotationObjects;
+use crate::pdf::document::page::annotation::private::internal::PdfPageAnnotationPrivate;
+
+/// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::Caret`.
+pub struct PdfPageCaretAnnotation<'a> {
+    handle: FPDF_ANNOTATION,
+    objects: PdfPageAnnotationObjects<'a>,
+    attachment_points: PdfPageAnnotationAttachmentPoints<'a>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageCaretAnnotation<'a> {
+    pub(crate) fn from_pdfium(
+        document_handle: FPDF_DOCUMENT,
+        page_handle: FPDF_PAGE,
+        annotation_handle: FPDF_ANNOTATION,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPageCaretAnnotation {
+            handle: annotation_handle,
+            objects: PdfPageAnnotationObjects::from_pdfium(
+                document_handle,
+                page_handle,
+                annotation_handle,
+                bindings,
+            ),
+            attachment_points: PdfPageAnnotationAttachmentPoints::from_pdfium(
+                annotation_handle,
+                bindings,
+            ),
+            bindings,
+        }
+    }
+}
+
+impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageCaretAnnotation<'a> {
+    #[inline]
+    fn handle(&self) -> FPDF_ANNOTATION {
+        self.handle
+    }
+
+    #[inline]
+    fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    #[inline]
+    fn objects_impl(&self) -> &PdfPageAnnotationObjects<'_> {
+        &self.objects
+    }
+
+    #[inline]
+    fn objects_mut_impl(&mut self) -> &mut PdfPageAnnotationObjects<'a> {
+        &mut self.objects
+    }
+
+    #[inline]
+    fn attachment_points_impl(&self) -> &PdfPageAnnotationAttachmentPoints<'_> {
+        &self.attachment_points
+    }
+
+    #[inline]
+    fn attachment_points_mut_impl(&mut self) -> &mut PdfPageAnnotationAttachmentPoints<'a> {
+        &mut self.attachment_points
+    }
+}
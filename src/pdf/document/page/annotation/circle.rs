@@ -46,7 +46,7 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageCircleAnnotation<'a> {
     }
 
     #[inline]
-    fn bindings(&self) -> &dyn PdfiumLibraryBindings {
+    fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
         self.bindings
     }
 
@@ -70,3 +70,48 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageCircleAnnotation<'a> {
         &mut self.attachment_points
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_create_circle_annotation_in_rect() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let rect = PdfRect::new_from_values(100.0, 100.0, 200.0, 250.0);
+
+        let border = PdfPageAnnotationBorder::from_width(PdfPoints::new(3.0));
+
+        let annotation = page.annotations_mut().create_circle_annotation_in_rect(
+            rect,
+            Some(PdfColor::RED),
+            border,
+            Some(PdfColor::YELLOW),
+        )?;
+
+        assert_eq!(annotation.bounds()?, rect);
+        assert_eq!(annotation.border()?, border);
+        assert_eq!(annotation.stroke_color()?.to_hex(), PdfColor::RED.to_hex());
+        assert_eq!(annotation.fill_color()?.to_hex(), PdfColor::YELLOW.to_hex());
+
+        let annotation = page.annotations_mut().create_square_annotation_in_rect(
+            rect,
+            None,
+            border,
+            Some(PdfColor::BLUE),
+        )?;
+
+        assert!(!annotation.border()?.is_visible());
+        assert_eq!(annotation.fill_color()?.to_hex(), PdfColor::BLUE.to_hex());
+
+        Ok(())
+    }
+}
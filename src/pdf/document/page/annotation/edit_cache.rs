@@ -0,0 +1,525 @@
+use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::page::annotation::private::internal::get_annotation_string_value;
+use crate::pdf::document::page::index_cache::PdfPageIndexCache;
+use crate::pdf::document::save_options::PdfSaveMode;
+use crate::pdf::document::syntax::{
+    append_incremental_update, save_document, PdfSyntaxDocument, PdfSyntaxObject, PdfSyntaxParser,
+};
+use chrono::Utc;
+use once_cell::sync::Lazy;
+use std::collections::{HashMap, HashSet};
+use std::os::raw::c_int;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+/// A cache of changes to annotation dictionary entries that Pdfium does not provide a way
+/// to write. The changes are held here, keyed by document handle and annotation name
+/// (the `/NM` entry of the annotation dictionary), and are applied to the file data
+/// written by Pdfium each time the containing document is saved.
+static ANNOTATION_EDIT_CACHE: Lazy<Mutex<PdfPageAnnotationEditCache>> =
+    Lazy::new(|| Mutex::new(PdfPageAnnotationEditCache::new()));
+
+/// A counter used to generate unique annotation names.
+static ANNOTATION_NAME_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// A list of changes to the entries of a single annotation dictionary. Each change replaces
+/// the entry with the given key by the given value, or removes the entry if the value is `None`.
+type PdfPageAnnotationEdits = Vec<(Vec<u8>, Option<PdfPageAnnotationEditValue>)>;
+
+/// The new value of a single entry in an annotation dictionary.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum PdfPageAnnotationEditValue {
+    /// A value written in PDF object syntax.
+    Object(Vec<u8>),
+
+    /// An indirect reference to the annotation with the given name (the `/NM` entry of the
+    /// annotation dictionary). Pdfium assigns object numbers to new annotations only when
+    /// the document is written, so the reference is resolved each time the containing
+    /// document is saved.
+    AnnotationReference(String),
+}
+
+pub(crate) struct PdfPageAnnotationEditCache {
+    edits: HashMap<FPDF_DOCUMENT, HashMap<String, PdfPageAnnotationEdits>>,
+}
+
+impl PdfPageAnnotationEditCache {
+    #[inline]
+    fn new() -> Self {
+        PdfPageAnnotationEditCache {
+            edits: HashMap::new(),
+        }
+    }
+
+    #[inline]
+    fn lock() -> MutexGuard<'static, PdfPageAnnotationEditCache> {
+        ANNOTATION_EDIT_CACHE.lock().unwrap()
+    }
+
+    /// Returns the pending change to the entry with the given key in the dictionary of the
+    /// annotation with the given name, if any.
+    fn get(
+        &self,
+        document: FPDF_DOCUMENT,
+        name: &str,
+        key: &[u8],
+    ) -> Option<Option<PdfPageAnnotationEditValue>> {
+        self.edits
+            .get(&document)
+            .and_then(|annotations| annotations.get(name))
+            .and_then(|edits| {
+                edits
+                    .iter()
+                    .find(|(edited_key, _)| edited_key == key)
+                    .map(|(_, value)| value.clone())
+            })
+    }
+
+    /// Records a pending change to the entry with the given key in the dictionary of the
+    /// annotation with the given name, replacing any earlier change to the same entry.
+    fn set(
+        &mut self,
+        document: FPDF_DOCUMENT,
+        name: &str,
+        key: &[u8],
+        value: Option<PdfPageAnnotationEditValue>,
+    ) {
+        let edits = self
+            .edits
+            .entry(document)
+            .or_default()
+            .entry(name.to_string())
+            .or_default();
+
+        edits.retain(|(edited_key, _)| edited_key != key);
+        edits.push((key.to_vec(), value));
+    }
+
+    // The remaining functions in this implementation take care of thread-safe locking.
+    // These functions form the public API of the cache.
+
+    /// Returns the value of the entry with the given key in the dictionary of the given
+    /// annotation, taking any pending change to the entry into account. Indirect references
+    /// in the value are resolved.
+    ///
+    /// Pdfium does not provide access to arbitrary dictionary entries, so the document is
+    /// written to a buffer by Pdfium and the entry is read from the written data.
+    pub(crate) fn get_entry_for_annotation(
+        bindings: &dyn PdfiumLibraryBindings,
+        document: FPDF_DOCUMENT,
+        page: FPDF_PAGE,
+        annotation: FPDF_ANNOTATION,
+        key: &str,
+    ) -> Result<Option<PdfSyntaxObject>, PdfiumError> {
+        if let Some(name) = get_annotation_string_value(bindings, annotation, "NM") {
+            let edit = Self::lock().get(document, name.as_str(), key.as_bytes());
+
+            match edit {
+                Some(Some(PdfPageAnnotationEditValue::Object(value))) => {
+                    return PdfSyntaxParser::new(value.as_slice(), 0)
+                        .parse_object()
+                        .map(Some)
+                }
+                Some(Some(PdfPageAnnotationEditValue::AnnotationReference(target))) => {
+                    // The referenced annotation dictionary is read from the written data,
+                    // as for any other indirect reference.
+
+                    let location =
+                        match find_annotations(bindings, document, &[target.as_str()].into())
+                            .remove(target.as_str())
+                            .and_then(|locations| locations.into_iter().next())
+                        {
+                            Some(location) => location,
+                            None => return Ok(None),
+                        };
+
+                    let data = save_document(bindings, document)?;
+
+                    let syntax = PdfSyntaxDocument::new(data.as_slice())?;
+
+                    return Ok(Some(PdfSyntaxObject::Dictionary(
+                        syntax.annotation(location.0, location.1)?.dictionary,
+                    )));
+                }
+                Some(None) => return Ok(None),
+                None => {}
+            }
+        }
+
+        if !bindings.is_true(bindings.FPDFAnnot_HasKey(annotation, key)) {
+            return Ok(None);
+        }
+
+        let (page_index, annotation_index) =
+            locate_annotation(bindings, document, page, annotation)?;
+
+        let data = save_document(bindings, document)?;
+
+        let syntax = PdfSyntaxDocument::new(data.as_slice())?;
+
+        match syntax
+            .annotation(page_index, annotation_index)?
+            .dictionary
+            .get(key.as_bytes())
+        {
+            Some(value) => syntax.resolve_all(value).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Records a pending change to the entry with the given key in the dictionary of the given
+    /// annotation, replacing the entry with the given value, written in PDF object syntax,
+    /// or removing the entry if the value is `None`.
+    ///
+    /// The annotation is given a unique name if it does not already have one, so that it
+    /// can be found when the document is saved.
+    pub(crate) fn set_entry_for_annotation(
+        bindings: &dyn PdfiumLibraryBindings,
+        document: FPDF_DOCUMENT,
+        page: FPDF_PAGE,
+        annotation: FPDF_ANNOTATION,
+        key: &str,
+        value: Option<Vec<u8>>,
+    ) -> Result<(), PdfiumError> {
+        let name = unique_annotation_name(bindings, document, page, annotation)?;
+
+        Self::lock().set(
+            document,
+            name.as_str(),
+            key.as_bytes(),
+            value.map(PdfPageAnnotationEditValue::Object),
+        );
+
+        Ok(())
+    }
+
+    /// Records a pending change to the entry with the given key in the dictionary of the given
+    /// annotation, replacing the entry with an indirect reference to the given target annotation,
+    /// or removing the entry if the target is `None`.
+    ///
+    /// The target annotation must be on the same page as the given annotation; otherwise,
+    /// [PdfiumError::PageAnnotationLinkedAnnotationNotOnPage] is returned. Both annotations
+    /// are given unique names if they do not already have them, so that they can be found
+    /// when the document is saved.
+    pub(crate) fn set_reference_for_annotation(
+        bindings: &dyn PdfiumLibraryBindings,
+        document: FPDF_DOCUMENT,
+        page: FPDF_PAGE,
+        annotation: FPDF_ANNOTATION,
+        key: &str,
+        target: Option<(FPDF_PAGE, FPDF_ANNOTATION)>,
+    ) -> Result<(), PdfiumError> {
+        let value = match target {
+            Some((target_page, target)) => {
+                if locate_annotation(bindings, document, target_page, target)?.0
+                    != locate_annotation(bindings, document, page, annotation)?.0
+                {
+                    return Err(PdfiumError::PageAnnotationLinkedAnnotationNotOnPage);
+                }
+
+                Some(PdfPageAnnotationEditValue::AnnotationReference(
+                    unique_annotation_name(bindings, document, target_page, target)?,
+                ))
+            }
+            None => None,
+        };
+
+        let name = unique_annotation_name(bindings, document, page, annotation)?;
+
+        Self::lock().set(document, name.as_str(), key.as_bytes(), value);
+
+        Ok(())
+    }
+
+    /// Returns the zero-based index, on the given page, of the annotation referenced by
+    /// a pending change to the entry with the given key in the dictionary of the given
+    /// annotation. Returns `Some(None)` if the pending change removes the entry, or the
+    /// referenced annotation is not on the given page, and `None` if there is no pending
+    /// change to the entry.
+    pub(crate) fn get_reference_for_annotation(
+        bindings: &dyn PdfiumLibraryBindings,
+        document: FPDF_DOCUMENT,
+        page: FPDF_PAGE,
+        annotation: FPDF_ANNOTATION,
+        key: &str,
+    ) -> Option<Option<usize>> {
+        let name = get_annotation_string_value(bindings, annotation, "NM")?;
+
+        let target = match Self::lock().get(document, name.as_str(), key.as_bytes())? {
+            Some(PdfPageAnnotationEditValue::AnnotationReference(target)) => target,
+            _ => return Some(None),
+        };
+
+        Some(
+            (0..bindings.FPDFPage_GetAnnotCount(page)).find_map(|index| {
+                let annotation = bindings.FPDFPage_GetAnnot(page, index);
+
+                if annotation.is_null() {
+                    return None;
+                }
+
+                let name = get_annotation_string_value(bindings, annotation, "NM");
+
+                bindings.FPDFPage_CloseAnnot(annotation);
+
+                if name.as_deref() == Some(target.as_str()) {
+                    Some(index as usize)
+                } else {
+                    None
+                }
+            }),
+        )
+    }
+
+    /// Returns `true` if any pending changes are recorded for the given document.
+    #[inline]
+    pub(crate) fn has_edits_for_document(document: FPDF_DOCUMENT) -> bool {
+        Self::lock()
+            .edits
+            .get(&document)
+            .map(|annotations| !annotations.is_empty())
+            .unwrap_or(false)
+    }
+
+    /// Removes all pending changes recorded for the given document.
+    #[inline]
+    pub(crate) fn remove_edits_for_document(document: FPDF_DOCUMENT) {
+        Self::lock().edits.remove(&document);
+    }
+
+    /// Applies all pending changes recorded for the given document to the given file data,
+    /// just written by Pdfium using the given [PdfSaveMode], by appending an incremental update
+    /// to the data. Changes to annotations that no longer exist in the document are ignored.
+    pub(crate) fn apply_edits_to_saved_document(
+        bindings: &dyn PdfiumLibraryBindings,
+        document: FPDF_DOCUMENT,
+        data: &mut Vec<u8>,
+        mode: PdfSaveMode,
+    ) -> Result<(), PdfiumError> {
+        let edits = match Self::lock().edits.get(&document) {
+            Some(edits) => edits.clone(),
+            None => return Ok(()),
+        };
+
+        // Annotations referenced by pending changes must be located as well as the
+        // annotations being changed.
+
+        let locations = find_annotations(
+            bindings,
+            document,
+            &edits
+                .iter()
+                .flat_map(|(name, changes)| {
+                    std::iter::once(name.as_str()).chain(changes.iter().filter_map(|(_, value)| {
+                        match value {
+                            Some(PdfPageAnnotationEditValue::AnnotationReference(target)) => {
+                                Some(target.as_str())
+                            }
+                            _ => None,
+                        }
+                    }))
+                })
+                .collect(),
+        );
+
+        // An incremental save writes only changed objects after the original file data,
+        // which may store objects in object streams. To locate the annotation dictionaries,
+        // we use a complete rewrite of the document instead. Pdfium assigns object numbers
+        // when objects are loaded or created, so they are the same in both cases.
+
+        let rewritten = if mode == PdfSaveMode::Incremental {
+            Some(save_document(bindings, document)?)
+        } else {
+            None
+        };
+
+        let source = rewritten.as_deref().unwrap_or(data.as_slice());
+
+        let syntax = PdfSyntaxDocument::new(source)?;
+
+        let mut containers = HashMap::new();
+
+        for (name, changes) in edits.iter() {
+            // Resolve references to other annotations into indirect references to their
+            // annotation dictionaries. References to annotations that no longer exist,
+            // or that are not indirect objects, are ignored.
+
+            let mut resolved = Vec::with_capacity(changes.len());
+
+            for (key, value) in changes.iter() {
+                let value = match value {
+                    Some(PdfPageAnnotationEditValue::Object(value)) => Some(value.clone()),
+                    Some(PdfPageAnnotationEditValue::AnnotationReference(target)) => {
+                        let location = locations
+                            .get(target.as_str())
+                            .and_then(|locations| locations.first());
+
+                        let reference = match location {
+                            Some((page_index, annotation_index)) => {
+                                syntax.annotation(*page_index, *annotation_index)?.reference
+                            }
+                            None => None,
+                        };
+
+                        match reference {
+                            Some((number, generation)) => {
+                                Some(format!("{} {} R", number, generation).into_bytes())
+                            }
+                            None => continue,
+                        }
+                    }
+                    None => None,
+                };
+
+                resolved.push((key.clone(), value));
+            }
+
+            for (page_index, annotation_index) in locations.get(name.as_str()).into_iter().flatten()
+            {
+                let annotation = syntax.annotation(*page_index, *annotation_index)?;
+
+                let replacement = annotation
+                    .dictionary
+                    .to_bytes_with_changes(source, resolved.as_slice());
+
+                containers
+                    .entry(annotation.container.number)
+                    .or_insert_with(|| (annotation.container.clone(), Vec::new()))
+                    .1
+                    .push((annotation.dictionary.span(), replacement));
+            }
+        }
+
+        let mut objects = Vec::with_capacity(containers.len());
+
+        for (container, mut replacements) in containers.into_values() {
+            // Apply replacements from the end of the container backwards, so that the positions
+            // of replacements yet to be applied are unaffected.
+
+            replacements.sort_by_key(|(span, _)| std::cmp::Reverse(span.start));
+
+            let mut value = source[container.span.clone()].to_vec();
+
+            for (span, replacement) in replacements {
+                value.splice(
+                    span.start - container.span.start..span.end - container.span.start,
+                    replacement,
+                );
+            }
+
+            objects.push((container.number, container.generation, value));
+        }
+
+        append_incremental_update(data, objects.as_slice())
+    }
+}
+
+unsafe impl Send for PdfPageAnnotationEditCache {}
+
+unsafe impl Sync for PdfPageAnnotationEditCache {}
+
+/// Returns the name of the given annotation, first giving it a new name if it does not
+/// already have one or shares its name with another annotation in the given document.
+fn unique_annotation_name(
+    bindings: &dyn PdfiumLibraryBindings,
+    document: FPDF_DOCUMENT,
+    page: FPDF_PAGE,
+    annotation: FPDF_ANNOTATION,
+) -> Result<String, PdfiumError> {
+    let location = locate_annotation(bindings, document, page, annotation)?;
+
+    match get_annotation_string_value(bindings, annotation, "NM") {
+        Some(name)
+            if find_annotations(bindings, document, &[name.as_str()].into())
+                .get(name.as_str())
+                .map(|locations| locations.as_slice() == [location])
+                .unwrap_or(false) =>
+        {
+            Ok(name)
+        }
+        _ => {
+            // The annotation has no name, or shares its name with another annotation.
+            // Give it a new name that is unique.
+
+            let name = format!(
+                "pdfium-render-{}-{}",
+                Utc::now().format("%Y%m%d%H%M%S%f"),
+                ANNOTATION_NAME_COUNTER.fetch_add(1, Ordering::Relaxed)
+            );
+
+            if bindings.is_true(bindings.FPDFAnnot_SetStringValue_str(
+                annotation,
+                "NM",
+                name.as_str(),
+            )) {
+                Ok(name)
+            } else {
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ))
+            }
+        }
+    }
+}
+
+/// Returns the zero-based page index and annotation index of the given annotation.
+fn locate_annotation(
+    bindings: &dyn PdfiumLibraryBindings,
+    document: FPDF_DOCUMENT,
+    page: FPDF_PAGE,
+    annotation: FPDF_ANNOTATION,
+) -> Result<(usize, usize), PdfiumError> {
+    let page_index = PdfPageIndexCache::get_index_for_page(document, page)
+        .ok_or(PdfiumError::SourcePageIndexNotInCache)?;
+
+    let annotation_index = bindings.FPDFPage_GetAnnotIndex(page, annotation);
+
+    if annotation_index < 0 {
+        return Err(PdfiumError::PageAnnotationIndexOutOfBounds);
+    }
+
+    Ok((page_index as usize, annotation_index as usize))
+}
+
+/// Returns the zero-based page indices and annotation indices of all annotations in the
+/// given document with any of the given names.
+fn find_annotations(
+    bindings: &dyn PdfiumLibraryBindings,
+    document: FPDF_DOCUMENT,
+    names: &HashSet<&str>,
+) -> HashMap<String, Vec<(usize, usize)>> {
+    let mut result: HashMap<String, Vec<(usize, usize)>> = HashMap::new();
+
+    for page_index in 0..bindings.FPDF_GetPageCount(document) {
+        let page = bindings.FPDF_LoadPage(document, page_index);
+
+        if page.is_null() {
+            continue;
+        }
+
+        for annotation_index in 0..bindings.FPDFPage_GetAnnotCount(page) {
+            let annotation = bindings.FPDFPage_GetAnnot(page, annotation_index as c_int);
+
+            if annotation.is_null() {
+                continue;
+            }
+
+            if let Some(name) = get_annotation_string_value(bindings, annotation, "NM") {
+                if names.contains(name.as_str()) {
+                    result
+                        .entry(name)
+                        .or_default()
+                        .push((page_index as usize, annotation_index as usize));
+                }
+            }
+
+            bindings.FPDFPage_CloseAnnot(annotation);
+        }
+
+        bindings.FPDF_ClosePage(page);
+    }
+
+    result
+}
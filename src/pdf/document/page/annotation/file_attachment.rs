@@ -0,0 +1,211 @@
+//! Defines the [PdfPageFileAttachmentAnnotation] struct, exposing functionality related to a single
+//! user annotation of type `PdfPageAnnotationType::FileAttachment`.
+
+use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::pdf::document::page::annotation::attachment_points::PdfPageAnnotationAttachmentPoints;
+use crate::pdf::document::page::annotation::objects::PdfPageAnnotationObjects;
+use crate::pdf::document::page::annotation::private::internal::PdfPageAnnotationPrivate;
+
+#[cfg(any(
+    feature = "pdfium_6337",
+    feature = "pdfium_6406",
+    feature = "pdfium_6490",
+    feature = "pdfium_6555",
+    feature = "pdfium_6569",
+    feature = "pdfium_6611",
+    feature = "pdfium_6666",
+    feature = "pdfium_future"
+))]
+use {
+    crate::error::{PdfiumError, PdfiumInternalError},
+    crate::pdf::document::attachment::PdfAttachment,
+    std::os::raw::{c_ulong, c_void},
+};
+
+/// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::FileAttachment`.
+///
+/// A file attachment annotation embeds a single file in the document, displayed on the page
+/// as an icon. Unlike the attachments returned by `PdfDocument::attachments()`, the embedded
+/// file belongs to the annotation itself rather than to the document's name tree.
+pub struct PdfPageFileAttachmentAnnotation<'a> {
+    #[allow(dead_code)] // Only used by functions requiring Pdfium 6337 or later.
+    document_handle: FPDF_DOCUMENT,
+    handle: FPDF_ANNOTATION,
+    objects: PdfPageAnnotationObjects<'a>,
+    attachment_points: PdfPageAnnotationAttachmentPoints<'a>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageFileAttachmentAnnotation<'a> {
+    pub(crate) fn from_pdfium(
+        document_handle: FPDF_DOCUMENT,
+        page_handle: FPDF_PAGE,
+        annotation_handle: FPDF_ANNOTATION,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPageFileAttachmentAnnotation {
+            document_handle,
+            handle: annotation_handle,
+            objects: PdfPageAnnotationObjects::from_pdfium(
+                document_handle,
+                page_handle,
+                annotation_handle,
+                bindings,
+            ),
+            attachment_points: PdfPageAnnotationAttachmentPoints::from_pdfium(
+                annotation_handle,
+                bindings,
+            ),
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfAttachment] embedded in this [PdfPageFileAttachmentAnnotation], if any.
+    #[cfg(any(
+        feature = "pdfium_6337",
+        feature = "pdfium_6406",
+        feature = "pdfium_6490",
+        feature = "pdfium_6555",
+        feature = "pdfium_6569",
+        feature = "pdfium_6611",
+        feature = "pdfium_6666",
+        feature = "pdfium_future"
+    ))]
+    pub fn attachment(&self) -> Option<PdfAttachment<'a>> {
+        let handle = self.bindings.FPDFAnnot_GetFileAttachment(self.handle);
+
+        if handle.is_null() {
+            None
+        } else {
+            Some(PdfAttachment::from_pdfium(handle, self.bindings))
+        }
+    }
+
+    /// Embeds the given byte data in this [PdfPageFileAttachmentAnnotation] as a new
+    /// [PdfAttachment] with the given name, replacing any file previously embedded in
+    /// this annotation. Returns the newly created attachment.
+    #[cfg(any(
+        feature = "pdfium_6337",
+        feature = "pdfium_6406",
+        feature = "pdfium_6490",
+        feature = "pdfium_6555",
+        feature = "pdfium_6569",
+        feature = "pdfium_6611",
+        feature = "pdfium_6666",
+        feature = "pdfium_future"
+    ))]
+    pub fn set_attachment_from_bytes(
+        &mut self,
+        name: &str,
+        bytes: &[u8],
+    ) -> Result<PdfAttachment<'a>, PdfiumError> {
+        // As with PdfAttachments::create_attachment_from_bytes(), creating the attachment
+        // is a two step operation. First, we create the FPDF_ATTACHMENT handle using the given
+        // name. Then, we add the given byte data to the FPDF_ATTACHMENT.
+
+        let handle = self
+            .bindings
+            .FPDFAnnot_AddFileAttachment_str(self.handle, name);
+
+        if handle.is_null() {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        if self.bindings.is_true(self.bindings.FPDFAttachment_SetFile(
+            handle,
+            self.document_handle,
+            bytes.as_ptr() as *const c_void,
+            bytes.len() as c_ulong,
+        )) {
+            Ok(PdfAttachment::from_pdfium(handle, self.bindings))
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+}
+
+impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageFileAttachmentAnnotation<'a> {
+    #[inline]
+    fn handle(&self) -> FPDF_ANNOTATION {
+        self.handle
+    }
+
+    #[inline]
+    fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    #[inline]
+    fn objects_impl(&self) -> &PdfPageAnnotationObjects<'_> {
+        &self.objects
+    }
+
+    #[inline]
+    fn objects_mut_impl(&mut self) -> &mut PdfPageAnnotationObjects<'a> {
+        &mut self.objects
+    }
+
+    #[inline]
+    fn attachment_points_impl(&self) -> &PdfPageAnnotationAttachmentPoints<'_> {
+        &self.attachment_points
+    }
+
+    #[inline]
+    fn attachment_points_mut_impl(&mut self) -> &mut PdfPageAnnotationAttachmentPoints<'a> {
+        &mut self.attachment_points
+    }
+}
+
+#[cfg(test)]
+#[cfg(any(
+    feature = "pdfium_6337",
+    feature = "pdfium_6406",
+    feature = "pdfium_6490",
+    feature = "pdfium_6555",
+    feature = "pdfium_6569",
+    feature = "pdfium_6611",
+    feature = "pdfium_6666",
+    feature = "pdfium_future"
+))]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_file_attachment_annotation() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let bytes = b"measurements: 12.5mm, 40mm".to_vec();
+
+        page.annotations_mut()
+            .create_file_attachment_annotation("notes.txt", &bytes)?;
+
+        let annotation = page.annotations().get(0)?;
+
+        assert_eq!(
+            annotation.annotation_type(),
+            PdfPageAnnotationType::FileAttachment
+        );
+
+        let attachment = annotation
+            .as_file_attachment_annotation()
+            .and_then(|annotation| annotation.attachment())
+            .unwrap();
+
+        assert_eq!(attachment.name(), "notes.txt");
+        assert_eq!(attachment.save_to_bytes()?, bytes);
+
+        Ok(())
+    }
+}
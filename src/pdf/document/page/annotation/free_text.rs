@@ -1,15 +1,68 @@
 //! Defines the [PdfPageFreeTextAnnotation] struct, exposing functionality related to a single
 //! user annotation of type `PdfPageAnnotationType::FreeText`.
 
-use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
+use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_FORMHANDLE, FPDF_PAGE};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
+use crate::pdf::appearance_mode::PdfAppearanceMode;
+use crate::pdf::color::PdfColor;
 use crate::pdf::document::page::annotation::attachment_points::PdfPageAnnotationAttachmentPoints;
 use crate::pdf::document::page::annotation::objects::PdfPageAnnotationObjects;
-use crate::pdf::document::page::annotation::private::internal::PdfPageAnnotationPrivate;
+use crate::pdf::document::page::annotation::private::internal::{
+    get_annotation_dictionary_entry, PdfPageAnnotationPrivate,
+};
+use crate::pdf::document::syntax::{number_to_bytes, PdfSyntaxObject};
+use crate::pdf::points::PdfPoints;
+use std::os::raw::c_float;
+
+#[cfg(any(
+    feature = "pdfium_6555",
+    feature = "pdfium_6569",
+    feature = "pdfium_6611",
+    feature = "pdfium_6666",
+    feature = "pdfium_future"
+))]
+use std::os::raw::c_uint;
+
+/// The horizontal alignment of the text in a single [PdfPageFreeTextAnnotation],
+/// as recorded in the annotation's `/Q` dictionary entry.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PdfPageFreeTextAnnotationAlignment {
+    Left,
+    Center,
+    Right,
+}
+
+impl PdfPageFreeTextAnnotationAlignment {
+    #[inline]
+    pub(crate) fn from_pdfium(value: c_float) -> Self {
+        match value as i32 {
+            1 => PdfPageFreeTextAnnotationAlignment::Center,
+            2 => PdfPageFreeTextAnnotationAlignment::Right,
+            _ => PdfPageFreeTextAnnotationAlignment::Left,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn as_pdfium(&self) -> c_float {
+        match self {
+            PdfPageFreeTextAnnotationAlignment::Left => 0.0,
+            PdfPageFreeTextAnnotationAlignment::Center => 1.0,
+            PdfPageFreeTextAnnotationAlignment::Right => 2.0,
+        }
+    }
+}
 
 /// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::FreeText`.
+///
+/// The font, font size, and color of the annotation's text are recorded in its default
+/// appearance string, and can be changed freely. The text alignment and any callout line,
+/// recorded in the annotation's `/Q` and `/CL` dictionary entries, can also be changed;
+/// Pdfium does not provide a way to write these entries, so changes to them are applied
+/// when the containing document is saved.
 pub struct PdfPageFreeTextAnnotation<'a> {
     handle: FPDF_ANNOTATION,
+    form_handle: Option<FPDF_FORMHANDLE>,
     objects: PdfPageAnnotationObjects<'a>,
     attachment_points: PdfPageAnnotationAttachmentPoints<'a>,
     bindings: &'a dyn PdfiumLibraryBindings,
@@ -20,10 +73,12 @@ impl<'a> PdfPageFreeTextAnnotation<'a> {
         document_handle: FPDF_DOCUMENT,
         page_handle: FPDF_PAGE,
         annotation_handle: FPDF_ANNOTATION,
+        form_handle: Option<FPDF_FORMHANDLE>,
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Self {
         PdfPageFreeTextAnnotation {
             handle: annotation_handle,
+            form_handle,
             objects: PdfPageAnnotationObjects::from_pdfium(
                 document_handle,
                 page_handle,
@@ -37,6 +92,502 @@ impl<'a> PdfPageFreeTextAnnotation<'a> {
             bindings,
         }
     }
+
+    /// Returns the default appearance string of this [PdfPageFreeTextAnnotation], if any.
+    ///
+    /// The default appearance string is a fragment of a content stream, recorded in the
+    /// annotation's `/DA` dictionary entry, that sets the font, font size, and color
+    /// used to draw the annotation's text; for example, `/Helv 12 Tf 1 0 0 rg`.
+    #[inline]
+    pub fn default_appearance(&self) -> Option<String> {
+        self.get_string_value("DA")
+    }
+
+    /// Sets the default appearance string of this [PdfPageFreeTextAnnotation].
+    ///
+    /// Any existing appearance streams will be removed from the annotation, so that
+    /// viewers regenerate the annotation's appearance using the new default appearance string.
+    pub fn set_default_appearance(&mut self, default_appearance: &str) -> Result<(), PdfiumError> {
+        self.set_string_value("DA", default_appearance)?;
+
+        if self
+            .appearance_stream_impl(PdfAppearanceMode::Normal)
+            .is_some()
+        {
+            self.clear_appearance_stream_impl(PdfAppearanceMode::Normal)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the name of the font resource used to draw the text of this
+    /// [PdfPageFreeTextAnnotation], as recorded in the annotation's default appearance string,
+    /// if any. Font resource names are typically abbreviated, for example `Helv` for Helvetica.
+    #[inline]
+    pub fn font_name(&self) -> Option<String> {
+        self.parsed_default_appearance().font_name
+    }
+
+    /// Sets the name of the font resource used to draw the text of this
+    /// [PdfPageFreeTextAnnotation], updating the annotation's default appearance string.
+    #[inline]
+    pub fn set_font_name(&mut self, font_name: &str) -> Result<(), PdfiumError> {
+        let mut default_appearance = self.parsed_default_appearance();
+
+        default_appearance.font_name = Some(font_name.trim_start_matches('/').to_string());
+
+        self.set_default_appearance(&default_appearance.to_string())
+    }
+
+    /// Returns the font size used to draw the text of this [PdfPageFreeTextAnnotation],
+    /// as recorded in the annotation's default appearance string, if any. A font size of zero
+    /// indicates that the text should be automatically sized to fit the annotation.
+    pub fn font_size(&self) -> Option<PdfPoints> {
+        // Pdfium can only retrieve the font size if the containing document has a form.
+
+        if let Some(form_handle) = self.form_handle {
+            let mut value: c_float = 0.0;
+
+            if self.bindings.is_true(self.bindings.FPDFAnnot_GetFontSize(
+                form_handle,
+                self.handle,
+                &mut value,
+            )) {
+                return Some(PdfPoints::new(value));
+            }
+        }
+
+        self.parsed_default_appearance().font_size
+    }
+
+    /// Sets the font size used to draw the text of this [PdfPageFreeTextAnnotation],
+    /// updating the annotation's default appearance string.
+    #[inline]
+    pub fn set_font_size(&mut self, font_size: PdfPoints) -> Result<(), PdfiumError> {
+        let mut default_appearance = self.parsed_default_appearance();
+
+        default_appearance.font_size = Some(font_size);
+
+        self.set_default_appearance(&default_appearance.to_string())
+    }
+
+    /// Returns the color used to draw the text of this [PdfPageFreeTextAnnotation],
+    /// as recorded in the annotation's default appearance string, if any.
+    #[inline]
+    pub fn font_color(&self) -> Option<PdfColor> {
+        self.font_color_from_pdfium()
+            .or_else(|| self.parsed_default_appearance().font_color)
+    }
+
+    /// Retrieves the color used to draw the text of this [PdfPageFreeTextAnnotation]
+    /// from Pdfium. Pdfium can only retrieve the color if the containing document has a form.
+    #[cfg(any(
+        feature = "pdfium_6555",
+        feature = "pdfium_6569",
+        feature = "pdfium_6611",
+        feature = "pdfium_6666",
+        feature = "pdfium_future"
+    ))]
+    fn font_color_from_pdfium(&self) -> Option<PdfColor> {
+        let form_handle = self.form_handle?;
+
+        let mut red: c_uint = 0;
+
+        let mut green: c_uint = 0;
+
+        let mut blue: c_uint = 0;
+
+        if self.bindings.is_true(self.bindings.FPDFAnnot_GetFontColor(
+            form_handle,
+            self.handle,
+            &mut red,
+            &mut green,
+            &mut blue,
+        )) {
+            Some(PdfColor::new(red as u8, green as u8, blue as u8, 255))
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves the color used to draw the text of this [PdfPageFreeTextAnnotation]
+    /// from Pdfium. This version of Pdfium cannot retrieve the color.
+    #[cfg(not(any(
+        feature = "pdfium_6555",
+        feature = "pdfium_6569",
+        feature = "pdfium_6611",
+        feature = "pdfium_6666",
+        feature = "pdfium_future"
+    )))]
+    #[inline]
+    fn font_color_from_pdfium(&self) -> Option<PdfColor> {
+        None
+    }
+
+    /// Sets the color used to draw the text of this [PdfPageFreeTextAnnotation],
+    /// updating the annotation's default appearance string. The alpha component of
+    /// the given color is ignored.
+    #[inline]
+    pub fn set_font_color(&mut self, font_color: PdfColor) -> Result<(), PdfiumError> {
+        let mut default_appearance = self.parsed_default_appearance();
+
+        default_appearance.set_font_color(font_color);
+
+        self.set_default_appearance(&default_appearance.to_string())
+    }
+
+    /// Returns the horizontal alignment of the text in this [PdfPageFreeTextAnnotation],
+    /// as recorded in the annotation's `/Q` dictionary entry.
+    pub fn alignment(&self) -> Result<PdfPageFreeTextAnnotationAlignment, PdfiumError> {
+        Ok(get_annotation_dictionary_entry(self, "Q")?
+            .and_then(|value| value.as_number())
+            .map(PdfPageFreeTextAnnotationAlignment::from_pdfium)
+            .unwrap_or(PdfPageFreeTextAnnotationAlignment::Left))
+    }
+
+    /// Sets the horizontal alignment of the text in this [PdfPageFreeTextAnnotation].
+    ///
+    /// Pdfium does not provide a way to set the `/Q` dictionary entry that records the
+    /// alignment, so the change is applied when the containing document is saved. Any existing
+    /// appearance stream is removed, so that viewers redraw the text using the new alignment.
+    pub fn set_alignment(
+        &mut self,
+        alignment: PdfPageFreeTextAnnotationAlignment,
+    ) -> Result<(), PdfiumError> {
+        self.set_dictionary_entry_impl("Q", Some(number_to_bytes(alignment.as_pdfium())))?;
+
+        self.clear_appearance_stream_impl(PdfAppearanceMode::Normal)
+    }
+
+    /// Returns `true` if this [PdfPageFreeTextAnnotation] has a callout line, i.e. if it is
+    /// drawn as a callout pointing at a specific area of the page.
+    #[inline]
+    pub fn has_callout_line(&self) -> Result<bool, PdfiumError> {
+        Ok(!self.callout_line()?.is_empty())
+    }
+
+    /// Returns the points of the callout line of this [PdfPageFreeTextAnnotation], as recorded
+    /// in the annotation's `/CL` dictionary entry, or an empty list if the annotation does not
+    /// have a callout line.
+    ///
+    /// A callout line has either two points, the start and end of a straight line, or three
+    /// points, the start, knee, and end of a line with a bend in it. The line starts at the area
+    /// of the page the callout points at.
+    pub fn callout_line(&self) -> Result<Vec<(PdfPoints, PdfPoints)>, PdfiumError> {
+        Ok(match get_annotation_dictionary_entry(self, "CL")? {
+            Some(PdfSyntaxObject::Array(values)) => values
+                .chunks_exact(2)
+                .filter_map(|point| match (point[0].as_number(), point[1].as_number()) {
+                    (Some(x), Some(y)) => Some((PdfPoints::new(x), PdfPoints::new(y))),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        })
+    }
+
+    /// Sets the callout line of this [PdfPageFreeTextAnnotation] to the given points, turning
+    /// the annotation into a callout, or removes the annotation's callout line if the given
+    /// list of points is empty.
+    ///
+    /// A callout line must have either two or three points, as described in
+    /// [PdfPageFreeTextAnnotation::callout_line()]; any other number of points returns
+    /// [PdfiumError::PageAnnotationCalloutLineInvalid].
+    ///
+    /// Pdfium does not provide a way to set the `/CL` dictionary entry that records the
+    /// callout line, so the change is applied when the containing document is saved.
+    /// Any existing appearance stream is removed, so that viewers draw the new callout line.
+    pub fn set_callout_line(
+        &mut self,
+        points: &[(PdfPoints, PdfPoints)],
+    ) -> Result<(), PdfiumError> {
+        if points.is_empty() {
+            // The annotation's intent marks it as a callout, so remove that too.
+
+            if let Some(PdfSyntaxObject::Name(intent)) =
+                get_annotation_dictionary_entry(self, "IT")?
+            {
+                if intent == b"FreeTextCallout" {
+                    self.set_dictionary_entry_impl("IT", None)?;
+                }
+            }
+
+            self.set_dictionary_entry_impl("CL", None)?;
+        } else if points.len() == 2 || points.len() == 3 {
+            let mut line = b"[".to_vec();
+
+            for (index, (x, y)) in points.iter().enumerate() {
+                if index > 0 {
+                    line.push(b' ');
+                }
+
+                line.extend_from_slice(&number_to_bytes(x.value));
+                line.push(b' ');
+                line.extend_from_slice(&number_to_bytes(y.value));
+            }
+
+            line.push(b']');
+
+            self.set_dictionary_entry_impl("CL", Some(line))?;
+            self.set_dictionary_entry_impl("IT", Some(b"/FreeTextCallout".to_vec()))?;
+        } else {
+            return Err(PdfiumError::PageAnnotationCalloutLineInvalid);
+        }
+
+        self.clear_appearance_stream_impl(PdfAppearanceMode::Normal)
+    }
+
+    /// Returns the default style string of this [PdfPageFreeTextAnnotation], if any.
+    ///
+    /// The default style string, recorded in the annotation's `/DS` dictionary entry,
+    /// uses CSS-style properties to describe the text style of rich text annotations; for example,
+    /// `font: 12pt Helvetica; text-align: center; color: #FF0000`.
+    #[inline]
+    pub fn default_style(&self) -> Option<String> {
+        self.get_string_value("DS")
+    }
+
+    /// Sets the default style string of this [PdfPageFreeTextAnnotation].
+    #[inline]
+    pub fn set_default_style(&mut self, default_style: &str) -> Result<(), PdfiumError> {
+        self.set_string_value("DS", default_style)
+    }
+
+    /// Returns the parsed default appearance string of this [PdfPageFreeTextAnnotation],
+    /// or an empty default appearance if the annotation does not have one.
+    #[inline]
+    fn parsed_default_appearance(&self) -> PdfDefaultAppearance {
+        self.default_appearance()
+            .map(|default_appearance| PdfDefaultAppearance::parse(default_appearance.as_str()))
+            .unwrap_or_default()
+    }
+}
+
+/// The font, font size, and color set by a default appearance string, together with
+/// any other operations in the string, which are preserved unchanged when the string
+/// is rewritten.
+#[derive(Debug, Default)]
+struct PdfDefaultAppearance {
+    font_name: Option<String>,
+    font_size: Option<PdfPoints>,
+    font_color: Option<PdfColor>,
+    color_operation: Option<String>,
+    operations: Vec<PdfDefaultAppearanceOperation>,
+}
+
+/// A single operation in a default appearance string.
+#[derive(Debug, Clone, PartialEq)]
+enum PdfDefaultAppearanceOperation {
+    /// The operation that sets the font and font size.
+    Font,
+
+    /// The operation that sets the fill color.
+    Color,
+
+    /// Any other operation, together with its operands, recorded as it appeared
+    /// in the original string.
+    Other(String),
+}
+
+impl PdfDefaultAppearance {
+    /// The font resource name used when writing a default appearance string
+    /// that does not specify a font.
+    const DEFAULT_FONT_NAME: &'static str = "Helv";
+
+    /// The font size used when writing a default appearance string that does not
+    /// specify a font size.
+    const DEFAULT_FONT_SIZE: PdfPoints = PdfPoints::new(12.0);
+
+    /// Parses the font, font size, and color operators in the given default appearance string.
+    /// Any other operators are recorded so they can be written back unchanged.
+    fn parse(default_appearance: &str) -> Self {
+        let mut result = PdfDefaultAppearance::default();
+
+        let mut operands: Vec<&str> = Vec::new();
+
+        for token in default_appearance.split_whitespace() {
+            let number = |index: usize| -> Option<f32> {
+                operands
+                    .len()
+                    .checked_sub(index)
+                    .and_then(|index| operands.get(index))
+                    .and_then(|operand| operand.parse::<f32>().ok())
+            };
+
+            let component = |index: usize| -> u8 {
+                (number(index).unwrap_or(0.0).clamp(0.0, 1.0) * 255.0).round() as u8
+            };
+
+            let operation = match token {
+                "Tf" => {
+                    result.font_name = operands
+                        .len()
+                        .checked_sub(2)
+                        .and_then(|index| operands.get(index))
+                        .map(|name| name.trim_start_matches('/').to_string());
+
+                    result.font_size = number(1).map(PdfPoints::new);
+
+                    PdfDefaultAppearanceOperation::Font
+                }
+                "g" => {
+                    let gray = component(1);
+
+                    result.font_color = Some(PdfColor::new(gray, gray, gray, 255));
+
+                    PdfDefaultAppearanceOperation::Color
+                }
+                "rg" => {
+                    result.font_color =
+                        Some(PdfColor::new(component(3), component(2), component(1), 255));
+
+                    PdfDefaultAppearanceOperation::Color
+                }
+                "k" => {
+                    let black = 1.0 - number(1).unwrap_or(0.0).clamp(0.0, 1.0);
+
+                    let channel = |index: usize| -> u8 {
+                        ((1.0 - number(index).unwrap_or(0.0).clamp(0.0, 1.0)) * black * 255.0)
+                            .round() as u8
+                    };
+
+                    result.font_color =
+                        Some(PdfColor::new(channel(4), channel(3), channel(2), 255));
+
+                    PdfDefaultAppearanceOperation::Color
+                }
+                _ if token.starts_with('/')
+                    || token.starts_with('[')
+                    || token.starts_with('(')
+                    || token.starts_with('<')
+                    || token.parse::<f32>().is_ok() =>
+                {
+                    operands.push(token);
+
+                    continue;
+                }
+                _ => {
+                    operands.push(token);
+
+                    PdfDefaultAppearanceOperation::Other(operands.join(" "))
+                }
+            };
+
+            if operation == PdfDefaultAppearanceOperation::Color {
+                operands.push(token);
+
+                result.color_operation = Some(operands.join(" "));
+            }
+
+            // Only the last font and color operations in the string take effect.
+
+            if operation != PdfDefaultAppearanceOperation::Font
+                && operation != PdfDefaultAppearanceOperation::Color
+                || !result.operations.contains(&operation)
+            {
+                result.operations.push(operation);
+            } else {
+                result.operations.retain(|existing| *existing != operation);
+                result.operations.push(operation);
+            }
+
+            operands.clear();
+        }
+
+        if !operands.is_empty() {
+            result
+                .operations
+                .push(PdfDefaultAppearanceOperation::Other(operands.join(" ")));
+        }
+
+        result
+    }
+
+    /// Sets the color used to draw text, replacing any existing color operation.
+    fn set_font_color(&mut self, font_color: PdfColor) {
+        self.font_color = Some(font_color);
+        self.color_operation = None;
+    }
+
+    /// Returns the font operation in this default appearance string.
+    fn font_operation(&self) -> String {
+        format!(
+            "/{} {} Tf",
+            self.font_name
+                .as_deref()
+                .unwrap_or(PdfDefaultAppearance::DEFAULT_FONT_NAME),
+            format_number(
+                self.font_size
+                    .unwrap_or(PdfDefaultAppearance::DEFAULT_FONT_SIZE)
+                    .value
+            ),
+        )
+    }
+
+    /// Returns the color operation in this default appearance string.
+    fn color_operation(&self) -> String {
+        match self.color_operation.as_ref() {
+            Some(operation) => operation.clone(),
+            None => {
+                let color = self.font_color.unwrap_or(PdfColor::BLACK);
+
+                format!(
+                    "{} {} {} rg",
+                    format_number(color.red() as f32 / 255.0),
+                    format_number(color.green() as f32 / 255.0),
+                    format_number(color.blue() as f32 / 255.0),
+                )
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for PdfDefaultAppearance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // A default appearance string must always set a font, so a font operation
+        // is added at the start of the string if there is not one already. Similarly,
+        // a color operation is added at the end of the string if necessary.
+
+        let mut operations = Vec::with_capacity(self.operations.len() + 2);
+
+        if !self
+            .operations
+            .contains(&PdfDefaultAppearanceOperation::Font)
+        {
+            operations.push(self.font_operation());
+        }
+
+        for operation in self.operations.iter() {
+            operations.push(match operation {
+                PdfDefaultAppearanceOperation::Font => self.font_operation(),
+                PdfDefaultAppearanceOperation::Color => self.color_operation(),
+                PdfDefaultAppearanceOperation::Other(operation) => operation.clone(),
+            });
+        }
+
+        if !self
+            .operations
+            .contains(&PdfDefaultAppearanceOperation::Color)
+        {
+            operations.push(self.color_operation());
+        }
+
+        write!(f, "{}", operations.join(" "))
+    }
+}
+
+/// Formats the given number for use as an operand in a content stream, using at most
+/// four decimal places and omitting any trailing zeros.
+fn format_number(value: f32) -> String {
+    let result = format!("{:.4}", value);
+
+    let result = result.trim_end_matches('0').trim_end_matches('.');
+
+    if result == "-0" {
+        "0".to_string()
+    } else {
+        result.to_string()
+    }
 }
 
 impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageFreeTextAnnotation<'a> {
@@ -46,7 +597,7 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageFreeTextAnnotation<'a> {
     }
 
     #[inline]
-    fn bindings(&self) -> &dyn PdfiumLibraryBindings {
+    fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
         self.bindings
     }
 
@@ -70,3 +621,127 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageFreeTextAnnotation<'a> {
         &mut self.attachment_points
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_parse_default_appearance() {
+        let parsed = PdfDefaultAppearance::parse("/Helv 10.5 Tf 1 0 0 rg");
+
+        assert_eq!(parsed.font_name.as_deref(), Some("Helv"));
+        assert_eq!(parsed.font_size, Some(PdfPoints::new(10.5)));
+        assert_eq!(
+            parsed.font_color.map(|color| color.to_hex()),
+            Some(PdfColor::new(255, 0, 0, 255).to_hex())
+        );
+
+        assert_eq!(parsed.to_string(), "/Helv 10.5 Tf 1 0 0 rg");
+
+        let parsed = PdfDefaultAppearance::parse("0.5 g /Cour 0 Tf");
+
+        assert_eq!(parsed.font_name.as_deref(), Some("Cour"));
+        assert_eq!(parsed.font_size, Some(PdfPoints::ZERO));
+        assert_eq!(
+            parsed.font_color.map(|color| color.to_hex()),
+            Some(PdfColor::new(128, 128, 128, 255).to_hex())
+        );
+
+        let parsed = PdfDefaultAppearance::parse("0 0 0 1 k");
+
+        assert_eq!(parsed.font_name, None);
+        assert_eq!(
+            parsed.font_color.map(|color| color.to_hex()),
+            Some(PdfColor::BLACK.to_hex())
+        );
+
+        assert_eq!(
+            PdfDefaultAppearance::default().to_string(),
+            "/Helv 12 Tf 0 0 0 rg"
+        );
+    }
+
+    #[test]
+    fn test_default_appearance_preserves_other_operators() {
+        let mut parsed = PdfDefaultAppearance::parse("0.5 g /Cour 9 Tf 100 Tz 2 Tr");
+
+        assert_eq!(parsed.to_string(), "0.5 g /Cour 9 Tf 100 Tz 2 Tr");
+
+        parsed.font_size = Some(PdfPoints::new(14.0));
+
+        assert_eq!(parsed.to_string(), "0.5 g /Cour 14 Tf 100 Tz 2 Tr");
+
+        parsed.set_font_color(PdfColor::new(255, 0, 0, 255));
+
+        assert_eq!(parsed.to_string(), "1 0 0 rg /Cour 14 Tf 100 Tz 2 Tr");
+
+        let parsed = PdfDefaultAppearance::parse("100 Tz");
+
+        assert_eq!(parsed.to_string(), "/Helv 12 Tf 100 Tz 0 0 0 rg");
+    }
+
+    #[test]
+    fn test_free_text_styling_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let callout_line = vec![
+            (PdfPoints::new(100.0), PdfPoints::new(100.0)),
+            (PdfPoints::new(150.0), PdfPoints::new(150.0)),
+            (PdfPoints::new(200.0), PdfPoints::new(150.0)),
+        ];
+
+        {
+            let mut page = document
+                .pages_mut()
+                .create_page_at_start(PdfPagePaperSize::a4())?;
+
+            let mut annotation = page.annotations_mut().create_free_text_annotation("Note")?;
+
+            annotation.set_font_name("Helv")?;
+            annotation.set_font_size(PdfPoints::new(14.0))?;
+            annotation.set_font_color(PdfColor::new(255, 0, 0, 255))?;
+            annotation.set_alignment(PdfPageFreeTextAnnotationAlignment::Center)?;
+            annotation.set_callout_line(&callout_line)?;
+
+            assert_eq!(
+                annotation.alignment()?,
+                PdfPageFreeTextAnnotationAlignment::Center
+            );
+            assert_eq!(annotation.callout_line()?, callout_line);
+
+            assert!(annotation.set_callout_line(&callout_line[..1]).is_err());
+        }
+
+        let bytes = document.save_to_bytes()?;
+
+        drop(document);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().first()?;
+
+        let annotation = page.annotations().get(0)?;
+
+        let annotation = annotation.as_free_text_annotation().unwrap();
+
+        assert_eq!(annotation.font_name(), Some("Helv".to_string()));
+        assert_eq!(annotation.font_size(), Some(PdfPoints::new(14.0)));
+        assert_eq!(
+            annotation.font_color().map(|color| color.to_hex()),
+            Some(PdfColor::new(255, 0, 0, 255).to_hex())
+        );
+        assert_eq!(
+            annotation.alignment()?,
+            PdfPageFreeTextAnnotationAlignment::Center
+        );
+        assert!(annotation.has_callout_line()?);
+        assert_eq!(annotation.callout_line()?, callout_line);
+
+        Ok(())
+    }
+}
@@ -3,15 +3,22 @@
 
 use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
 use crate::pdf::document::page::annotation::attachment_points::PdfPageAnnotationAttachmentPoints;
+use crate::pdf::document::page::annotation::ink_strokes::PdfPageAnnotationInkStrokes;
 use crate::pdf::document::page::annotation::objects::PdfPageAnnotationObjects;
 use crate::pdf::document::page::annotation::private::internal::PdfPageAnnotationPrivate;
+use crate::pdf::document::page::object::PdfPageObject;
+
+#[cfg(doc)]
+use crate::pdf::appearance_mode::PdfAppearanceMode;
 
 /// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::Ink`.
 pub struct PdfPageInkAnnotation<'a> {
     handle: FPDF_ANNOTATION,
     objects: PdfPageAnnotationObjects<'a>,
     attachment_points: PdfPageAnnotationAttachmentPoints<'a>,
+    strokes: PdfPageAnnotationInkStrokes<'a>,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
 
@@ -34,6 +41,7 @@ impl<'a> PdfPageInkAnnotation<'a> {
                 annotation_handle,
                 bindings,
             ),
+            strokes: PdfPageAnnotationInkStrokes::from_pdfium(annotation_handle, bindings),
             bindings,
         }
     }
@@ -43,6 +51,32 @@ impl<'a> PdfPageInkAnnotation<'a> {
     pub fn objects_mut(&mut self) -> &mut PdfPageAnnotationObjects<'a> {
         &mut self.objects
     }
+
+    /// Replaces the page objects in this [PdfPageInkAnnotation] with the given objects.
+    /// Pdfium generates the normal appearance stream of the annotation from its page objects,
+    /// so this sets the appearance of the annotation in [PdfAppearanceMode::Normal].
+    ///
+    /// To set the appearance of the annotation from raw content stream text instead, use the
+    /// `PdfPageAnnotationCommon::set_appearance_stream()` function.
+    #[inline]
+    pub fn set_appearance_stream_from_objects(
+        &mut self,
+        objects: Vec<PdfPageObject<'a>>,
+    ) -> Result<(), PdfiumError> {
+        self.set_appearance_stream_from_objects_impl(objects)
+    }
+
+    /// Returns an immutable collection of all the freehand strokes in this [PdfPageInkAnnotation].
+    #[inline]
+    pub fn strokes(&self) -> &PdfPageAnnotationInkStrokes<'a> {
+        &self.strokes
+    }
+
+    /// Returns a mutable collection of all the freehand strokes in this [PdfPageInkAnnotation].
+    #[inline]
+    pub fn strokes_mut(&mut self) -> &mut PdfPageAnnotationInkStrokes<'a> {
+        &mut self.strokes
+    }
 }
 
 impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageInkAnnotation<'a> {
@@ -52,7 +86,7 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageInkAnnotation<'a> {
     }
 
     #[inline]
-    fn bindings(&self) -> &dyn PdfiumLibraryBindings {
+    fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
         self.bindings
     }
 
@@ -0,0 +1,300 @@
+//! Defines the [PdfPageAnnotationInkStrokes] struct, a collection of all the freehand
+//! strokes in a `PdfPageInkAnnotation` object.
+
+use crate::bindgen::{FPDF_ANNOTATION, FS_POINTF};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::points::PdfPoints;
+use std::ops::{Range, RangeInclusive};
+use std::os::raw::c_ulong;
+
+/// The zero-based index of a single stroke inside its containing
+/// [PdfPageAnnotationInkStrokes] collection.
+pub type PdfPageAnnotationInkStrokeIndex = usize;
+
+/// A single freehand stroke in a `PdfPageInkAnnotation`, expressed as the sequence of
+/// (x, y) points in page coordinates that the stroke passes through.
+pub type PdfPageAnnotationInkStroke = Vec<(PdfPoints, PdfPoints)>;
+
+/// The set of all the freehand strokes in a `PdfPageInkAnnotation`, stored in the /InkList
+/// entry of the annotation dictionary.
+///
+/// Adding or removing strokes does not update the annotation's appearance stream.
+/// Viewers that honour the /InkList entry, including Pdfium itself, will regenerate the
+/// appearance of an ink annotation that has no appearance stream when the document is loaded.
+pub struct PdfPageAnnotationInkStrokes<'a> {
+    annotation_handle: FPDF_ANNOTATION,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageAnnotationInkStrokes<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        annotation_handle: FPDF_ANNOTATION,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPageAnnotationInkStrokes {
+            annotation_handle,
+            bindings,
+        }
+    }
+
+    /// Returns the number of strokes in this [PdfPageAnnotationInkStrokes] collection.
+    #[inline]
+    pub fn len(&self) -> PdfPageAnnotationInkStrokeIndex {
+        self.bindings
+            .FPDFAnnot_GetInkListCount(self.annotation_handle)
+            as PdfPageAnnotationInkStrokeIndex
+    }
+
+    /// Returns `true` if this [PdfPageAnnotationInkStrokes] collection is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a Range from `0..(number of strokes)` for this
+    /// [PdfPageAnnotationInkStrokes] collection.
+    #[inline]
+    pub fn as_range(&self) -> Range<PdfPageAnnotationInkStrokeIndex> {
+        0..self.len()
+    }
+
+    /// Returns an inclusive Range from `0..=(number of strokes - 1)` for this
+    /// [PdfPageAnnotationInkStrokes] collection.
+    #[inline]
+    pub fn as_range_inclusive(&self) -> RangeInclusive<PdfPageAnnotationInkStrokeIndex> {
+        if self.is_empty() {
+            0..=0
+        } else {
+            0..=(self.len() - 1)
+        }
+    }
+
+    /// Returns a single stroke from this [PdfPageAnnotationInkStrokes] collection.
+    pub fn get(
+        &self,
+        index: PdfPageAnnotationInkStrokeIndex,
+    ) -> Result<PdfPageAnnotationInkStroke, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::PageAnnotationInkStrokeIndexOutOfBounds);
+        }
+
+        // Retrieving the points in a stroke from Pdfium is a two-step operation. First, we call
+        // FPDFAnnot_GetInkListPath() with a null buffer; this will retrieve the number of points
+        // in the stroke. We then reserve a buffer of the given length and call
+        // FPDFAnnot_GetInkListPath() again with a pointer to the buffer.
+
+        let point_count = self.bindings.FPDFAnnot_GetInkListPath(
+            self.annotation_handle,
+            index as c_ulong,
+            std::ptr::null_mut(),
+            0,
+        );
+
+        if point_count == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut buffer = vec![FS_POINTF { x: 0.0, y: 0.0 }; point_count as usize];
+
+        let result = self.bindings.FPDFAnnot_GetInkListPath(
+            self.annotation_handle,
+            index as c_ulong,
+            buffer.as_mut_ptr(),
+            point_count,
+        );
+
+        if result != point_count {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        Ok(buffer
+            .iter()
+            .map(|point| (PdfPoints::new(point.x), PdfPoints::new(point.y)))
+            .collect())
+    }
+
+    /// Returns the first stroke in this [PdfPageAnnotationInkStrokes] collection.
+    #[inline]
+    pub fn first(&self) -> Result<PdfPageAnnotationInkStroke, PdfiumError> {
+        if !self.is_empty() {
+            self.get(0)
+        } else {
+            Err(PdfiumError::NoInkStrokesInPageAnnotation)
+        }
+    }
+
+    /// Returns the last stroke in this [PdfPageAnnotationInkStrokes] collection.
+    #[inline]
+    pub fn last(&self) -> Result<PdfPageAnnotationInkStroke, PdfiumError> {
+        if !self.is_empty() {
+            self.get(self.len() - 1)
+        } else {
+            Err(PdfiumError::NoInkStrokesInPageAnnotation)
+        }
+    }
+
+    /// Creates a new stroke passing through the given sequence of (x, y) points,
+    /// and appends it to the end of this [PdfPageAnnotationInkStrokes] collection,
+    /// returning the index of the new stroke.
+    pub fn create_stroke_at_end(
+        &mut self,
+        points: &[(PdfPoints, PdfPoints)],
+    ) -> Result<PdfPageAnnotationInkStrokeIndex, PdfiumError> {
+        let points = points
+            .iter()
+            .map(|(x, y)| FS_POINTF {
+                x: x.value,
+                y: y.value,
+            })
+            .collect::<Vec<_>>();
+
+        let result = self.bindings.FPDFAnnot_AddInkStroke(
+            self.annotation_handle,
+            points.as_ptr(),
+            points.len(),
+        );
+
+        if result < 0 {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        } else {
+            Ok(result as PdfPageAnnotationInkStrokeIndex)
+        }
+    }
+
+    /// Removes the stroke at the given index from this [PdfPageAnnotationInkStrokes] collection,
+    /// returning the points in the removed stroke.
+    ///
+    /// Pdfium does not support removing individual strokes, so all the strokes in this
+    /// collection are removed and all strokes other than the target stroke are then re-added.
+    pub fn delete_stroke_at_index(
+        &mut self,
+        index: PdfPageAnnotationInkStrokeIndex,
+    ) -> Result<PdfPageAnnotationInkStroke, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::PageAnnotationInkStrokeIndexOutOfBounds);
+        }
+
+        let mut strokes = self
+            .as_range()
+            .map(|index| self.get(index))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let removed = strokes.remove(index);
+
+        self.clear()?;
+
+        for stroke in strokes.iter() {
+            self.create_stroke_at_end(stroke)?;
+        }
+
+        Ok(removed)
+    }
+
+    /// Removes all the strokes in this [PdfPageAnnotationInkStrokes] collection.
+    pub fn clear(&mut self) -> Result<(), PdfiumError> {
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        if self.bindings.is_true(
+            self.bindings
+                .FPDFAnnot_RemoveInkList(self.annotation_handle),
+        ) {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Returns an iterator over all the strokes in this [PdfPageAnnotationInkStrokes] collection.
+    #[inline]
+    pub fn iter(&self) -> PdfPageAnnotationInkStrokesIterator<'_> {
+        PdfPageAnnotationInkStrokesIterator::new(self)
+    }
+}
+
+/// An iterator over all the strokes in a [PdfPageAnnotationInkStrokes] collection.
+pub struct PdfPageAnnotationInkStrokesIterator<'a> {
+    strokes: &'a PdfPageAnnotationInkStrokes<'a>,
+    next_index: PdfPageAnnotationInkStrokeIndex,
+}
+
+impl<'a> PdfPageAnnotationInkStrokesIterator<'a> {
+    #[inline]
+    pub(crate) fn new(strokes: &'a PdfPageAnnotationInkStrokes<'a>) -> Self {
+        PdfPageAnnotationInkStrokesIterator {
+            strokes,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfPageAnnotationInkStrokesIterator<'a> {
+    type Item = PdfPageAnnotationInkStroke;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.strokes.get(self.next_index);
+
+        self.next_index += 1;
+
+        next.ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_ink_strokes() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut annotation = page.annotations_mut().create_ink_annotation()?;
+
+        let first = vec![
+            (PdfPoints::new(10.0), PdfPoints::new(10.0)),
+            (PdfPoints::new(20.0), PdfPoints::new(30.0)),
+        ];
+
+        let second = vec![
+            (PdfPoints::new(50.0), PdfPoints::new(50.0)),
+            (PdfPoints::new(60.0), PdfPoints::new(40.0)),
+            (PdfPoints::new(70.0), PdfPoints::new(50.0)),
+        ];
+
+        let strokes = annotation.strokes_mut();
+
+        assert!(strokes.is_empty());
+        assert_eq!(strokes.create_stroke_at_end(&first)?, 0);
+        assert_eq!(strokes.create_stroke_at_end(&second)?, 1);
+        assert_eq!(
+            strokes.iter().collect::<Vec<_>>(),
+            vec![first.clone(), second.clone()]
+        );
+
+        assert_eq!(strokes.delete_stroke_at_index(0)?, first);
+        assert_eq!(strokes.len(), 1);
+        assert_eq!(strokes.first()?, second);
+
+        strokes.clear()?;
+
+        assert!(strokes.is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+//! Defines the [PdfPageLineAnnotation] struct, exposing functionality related to a single
+//! user annotation of type `PdfPageAnnotationType::Line`.
+
+use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE, FS_POINTF};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::page::annotation::attachment_points::PdfPageAnnotationAttachmentPoints;
+use crate::pdf::document::page::annotation::objects::PdfPageAnnotationObjects;
+use crate::pdf::document::page::annotation::private::internal::PdfPageAnnotationPrivate;
+use crate::pdf::points::PdfPoints;
+
+/// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::Line`.
+pub struct PdfPageLineAnnotation<'a> {
+    handle: FPDF_ANNOTATION,
+    objects: PdfPageAnnotationObjects<'a>,
+    attachment_points: PdfPageAnnotationAttachmentPoints<'a>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageLineAnnotation<'a> {
+    pub(crate) fn from_pdfium(
+        document_handle: FPDF_DOCUMENT,
+        page_handle: FPDF_PAGE,
+        annotation_handle: FPDF_ANNOTATION,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPageLineAnnotation {
+            handle: annotation_handle,
+            objects: PdfPageAnnotationObjects::from_pdfium(
+                document_handle,
+                page_handle,
+                annotation_handle,
+                bindings,
+            ),
+            attachment_points: PdfPageAnnotationAttachmentPoints::from_pdfium(
+                annotation_handle,
+                bindings,
+            ),
+            bindings,
+        }
+    }
+
+    /// Returns the start and end points of the line drawn by this [PdfPageLineAnnotation],
+    /// as recorded in the annotation's `/L` dictionary entry.
+    fn points(&self) -> Result<(FS_POINTF, FS_POINTF), PdfiumError> {
+        let mut start = FS_POINTF { x: 0.0, y: 0.0 };
+
+        let mut end = FS_POINTF { x: 0.0, y: 0.0 };
+
+        if self.bindings.is_true(
+            self.bindings
+                .FPDFAnnot_GetLine(self.handle, &mut start, &mut end),
+        ) {
+            Ok((start, end))
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Returns the `(x, y)` coordinates of the start point of the line drawn by this
+    /// [PdfPageLineAnnotation].
+    #[inline]
+    pub fn start(&self) -> Result<(PdfPoints, PdfPoints), PdfiumError> {
+        self.points()
+            .map(|(start, _)| (PdfPoints::new(start.x), PdfPoints::new(start.y)))
+    }
+
+    /// Returns the `(x, y)` coordinates of the end point of the line drawn by this
+    /// [PdfPageLineAnnotation].
+    #[inline]
+    pub fn end(&self) -> Result<(PdfPoints, PdfPoints), PdfiumError> {
+        self.points()
+            .map(|(_, end)| (PdfPoints::new(end.x), PdfPoints::new(end.y)))
+    }
+}
+
+impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageLineAnnotation<'a> {
+    #[inline]
+    fn handle(&self) -> FPDF_ANNOTATION {
+        self.handle
+    }
+
+    #[inline]
+    fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    #[inline]
+    fn objects_impl(&self) -> &PdfPageAnnotationObjects<'_> {
+        &self.objects
+    }
+
+    #[inline]
+    fn objects_mut_impl(&mut self) -> &mut PdfPageAnnotationObjects<'a> {
+        &mut self.objects
+    }
+
+    #[inline]
+    fn attachment_points_impl(&self) -> &PdfPageAnnotationAttachmentPoints<'_> {
+        &self.attachment_points
+    }
+
+    #[inline]
+    fn attachment_points_mut_impl(&mut self) -> &mut PdfPageAnnotationAttachmentPoints<'a> {
+        &mut self.attachment_points
+    }
+}
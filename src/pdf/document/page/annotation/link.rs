@@ -86,7 +86,7 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageLinkAnnotation<'a> {
     }
 
     #[inline]
-    fn bindings(&self) -> &dyn PdfiumLibraryBindings {
+    fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
         self.bindings
     }
 
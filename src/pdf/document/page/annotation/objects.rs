@@ -58,6 +58,20 @@ impl<'a> PdfPageAnnotationObjects<'a> {
         &self.annotation_handle
     }
 
+    /// Returns the internal `FPDF_PAGE` handle for the page containing the [PdfPageAnnotation]
+    /// that contains this [PdfPageAnnotationObjects] collection.
+    #[inline]
+    pub(crate) fn get_page_handle(&self) -> &FPDF_PAGE {
+        &self.page_handle
+    }
+
+    /// Returns the internal `FPDF_DOCUMENT` handle for the document containing the
+    /// [PdfPageAnnotation] that contains this [PdfPageAnnotationObjects] collection.
+    #[inline]
+    pub(crate) fn get_document_handle(&self) -> &FPDF_DOCUMENT {
+        &self.document_handle
+    }
+
     /// Sets whether or not this [PdfPageAnnotationObjects] collection should trigger
     /// content regeneration on its containing [PdfPage] when the collection is mutated.
     #[inline]
@@ -103,6 +117,7 @@ impl<'a> PdfPageObjectsPrivate<'a> for PdfPageAnnotationObjects<'a> {
         } else {
             Ok(PdfPageObject::from_pdfium(
                 object_handle,
+                self.document_handle,
                 None,
                 Some(self.annotation_handle),
                 self.bindings,
@@ -46,7 +46,7 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPagePopupAnnotation<'a> {
     }
 
     #[inline]
-    fn bindings(&self) -> &dyn PdfiumLibraryBindings {
+    fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
         self.bindings
     }
 
@@ -9,22 +9,32 @@ pub(crate) mod internal {
 
     use crate::bindgen::{
         FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color,
-        FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_InteriorColor, FPDF_ANNOTATION, FPDF_OBJECT_STRING,
-        FPDF_PAGEOBJECT, FPDF_WCHAR, FS_RECTF,
+        FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_InteriorColor, FPDF_ANNOTATION, FPDF_DOCUMENT,
+        FPDF_OBJECT_STRING, FPDF_PAGE, FPDF_PAGEOBJECT, FPDF_WCHAR, FS_POINTF, FS_RECTF,
     };
     use crate::bindings::PdfiumLibraryBindings;
     use crate::error::{PdfiumError, PdfiumInternalError};
+    use crate::pdf::appearance_mode::PdfAppearanceMode;
     use crate::pdf::color::PdfColor;
     use crate::pdf::document::page::annotation::attachment_points::PdfPageAnnotationAttachmentPoints;
+    use crate::pdf::document::page::annotation::border::{
+        PdfPageAnnotationBorder, PdfPageAnnotationBorderEffect, PdfPageAnnotationBorderStyle,
+    };
+    use crate::pdf::document::page::annotation::edit_cache::PdfPageAnnotationEditCache;
     use crate::pdf::document::page::annotation::objects::PdfPageAnnotationObjects;
-    use crate::pdf::document::page::annotation::{PdfPageAnnotationCommon, PdfPageAnnotationType};
+    use crate::pdf::document::page::annotation::{
+        PdfPageAnnotation, PdfPageAnnotationCommon, PdfPageAnnotationFlags, PdfPageAnnotationType,
+    };
+    use crate::pdf::document::page::object::PdfPageObject;
+    use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
+    use crate::pdf::document::syntax::PdfSyntaxObject;
     use crate::pdf::points::PdfPoints;
     use crate::pdf::rect::PdfRect;
     use crate::utils::dates::date_time_to_pdf_string;
     use crate::utils::mem::create_byte_buffer;
     use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
     use chrono::{DateTime, Utc};
-    use std::os::raw::c_uint;
+    use std::os::raw::{c_float, c_int, c_uint, c_ulong};
 
     /// Internal crate-specific functionality common to all [PdfPageAnnotation] objects.
     pub trait PdfPageAnnotationPrivate<'a>: PdfPageAnnotationCommon {
@@ -32,7 +42,21 @@ pub(crate) mod internal {
         fn handle(&self) -> FPDF_ANNOTATION;
 
         /// Returns the [PdfiumLibraryBindings] used by this [PdfPageAnnotation].
-        fn bindings(&self) -> &dyn PdfiumLibraryBindings;
+        fn bindings(&self) -> &'a dyn PdfiumLibraryBindings;
+
+        /// Returns the internal `FPDF_DOCUMENT` handle for the document containing
+        /// this [PdfPageAnnotation].
+        #[inline]
+        fn document_handle(&self) -> FPDF_DOCUMENT {
+            *self.objects_impl().get_document_handle()
+        }
+
+        /// Returns the internal `FPDF_PAGE` handle for the page containing
+        /// this [PdfPageAnnotation].
+        #[inline]
+        fn page_handle(&self) -> FPDF_PAGE {
+            *self.objects_impl().get_page_handle()
+        }
 
         /// Returns the [PdfPageAnnotationType] of this [PdfPageAnnotation].
         fn get_annotation_type(&self) -> PdfPageAnnotationType {
@@ -42,59 +66,9 @@ pub(crate) mod internal {
 
         /// Returns the string value associated with the given key in the annotation dictionary
         /// of this [PdfPageAnnotation], if any.
+        #[inline]
         fn get_string_value(&self, key: &str) -> Option<String> {
-            if !self
-                .bindings()
-                .is_true(self.bindings().FPDFAnnot_HasKey(self.handle(), key))
-            {
-                // The key does not exist.
-
-                return None;
-            }
-
-            if self.bindings().FPDFAnnot_GetValueType(self.handle(), key) as u32
-                != FPDF_OBJECT_STRING
-            {
-                // The key exists, but the value associated with the key is not a string.
-
-                return None;
-            }
-
-            // Retrieving the string value from Pdfium is a two-step operation. First, we call
-            // FPDFAnot_GetStringValue() with a null buffer; this will retrieve the length of
-            // the value in bytes, assuming the key exists. If the length is zero, then there
-            // is no such key, or the key's value is not a string.
-
-            // If the length is non-zero, then we reserve a byte buffer of the given
-            // length and call FPDFAnot_GetStringValue() again with a pointer to the buffer;
-            // this will write the string value into the buffer.
-
-            let buffer_length = self.bindings().FPDFAnnot_GetStringValue(
-                self.handle(),
-                key,
-                std::ptr::null_mut(),
-                0,
-            );
-
-            if buffer_length <= 2 {
-                // A buffer length of 2 indicates that the string value for the given key is
-                // an empty UTF16-LE string, so there is no point in retrieving it.
-
-                return None;
-            }
-
-            let mut buffer = create_byte_buffer(buffer_length as usize);
-
-            let result = self.bindings().FPDFAnnot_GetStringValue(
-                self.handle(),
-                key,
-                buffer.as_mut_ptr() as *mut FPDF_WCHAR,
-                buffer_length,
-            );
-
-            assert_eq!(result, buffer_length);
-
-            Some(get_string_from_pdfium_utf16le_bytes(buffer).unwrap_or_default())
+            get_annotation_string_value(self.bindings(), self.handle(), key)
         }
 
         /// Sets the string value associated with the given key in the annotation dictionary
@@ -210,6 +184,218 @@ pub(crate) mod internal {
             ))
         }
 
+        /// Internal implementation of [PdfPageAnnotationCommon::flags()].
+        #[inline]
+        fn flags_impl(&self) -> PdfPageAnnotationFlags {
+            PdfPageAnnotationFlags::from_bits_truncate(
+                self.bindings().FPDFAnnot_GetFlags(self.handle()) as u32,
+            )
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::set_flags()].
+        fn set_flags_impl(&mut self, flags: PdfPageAnnotationFlags) -> Result<(), PdfiumError> {
+            if self.bindings().is_true(
+                self.bindings()
+                    .FPDFAnnot_SetFlags(self.handle(), flags.bits() as c_int),
+            ) {
+                self.set_string_value("M", &date_time_to_pdf_string(Utc::now()))
+            } else {
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ))
+            }
+        }
+
+        /// Returns `true` if the dictionary of this annotation contains the given key.
+        #[inline]
+        fn has_key_impl(&self, key: &str) -> bool {
+            self.bindings()
+                .is_true(self.bindings().FPDFAnnot_HasKey(self.handle(), key))
+        }
+
+        /// Replaces the entry with the given key in the dictionary of this annotation with the
+        /// given value, written in PDF object syntax, or removes the entry if the value is `None`.
+        ///
+        /// Pdfium does not provide a way to write arbitrary dictionary entries, so the change is
+        /// held by the [PdfPageAnnotationEditCache] and applied each time the containing
+        /// document is saved.
+        fn set_dictionary_entry_impl(
+            &mut self,
+            key: &str,
+            value: Option<Vec<u8>>,
+        ) -> Result<(), PdfiumError> {
+            PdfPageAnnotationEditCache::set_entry_for_annotation(
+                self.bindings(),
+                self.document_handle(),
+                self.page_handle(),
+                self.handle(),
+                key,
+                value,
+            )?;
+
+            self.set_string_value("M", &date_time_to_pdf_string(Utc::now()))
+        }
+
+        /// Replaces the entry with the given key in the dictionary of this annotation with an
+        /// indirect reference to the given annotation on the same page, or removes the entry
+        /// if the given annotation is `None`.
+        ///
+        /// As with [PdfPageAnnotationPrivate::set_dictionary_entry_impl()], the change is held
+        /// by the [PdfPageAnnotationEditCache] and applied each time the containing document
+        /// is saved.
+        fn set_linked_annotation_impl(
+            &mut self,
+            key: &str,
+            annotation: Option<&PdfPageAnnotation>,
+        ) -> Result<(), PdfiumError> {
+            PdfPageAnnotationEditCache::set_reference_for_annotation(
+                self.bindings(),
+                self.document_handle(),
+                self.page_handle(),
+                self.handle(),
+                key,
+                annotation.map(|annotation| (annotation.page_handle(), annotation.handle())),
+            )?;
+
+            self.set_string_value("M", &date_time_to_pdf_string(Utc::now()))
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::border()].
+        fn border_impl(&self) -> Result<PdfPageAnnotationBorder, PdfiumError> {
+            let mut border = if self.has_key_impl("Border") {
+                let mut horizontal_radius: c_float = 0.0;
+
+                let mut vertical_radius: c_float = 0.0;
+
+                let mut width: c_float = 0.0;
+
+                if self.bindings().is_true(self.bindings().FPDFAnnot_GetBorder(
+                    self.handle(),
+                    &mut horizontal_radius,
+                    &mut vertical_radius,
+                    &mut width,
+                )) {
+                    PdfPageAnnotationBorder::new(
+                        PdfPoints::new(horizontal_radius),
+                        PdfPoints::new(vertical_radius),
+                        PdfPoints::new(width),
+                    )
+                } else {
+                    return Err(PdfiumError::PdfiumLibraryInternalError(
+                        PdfiumInternalError::Unknown,
+                    ));
+                }
+            } else {
+                PdfPageAnnotationBorder::DEFAULT
+            };
+
+            // The width in the border style dictionary, if any, takes precedence.
+
+            if let Some(width) = get_annotation_dictionary_entry(self, "BS")?
+                .as_ref()
+                .and_then(|style| style.as_dictionary())
+                .and_then(|style| style.get(b"W"))
+                .and_then(|width| width.as_number())
+            {
+                border.width = PdfPoints::new(width);
+            }
+
+            Ok(border)
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::set_border()].
+        fn set_border_impl(&mut self, border: PdfPageAnnotationBorder) -> Result<(), PdfiumError> {
+            // Read any existing border style before Pdfium updates the /Border entry,
+            // since a dash pattern may be recorded there.
+
+            let style = match get_annotation_dictionary_entry(self, "BS")? {
+                Some(_) => Some(self.border_style_impl()?),
+                None => None,
+            };
+
+            if !self.bindings().is_true(self.bindings().FPDFAnnot_SetBorder(
+                self.handle(),
+                border.horizontal_radius.value,
+                border.vertical_radius.value,
+                border.width.value,
+            )) {
+                return Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ));
+            }
+
+            match style {
+                Some(style) => {
+                    // Keep the width in the border style dictionary in step with the new border,
+                    // otherwise the new width would have no effect.
+
+                    self.set_dictionary_entry_impl(
+                        "BS",
+                        Some(style.to_dictionary_bytes(border.width)?),
+                    )
+                }
+                None => self.set_string_value("M", &date_time_to_pdf_string(Utc::now())),
+            }
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::border_style()].
+        fn border_style_impl(&self) -> Result<PdfPageAnnotationBorderStyle, PdfiumError> {
+            if let Some(PdfSyntaxObject::Dictionary(style)) =
+                get_annotation_dictionary_entry(self, "BS")?
+            {
+                return Ok(PdfPageAnnotationBorderStyle::from_dictionary(&style));
+            }
+
+            // An annotation without a border style dictionary may record a dash pattern
+            // as the fourth element of its /Border entry.
+
+            Ok(match get_annotation_dictionary_entry(self, "Border")? {
+                Some(PdfSyntaxObject::Array(border)) => border
+                    .get(3)
+                    .and_then(PdfPageAnnotationBorderStyle::dash_pattern_from_array)
+                    .map(PdfPageAnnotationBorderStyle::Dashed)
+                    .unwrap_or(PdfPageAnnotationBorderStyle::Solid),
+                _ => PdfPageAnnotationBorderStyle::Solid,
+            })
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::set_border_style()].
+        fn set_border_style_impl(
+            &mut self,
+            style: PdfPageAnnotationBorderStyle,
+        ) -> Result<(), PdfiumError> {
+            let style = style.to_dictionary_bytes(self.border_impl()?.width)?;
+
+            self.set_dictionary_entry_impl("BS", Some(style))?;
+
+            // As with FPDFAnnot_SetBorder(), remove any appearance stream so that viewers
+            // draw the border using the new style.
+
+            self.clear_appearance_stream_impl(PdfAppearanceMode::Normal)
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::border_effect()].
+        fn border_effect_impl(&self) -> Result<PdfPageAnnotationBorderEffect, PdfiumError> {
+            Ok(match get_annotation_dictionary_entry(self, "BE")? {
+                Some(PdfSyntaxObject::Dictionary(effect)) => {
+                    PdfPageAnnotationBorderEffect::from_dictionary(&effect)
+                }
+                _ => PdfPageAnnotationBorderEffect::None,
+            })
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::set_border_effect()].
+        fn set_border_effect_impl(
+            &mut self,
+            effect: PdfPageAnnotationBorderEffect,
+        ) -> Result<(), PdfiumError> {
+            let effect = effect.to_dictionary_bytes()?;
+
+            self.set_dictionary_entry_impl("BE", effect)?;
+
+            self.clear_appearance_stream_impl(PdfAppearanceMode::Normal)
+        }
+
         /// Internal implementation of [PdfPageAnnotationCommon::contents()].
         #[inline]
         fn contents_impl(&self) -> Option<String> {
@@ -440,6 +626,138 @@ pub(crate) mod internal {
             }
         }
 
+        /// Internal implementation of [PdfPageAnnotationCommon::appearance_stream()].
+        fn appearance_stream_impl(&self, appearance_mode: PdfAppearanceMode) -> Option<String> {
+            // Retrieving the appearance stream from Pdfium is a two-step operation.
+            // First, we call FPDFAnnot_GetAP() with a null buffer; this will retrieve the length of
+            // the appearance stream in bytes. If the length is two or less, then the annotation
+            // has no appearance stream for the given mode; Pdfium returns an empty,
+            // null-terminated string in that case.
+
+            // If the length is larger, then we reserve a byte buffer of the given
+            // length and call FPDFAnnot_GetAP() again with a pointer to the buffer;
+            // this will write the appearance stream to the buffer in UTF16LE format.
+
+            let buffer_length = self.bindings().FPDFAnnot_GetAP(
+                self.handle(),
+                appearance_mode.as_pdfium(),
+                std::ptr::null_mut(),
+                0,
+            );
+
+            if buffer_length <= 2 {
+                // The appearance stream is not present.
+
+                return None;
+            }
+
+            let mut buffer = create_byte_buffer(buffer_length as usize);
+
+            let result = self.bindings().FPDFAnnot_GetAP(
+                self.handle(),
+                appearance_mode.as_pdfium(),
+                buffer.as_mut_ptr() as *mut FPDF_WCHAR,
+                buffer_length,
+            );
+
+            debug_assert_eq!(result, buffer_length);
+
+            get_string_from_pdfium_utf16le_bytes(buffer)
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::set_appearance_stream()].
+        fn set_appearance_stream_impl(
+            &mut self,
+            appearance_mode: PdfAppearanceMode,
+            content: &str,
+        ) -> Result<(), PdfiumError> {
+            if self.bindings().is_true(self.bindings().FPDFAnnot_SetAP_str(
+                self.handle(),
+                appearance_mode.as_pdfium(),
+                content,
+            )) {
+                self.set_string_value("M", &date_time_to_pdf_string(Utc::now()))
+            } else {
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ))
+            }
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::clear_appearance_stream()].
+        fn clear_appearance_stream_impl(
+            &mut self,
+            appearance_mode: PdfAppearanceMode,
+        ) -> Result<(), PdfiumError> {
+            // Passing a null pointer to FPDFAnnot_SetAP() clears the appearance stream.
+
+            if self.bindings().is_true(self.bindings().FPDFAnnot_SetAP(
+                self.handle(),
+                appearance_mode.as_pdfium(),
+                std::ptr::null(),
+            )) {
+                self.set_string_value("M", &date_time_to_pdf_string(Utc::now()))
+            } else {
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ))
+            }
+        }
+
+        /// Internal implementation of the `set_appearance_stream_from_objects()` function
+        /// shared by ink and stamp annotations.
+        fn set_appearance_stream_from_objects_impl(
+            &mut self,
+            objects: Vec<PdfPageObject<'a>>,
+        ) -> Result<(), PdfiumError> {
+            // Pdfium regenerates the normal appearance stream of the annotation from its
+            // page objects whenever an object is added or removed, so it is sufficient
+            // to replace the annotation's existing page objects with the given objects.
+
+            let annotation_objects = self.objects_mut_impl();
+
+            while !annotation_objects.is_empty() {
+                annotation_objects.remove_object_at_index(annotation_objects.len() - 1)?;
+            }
+
+            for object in objects {
+                annotation_objects.add_object(object)?;
+            }
+
+            Ok(())
+        }
+
+        /// Internal implementation of the `vertices()` function shared by
+        /// polygon and polyline annotations.
+        fn vertices_impl(&self) -> Vec<(PdfPoints, PdfPoints)> {
+            // Retrieving the vertices from Pdfium is a two-step operation. First, we call
+            // FPDFAnnot_GetVertices() with a null buffer; this will retrieve the number of vertices.
+            // Then, we allocate a buffer large enough to hold the vertices and call
+            // FPDFAnnot_GetVertices() again to fill it.
+
+            let count =
+                self.bindings()
+                    .FPDFAnnot_GetVertices(self.handle(), std::ptr::null_mut(), 0);
+
+            if count == 0 {
+                return Vec::new();
+            }
+
+            let mut buffer = vec![FS_POINTF { x: 0.0, y: 0.0 }; count as usize];
+
+            let count = self.bindings().FPDFAnnot_GetVertices(
+                self.handle(),
+                buffer.as_mut_ptr(),
+                buffer.len() as c_ulong,
+            );
+
+            buffer
+                .iter()
+                .take(count as usize)
+                .map(|point| (PdfPoints::new(point.x), PdfPoints::new(point.y)))
+                .collect()
+        }
+
         /// Internal implementation of [PdfPageAnnotationCommon::objects()].
         fn objects_impl(&self) -> &PdfPageAnnotationObjects;
 
@@ -460,4 +778,72 @@ pub(crate) mod internal {
         /// attachment points.
         fn attachment_points_mut_impl(&mut self) -> &mut PdfPageAnnotationAttachmentPoints<'a>;
     }
+
+    /// Returns the value of the entry with the given key in the dictionary of the given
+    /// annotation, including entries that Pdfium does not provide access to.
+    /// Any indirect references in the value are resolved.
+    pub(crate) fn get_annotation_dictionary_entry<'a>(
+        annotation: &(impl PdfPageAnnotationPrivate<'a> + ?Sized),
+        key: &str,
+    ) -> Result<Option<PdfSyntaxObject>, PdfiumError> {
+        PdfPageAnnotationEditCache::get_entry_for_annotation(
+            annotation.bindings(),
+            annotation.document_handle(),
+            annotation.page_handle(),
+            annotation.handle(),
+            key,
+        )
+    }
+
+    /// Returns the string value associated with the given key in the dictionary of the
+    /// given annotation, if any.
+    pub(crate) fn get_annotation_string_value(
+        bindings: &dyn PdfiumLibraryBindings,
+        annotation: FPDF_ANNOTATION,
+        key: &str,
+    ) -> Option<String> {
+        if !bindings.is_true(bindings.FPDFAnnot_HasKey(annotation, key)) {
+            // The key does not exist.
+
+            return None;
+        }
+
+        if bindings.FPDFAnnot_GetValueType(annotation, key) as u32 != FPDF_OBJECT_STRING {
+            // The key exists, but the value associated with the key is not a string.
+
+            return None;
+        }
+
+        // Retrieving the string value from Pdfium is a two-step operation. First, we call
+        // FPDFAnot_GetStringValue() with a null buffer; this will retrieve the length of
+        // the value in bytes, assuming the key exists. If the length is zero, then there
+        // is no such key, or the key's value is not a string.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDFAnot_GetStringValue() again with a pointer to the buffer;
+        // this will write the string value into the buffer.
+
+        let buffer_length =
+            bindings.FPDFAnnot_GetStringValue(annotation, key, std::ptr::null_mut(), 0);
+
+        if buffer_length <= 2 {
+            // A buffer length of 2 indicates that the string value for the given key is
+            // an empty UTF16-LE string, so there is no point in retrieving it.
+
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = bindings.FPDFAnnot_GetStringValue(
+            annotation,
+            key,
+            buffer.as_mut_ptr() as *mut FPDF_WCHAR,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        Some(get_string_from_pdfium_utf16le_bytes(buffer).unwrap_or_default())
+    }
 }
@@ -3,9 +3,14 @@
 
 use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
 use crate::pdf::document::page::annotation::attachment_points::PdfPageAnnotationAttachmentPoints;
 use crate::pdf::document::page::annotation::objects::PdfPageAnnotationObjects;
 use crate::pdf::document::page::annotation::private::internal::PdfPageAnnotationPrivate;
+use crate::pdf::document::page::object::PdfPageObject;
+
+#[cfg(doc)]
+use crate::pdf::appearance_mode::PdfAppearanceMode;
 
 /// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::Stamp`.
 pub struct PdfPageStampAnnotation<'a> {
@@ -43,6 +48,20 @@ impl<'a> PdfPageStampAnnotation<'a> {
     pub fn objects_mut(&mut self) -> &mut PdfPageAnnotationObjects<'a> {
         &mut self.objects
     }
+
+    /// Replaces the page objects in this [PdfPageStampAnnotation] with the given objects.
+    /// Pdfium generates the normal appearance stream of the annotation from its page objects,
+    /// so this sets the appearance of the annotation in [PdfAppearanceMode::Normal].
+    ///
+    /// To set the appearance of the annotation from raw content stream text instead, use the
+    /// `PdfPageAnnotationCommon::set_appearance_stream()` function.
+    #[inline]
+    pub fn set_appearance_stream_from_objects(
+        &mut self,
+        objects: Vec<PdfPageObject<'a>>,
+    ) -> Result<(), PdfiumError> {
+        self.set_appearance_stream_from_objects_impl(objects)
+    }
 }
 
 impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageStampAnnotation<'a> {
@@ -52,7 +71,7 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageStampAnnotation<'a> {
     }
 
     #[inline]
-    fn bindings(&self) -> &dyn PdfiumLibraryBindings {
+    fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
         self.bindings
     }
 
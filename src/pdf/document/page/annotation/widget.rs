@@ -41,7 +41,7 @@ impl<'a> PdfPageWidgetAnnotation<'a> {
                 bindings,
             ),
             form_field: form_handle.and_then(|form_handle| {
-                PdfFormField::from_pdfium(form_handle, annotation_handle, bindings)
+                PdfFormField::from_pdfium(form_handle, page_handle, annotation_handle, bindings)
             }),
             bindings,
         }
@@ -69,7 +69,7 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageWidgetAnnotation<'a> {
     }
 
     #[inline]
-    fn bindings(&self) -> &dyn PdfiumLibraryBindings {
+    fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
         self.bindings
     }
 
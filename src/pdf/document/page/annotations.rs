@@ -5,6 +5,8 @@ use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_FORMHANDLE, FPDF_PAGE}
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::color::PdfColor;
+use crate::pdf::document::page::annotation::border::PdfPageAnnotationBorder;
+use crate::pdf::document::page::annotation::circle::PdfPageCircleAnnotation;
 use crate::pdf::document::page::annotation::free_text::PdfPageFreeTextAnnotation;
 use crate::pdf::document::page::annotation::highlight::PdfPageHighlightAnnotation;
 use crate::pdf::document::page::annotation::ink::PdfPageInkAnnotation;
@@ -22,13 +24,30 @@ use crate::pdf::document::page::annotation::{
 };
 use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectCommon};
 use crate::pdf::quad_points::PdfQuadPoints;
+use crate::pdf::rect::PdfRect;
 use chrono::prelude::*;
 use std::ops::Range;
 use std::os::raw::c_int;
 
+#[cfg(any(
+    feature = "pdfium_6337",
+    feature = "pdfium_6406",
+    feature = "pdfium_6490",
+    feature = "pdfium_6555",
+    feature = "pdfium_6569",
+    feature = "pdfium_6611",
+    feature = "pdfium_6666",
+    feature = "pdfium_future"
+))]
+use crate::pdf::document::page::annotation::file_attachment::PdfPageFileAttachmentAnnotation;
+
 pub type PdfPageAnnotationIndex = usize;
 
 /// The annotations that have been added to a single `PdfPage`.
+///
+/// Annotations of any type supported by Pdfium can be created in this collection, with the
+/// exception of caret, line, polygon, and polyline annotations; Pdfium can edit and render
+/// existing annotations of these types, but cannot create them.
 pub struct PdfPageAnnotations<'a> {
     document_handle: FPDF_DOCUMENT,
     page_handle: FPDF_PAGE,
@@ -148,6 +167,99 @@ impl<'a> PdfPageAnnotations<'a> {
         PdfPageAnnotationsIterator::new(self)
     }
 
+    /// Returns the index of the given [PdfPageAnnotation] in this [PdfPageAnnotations] collection,
+    /// or `None` if the annotation is not on the page containing this collection.
+    pub fn index_of(&self, annotation: &PdfPageAnnotation<'_>) -> Option<PdfPageAnnotationIndex> {
+        let index = self
+            .bindings()
+            .FPDFPage_GetAnnotIndex(self.page_handle, annotation.handle());
+
+        if index < 0 {
+            // Pdfium uses a -1 value to signal that the annotation was not found.
+
+            None
+        } else {
+            Some(index as PdfPageAnnotationIndex)
+        }
+    }
+
+    /// Groups the annotations in this [PdfPageAnnotations] collection into comment threads,
+    /// following the `/IRT` ("in reply to") links between annotations in the same way as
+    /// Adobe Acrobat's comments list.
+    ///
+    /// Each thread starts with an annotation that is not a reply to another annotation on this
+    /// page, followed by every direct and indirect reply to it, in page order. Pop-up annotations
+    /// are not included in any thread; they can be retrieved from the annotation they belong to
+    /// using the `PdfPageAnnotation::popup()` function. Threads are returned in page order.
+    pub fn threads(&self) -> Vec<PdfPageAnnotationThread> {
+        // The index of the annotation each annotation directly replies to, if any.
+
+        let mut parents: Vec<Option<PdfPageAnnotationIndex>> = Vec::with_capacity(self.len());
+
+        let mut is_popup = Vec::with_capacity(self.len());
+
+        for annotation in self.iter() {
+            is_popup.push(annotation.annotation_type() == PdfPageAnnotationType::Popup);
+            parents.push(
+                annotation
+                    .in_reply_to()
+                    .and_then(|parent| self.index_of(&parent)),
+            );
+        }
+
+        let root_of = |index: PdfPageAnnotationIndex| -> PdfPageAnnotationIndex {
+            // Follow the chain of replies back to its start. Malformed documents may contain
+            // circular replies; in that case, the lowest index in the cycle is used as the root,
+            // so that every annotation in the cycle is placed in the same thread.
+
+            let mut path = vec![index];
+
+            while let Some(parent) = parents[*path.last().unwrap()] {
+                if is_popup[parent] {
+                    break;
+                }
+
+                if let Some(position) = path.iter().position(|visited| *visited == parent) {
+                    return *path[position..].iter().min().unwrap();
+                }
+
+                path.push(parent);
+            }
+
+            *path.last().unwrap()
+        };
+
+        let mut threads: Vec<PdfPageAnnotationThread> = Vec::new();
+
+        for (index, is_popup) in is_popup.iter().enumerate() {
+            if *is_popup {
+                continue;
+            }
+
+            let root = root_of(index);
+
+            match threads.iter_mut().find(|thread| thread.root == root) {
+                Some(thread) => {
+                    if index != root {
+                        thread.replies.push(index);
+                    }
+                }
+                None => threads.push(PdfPageAnnotationThread {
+                    root,
+                    replies: if index == root {
+                        Vec::new()
+                    } else {
+                        vec![index]
+                    },
+                }),
+            }
+        }
+
+        threads.sort_by_key(|thread| thread.root);
+
+        threads
+    }
+
     // Regenerates the content of the containing [PdfPage] if necessary after this
     // [PdfPageAnnotations] collection has been mutated.
     fn regenerate_content(&self) -> Result<(), PdfiumError> {
@@ -167,6 +279,32 @@ impl<'a> PdfPageAnnotations<'a> {
         }
     }
 
+    /// Applies the given bounds, stroke color, border, and interior fill color to the given
+    /// newly created shape annotation.
+    fn configure_shape_annotation<T: PdfPageAnnotationCommon>(
+        annotation: &mut T,
+        rect: PdfRect,
+        stroke_color: Option<PdfColor>,
+        border: PdfPageAnnotationBorder,
+        fill_color: Option<PdfColor>,
+    ) -> Result<(), PdfiumError> {
+        annotation.set_bounds(rect)?;
+
+        match stroke_color {
+            Some(stroke_color) => {
+                annotation.set_stroke_color(stroke_color)?;
+                annotation.set_border(border)?;
+            }
+            None => annotation.set_border(PdfPageAnnotationBorder::NONE)?,
+        }
+
+        if let Some(fill_color) = fill_color {
+            annotation.set_fill_color(fill_color)?;
+        }
+
+        Ok(())
+    }
+
     /// Creates a new annotation of the given [PdfPageAnnotationType] by passing the result of calling
     /// `FPDFPage_CreateAnnot()` to an annotation constructor function.
     ///
@@ -176,7 +314,7 @@ impl<'a> PdfPageAnnotations<'a> {
     pub(crate) fn create_annotation<T: PdfPageAnnotationCommon>(
         &mut self,
         annotation_type: PdfPageAnnotationType,
-        constructor: fn(
+        constructor: impl FnOnce(
             FPDF_DOCUMENT,
             FPDF_PAGE,
             FPDF_ANNOTATION,
@@ -206,6 +344,76 @@ impl<'a> PdfPageAnnotations<'a> {
         }
     }
 
+    /// Creates a new [PdfPageCircleAnnotation] annotation in this [PdfPageAnnotations] collection,
+    /// returning the newly created annotation.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    #[inline]
+    pub fn create_circle_annotation(&mut self) -> Result<PdfPageCircleAnnotation<'a>, PdfiumError> {
+        self.create_annotation(
+            PdfPageAnnotationType::Circle,
+            PdfPageCircleAnnotation::from_pdfium,
+        )
+    }
+
+    /// Creates a new [PdfPageCircleAnnotation] annotation in this [PdfPageAnnotations] collection,
+    /// drawing a circle or ellipse that fills the given rectangle with the given stroke color,
+    /// border, and interior fill color, and returns the newly created annotation.
+    ///
+    /// The circle will only be stroked if a stroke color is provided and the given border is
+    /// visible. The circle will only be filled if a fill color is provided.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn create_circle_annotation_in_rect(
+        &mut self,
+        rect: PdfRect,
+        stroke_color: Option<PdfColor>,
+        border: PdfPageAnnotationBorder,
+        fill_color: Option<PdfColor>,
+    ) -> Result<PdfPageCircleAnnotation<'a>, PdfiumError> {
+        let mut annotation = self.create_circle_annotation()?;
+
+        Self::configure_shape_annotation(&mut annotation, rect, stroke_color, border, fill_color)?;
+
+        Ok(annotation)
+    }
+
+    /// Creates a new [PdfPageFileAttachmentAnnotation] in this [PdfPageAnnotations] collection,
+    /// embedding the given byte data in the annotation as a file with the given name.
+    /// Returns the newly created annotation.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    #[cfg(any(
+        feature = "pdfium_6337",
+        feature = "pdfium_6406",
+        feature = "pdfium_6490",
+        feature = "pdfium_6555",
+        feature = "pdfium_6569",
+        feature = "pdfium_6611",
+        feature = "pdfium_6666",
+        feature = "pdfium_future"
+    ))]
+    pub fn create_file_attachment_annotation(
+        &mut self,
+        name: &str,
+        bytes: &[u8],
+    ) -> Result<PdfPageFileAttachmentAnnotation<'a>, PdfiumError> {
+        let mut annotation = self.create_annotation(
+            PdfPageAnnotationType::FileAttachment,
+            PdfPageFileAttachmentAnnotation::from_pdfium,
+        )?;
+
+        annotation.set_attachment_from_bytes(name, bytes)?;
+
+        Ok(annotation)
+    }
+
     /// Creates a new [PdfPageFreeTextAnnotation] containing the given text in this
     /// [PdfPageAnnotations] collection, returning the newly created annotation.
     ///
@@ -217,9 +425,19 @@ impl<'a> PdfPageAnnotations<'a> {
         &mut self,
         text: &str,
     ) -> Result<PdfPageFreeTextAnnotation<'a>, PdfiumError> {
+        let form_handle = self.form_handle;
+
         let mut annotation = self.create_annotation(
             PdfPageAnnotationType::FreeText,
-            PdfPageFreeTextAnnotation::from_pdfium,
+            |document_handle, page_handle, annotation_handle, bindings| {
+                PdfPageFreeTextAnnotation::from_pdfium(
+                    document_handle,
+                    page_handle,
+                    annotation_handle,
+                    form_handle,
+                    bindings,
+                )
+            },
         )?;
 
         annotation.set_contents(text)?;
@@ -305,6 +523,30 @@ impl<'a> PdfPageAnnotations<'a> {
         )
     }
 
+    /// Creates a new [PdfPageSquareAnnotation] annotation in this [PdfPageAnnotations] collection,
+    /// drawing a rectangle that fills the given rectangle with the given stroke color,
+    /// border, and interior fill color, and returns the newly created annotation.
+    ///
+    /// The rectangle will only be stroked if a stroke color is provided and the given border is
+    /// visible. The rectangle will only be filled if a fill color is provided.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn create_square_annotation_in_rect(
+        &mut self,
+        rect: PdfRect,
+        stroke_color: Option<PdfColor>,
+        border: PdfPageAnnotationBorder,
+        fill_color: Option<PdfColor>,
+    ) -> Result<PdfPageSquareAnnotation<'a>, PdfiumError> {
+        let mut annotation = self.create_square_annotation()?;
+
+        Self::configure_shape_annotation(&mut annotation, rect, stroke_color, border, fill_color)?;
+
+        Ok(annotation)
+    }
+
     /// Creates a new [PdfPageSquigglyAnnotation] annotation in this [PdfPageAnnotations] collection,
     /// returning the newly created annotation.
     ///
@@ -589,6 +831,35 @@ impl<'a> PdfPageAnnotations<'a> {
     }
 }
 
+/// A single comment thread on a `PdfPage`, as returned by [PdfPageAnnotations::threads()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdfPageAnnotationThread {
+    root: PdfPageAnnotationIndex,
+    replies: Vec<PdfPageAnnotationIndex>,
+}
+
+impl PdfPageAnnotationThread {
+    /// Returns the index of the annotation that starts this [PdfPageAnnotationThread].
+    #[inline]
+    pub fn root(&self) -> PdfPageAnnotationIndex {
+        self.root
+    }
+
+    /// Returns the indices of all the direct and indirect replies to the annotation that
+    /// starts this [PdfPageAnnotationThread], in page order.
+    #[inline]
+    pub fn replies(&self) -> &[PdfPageAnnotationIndex] {
+        self.replies.as_slice()
+    }
+
+    /// Returns `true` if any annotations reply to the annotation that starts this
+    /// [PdfPageAnnotationThread].
+    #[inline]
+    pub fn has_replies(&self) -> bool {
+        !self.replies.is_empty()
+    }
+}
+
 /// An iterator over all the [PdfPageAnnotation] objects in a [PdfPageAnnotations] collection.
 pub struct PdfPageAnnotationsIterator<'a> {
     annotations: &'a PdfPageAnnotations<'a>,
@@ -616,3 +887,262 @@ impl<'a> Iterator for PdfPageAnnotationsIterator<'a> {
         next.ok()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_annotation_links() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/annotation-threads-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let annotations = page.annotations();
+
+        let comment = annotations.get(0)?;
+
+        let popup = comment.popup().unwrap();
+
+        assert_eq!(popup.annotation_type(), PdfPageAnnotationType::Popup);
+        assert_eq!(annotations.index_of(&popup), Some(1));
+        assert_eq!(
+            popup
+                .parent()
+                .and_then(|parent| annotations.index_of(&parent)),
+            Some(0)
+        );
+        assert!(!comment.is_reply());
+        assert!(comment.in_reply_to().is_none());
+
+        let nested_reply = annotations.get(3)?;
+
+        assert!(nested_reply.is_reply());
+        assert_eq!(nested_reply.contents().as_deref(), Some("Nested reply"));
+        assert_eq!(
+            nested_reply
+                .in_reply_to()
+                .and_then(|parent| annotations.index_of(&parent)),
+            Some(2)
+        );
+        assert!(nested_reply.popup().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_threads() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/annotation-threads-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let threads = page.annotations().threads();
+
+        let summary = threads
+            .iter()
+            .map(|thread| (thread.root(), thread.replies().to_vec()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            summary,
+            vec![
+                // A comment, a reply to it, and a reply to that reply; the comment's
+                // pop-up annotation at index 1 is excluded.
+                (0, vec![2, 3]),
+                // A standalone comment.
+                (4, vec![]),
+                // Two annotations that reply to each other.
+                (5, vec![6]),
+                // A reply to a pop-up annotation, which starts a thread of its own.
+                (7, vec![]),
+            ]
+        );
+
+        assert!(threads[0].has_replies());
+        assert!(!threads[1].has_replies());
+
+        assert!(threads
+            .iter()
+            .all(|thread| thread.root() != 1 && !thread.replies().contains(&1)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_annotation_links() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let check = |annotations: &PdfPageAnnotations| -> Result<(), PdfiumError> {
+            let comment = annotations.get(0)?;
+
+            let popup = annotations.get(1)?;
+
+            let reply = annotations.get(2)?;
+
+            assert_eq!(
+                comment
+                    .popup()
+                    .and_then(|popup| annotations.index_of(&popup)),
+                Some(1)
+            );
+            assert_eq!(
+                popup
+                    .parent()
+                    .and_then(|parent| annotations.index_of(&parent)),
+                Some(0)
+            );
+            assert!(reply.is_reply());
+            assert_eq!(
+                reply
+                    .in_reply_to()
+                    .and_then(|parent| annotations.index_of(&parent)),
+                Some(0)
+            );
+            assert!(!comment.is_reply());
+            assert!(reply.popup().is_none());
+
+            let threads = annotations.threads();
+
+            assert_eq!(threads.len(), 1);
+            assert_eq!(threads[0].root(), 0);
+            assert_eq!(threads[0].replies(), &[2]);
+
+            Ok(())
+        };
+
+        {
+            let mut page = document
+                .pages_mut()
+                .create_page_at_start(PdfPagePaperSize::a4())?;
+
+            let annotations = page.annotations_mut();
+
+            annotations.create_text_annotation("Comment")?;
+            annotations.create_popup_annotation()?;
+            annotations.create_text_annotation("Reply")?;
+
+            let mut comment = annotations.get(0)?;
+
+            let mut popup = annotations.get(1)?;
+
+            let mut reply = annotations.get(2)?;
+
+            comment.set_popup(Some(&popup))?;
+            popup.set_parent(Some(&comment))?;
+            reply.set_in_reply_to(Some(&popup))?;
+
+            // Links are replaced, not added to, by later changes.
+
+            reply.set_in_reply_to(Some(&comment))?;
+            reply.set_popup(Some(&popup))?;
+            reply.set_popup(None)?;
+
+            check(annotations)?;
+        }
+
+        let bytes = document.save_to_bytes()?;
+
+        drop(document);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        check(document.pages().first()?.annotations())?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_markup_annotations() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let check = |document: &PdfDocument, is_edited: bool| -> Result<(), PdfiumError> {
+            let page = document.pages().first()?;
+
+            let annotations = page.annotations();
+
+            assert_eq!(annotations.len(), 4);
+
+            let line = annotations.get(0)?;
+
+            let line = line.as_line_annotation().unwrap();
+
+            assert_eq!(
+                line.start()?,
+                (PdfPoints::new(100.0), PdfPoints::new(100.0))
+            );
+            assert_eq!(line.end()?, (PdfPoints::new(300.0), PdfPoints::new(200.0)));
+            assert_eq!(
+                line.contents().as_deref(),
+                Some(if is_edited { "Edited" } else { "Measurement" })
+            );
+
+            let polygon = annotations.get(1)?;
+
+            assert_eq!(
+                polygon.as_polygon_annotation().unwrap().vertices(),
+                vec![
+                    (PdfPoints::new(100.0), PdfPoints::new(300.0)),
+                    (PdfPoints::new(200.0), PdfPoints::new(300.0)),
+                    (PdfPoints::new(150.0), PdfPoints::new(400.0)),
+                ]
+            );
+
+            let polyline = annotations.get(2)?;
+
+            assert_eq!(
+                polyline.as_polyline_annotation().unwrap().vertices().len(),
+                4
+            );
+            assert_eq!(
+                polyline.as_polyline_annotation().unwrap().vertices()[3],
+                (PdfPoints::new(450.0), PdfPoints::new(350.0))
+            );
+
+            let caret = annotations.get(3)?;
+
+            assert_eq!(caret.annotation_type(), PdfPageAnnotationType::Caret);
+            assert!(caret.as_caret_annotation().is_some());
+            assert_eq!(caret.contents().as_deref(), Some("Insert here"));
+
+            if is_edited {
+                let color = polygon.stroke_color()?;
+
+                assert_eq!((color.red(), color.green(), color.blue()), (255, 0, 255));
+            }
+
+            Ok(())
+        };
+
+        let document = pdfium.load_pdf_from_file("./test/markup-annotations-test.pdf", None)?;
+
+        check(&document, false)?;
+
+        {
+            let page = document.pages().first()?;
+
+            let mut line = page.annotations().get(0)?;
+
+            line.as_line_annotation_mut()
+                .unwrap()
+                .set_contents("Edited")?;
+
+            let mut polygon = page.annotations().get(1)?;
+
+            polygon
+                .as_polygon_annotation_mut()
+                .unwrap()
+                .set_stroke_color(PdfColor::new(255, 0, 255, 255))?;
+        }
+
+        let document = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        check(&document, true)
+    }
+}
@@ -14,9 +14,11 @@ pub mod text;
 pub mod unknown;
 
 use crate::bindgen::{
-    FPDF_ANNOTATION, FPDF_FORMFIELD_CHECKBOX, FPDF_FORMFIELD_COMBOBOX, FPDF_FORMFIELD_LISTBOX,
-    FPDF_FORMFIELD_PUSHBUTTON, FPDF_FORMFIELD_RADIOBUTTON, FPDF_FORMFIELD_SIGNATURE,
-    FPDF_FORMFIELD_TEXTFIELD, FPDF_FORMFIELD_UNKNOWN, FPDF_FORMHANDLE,
+    FPDF_ANNOTATION, FPDF_ANNOT_AACTION_CALCULATE, FPDF_ANNOT_AACTION_FORMAT,
+    FPDF_ANNOT_AACTION_KEY_STROKE, FPDF_ANNOT_AACTION_VALIDATE, FPDF_FORMFIELD_CHECKBOX,
+    FPDF_FORMFIELD_COMBOBOX, FPDF_FORMFIELD_LISTBOX, FPDF_FORMFIELD_PUSHBUTTON,
+    FPDF_FORMFIELD_RADIOBUTTON, FPDF_FORMFIELD_SIGNATURE, FPDF_FORMFIELD_TEXTFIELD,
+    FPDF_FORMFIELD_UNKNOWN, FPDF_FORMHANDLE, FPDF_PAGE,
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
@@ -84,6 +86,36 @@ impl PdfFormFieldType {
     }
 }
 
+/// A form field event that can trigger a JavaScript action stored in the additional-actions
+/// dictionary of a single interactive form field in a [PdfForm].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PdfFormFieldAdditionalActionEvent {
+    /// The user types a keystroke into a text field or combo box, or modifies
+    /// the selection in a list box.
+    KeyStroke,
+
+    /// The field's value is about to be formatted for display.
+    Format,
+
+    /// The field's value has changed and can be checked for validity.
+    Validate,
+
+    /// The value of another field has changed and this field's value should be recalculated.
+    Calculate,
+}
+
+impl PdfFormFieldAdditionalActionEvent {
+    #[inline]
+    pub(crate) fn as_pdfium(&self) -> u32 {
+        match self {
+            PdfFormFieldAdditionalActionEvent::KeyStroke => FPDF_ANNOT_AACTION_KEY_STROKE,
+            PdfFormFieldAdditionalActionEvent::Format => FPDF_ANNOT_AACTION_FORMAT,
+            PdfFormFieldAdditionalActionEvent::Validate => FPDF_ANNOT_AACTION_VALIDATE,
+            PdfFormFieldAdditionalActionEvent::Calculate => FPDF_ANNOT_AACTION_CALCULATE,
+        }
+    }
+}
+
 /// A single interactive form field in a [PdfForm].
 pub enum PdfFormField<'a> {
     PushButton(PdfFormPushButtonField<'a>),
@@ -99,6 +131,7 @@ pub enum PdfFormField<'a> {
 impl<'a> PdfFormField<'a> {
     pub(crate) fn from_pdfium(
         form_handle: FPDF_FORMHANDLE,
+        page_handle: FPDF_PAGE,
         annotation_handle: FPDF_ANNOTATION,
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Option<Self> {
@@ -121,11 +154,17 @@ impl<'a> PdfFormField<'a> {
             PdfFormFieldType::RadioButton => PdfFormField::RadioButton(
                 PdfFormRadioButtonField::from_pdfium(form_handle, annotation_handle, bindings),
             ),
-            PdfFormFieldType::ComboBox => PdfFormField::ComboBox(
-                PdfFormComboBoxField::from_pdfium(form_handle, annotation_handle, bindings),
-            ),
+            PdfFormFieldType::ComboBox => {
+                PdfFormField::ComboBox(PdfFormComboBoxField::from_pdfium(
+                    form_handle,
+                    page_handle,
+                    annotation_handle,
+                    bindings,
+                ))
+            }
             PdfFormFieldType::ListBox => PdfFormField::ListBox(PdfFormListBoxField::from_pdfium(
                 form_handle,
+                page_handle,
                 annotation_handle,
                 bindings,
             )),
@@ -295,6 +334,27 @@ pub trait PdfFormFieldCommon {
     /// Returns the value currently set for the given appearance mode for this [PdfFormField],
     /// if any.
     fn appearance_mode_value(&self, appearance_mode: PdfAppearanceMode) -> Option<String>;
+
+    /// Returns the JavaScript source code that is executed when the given event occurs
+    /// for this [PdfFormField], if any.
+    fn javascript(&self, event: PdfFormFieldAdditionalActionEvent) -> Option<String>;
+
+    /// Returns the JavaScript actions triggered by each of the form field events listed in
+    /// [PdfFormFieldAdditionalActionEvent], paired with the event that triggers each one.
+    ///
+    /// Only the keystroke, format, validate, and calculate triggers are inspected, since these
+    /// are the only triggers Pdfium provides access to. An action performed when the widget
+    /// is activated, and the additional actions triggered by mouse, focus, and page visibility
+    /// events, are not returned; an empty result therefore does not mean that this
+    /// [PdfFormField] carries no JavaScript.
+    fn field_event_javascripts(&self) -> Vec<(PdfFormFieldAdditionalActionEvent, String)>;
+
+    /// Returns `true` if a JavaScript action is triggered by any of the form field events
+    /// listed in [PdfFormFieldAdditionalActionEvent].
+    ///
+    /// As for [PdfFormFieldCommon::field_event_javascripts()], JavaScript actions triggered
+    /// by activating the widget or by mouse, focus, and page visibility events are not considered.
+    fn has_field_event_javascript(&self) -> bool;
 }
 
 // Blanket implementation for all PdfFormFieldCommon types.
@@ -317,6 +377,28 @@ where
     fn appearance_mode_value(&self, appearance_mode: PdfAppearanceMode) -> Option<String> {
         self.appearance_mode_value_impl(appearance_mode)
     }
+
+    #[inline]
+    fn javascript(&self, event: PdfFormFieldAdditionalActionEvent) -> Option<String> {
+        self.javascript_impl(event)
+    }
+
+    fn field_event_javascripts(&self) -> Vec<(PdfFormFieldAdditionalActionEvent, String)> {
+        [
+            PdfFormFieldAdditionalActionEvent::KeyStroke,
+            PdfFormFieldAdditionalActionEvent::Format,
+            PdfFormFieldAdditionalActionEvent::Validate,
+            PdfFormFieldAdditionalActionEvent::Calculate,
+        ]
+        .iter()
+        .filter_map(|event| self.javascript_impl(*event).map(|script| (*event, script)))
+        .collect()
+    }
+
+    #[inline]
+    fn has_field_event_javascript(&self) -> bool {
+        !self.field_event_javascripts().is_empty()
+    }
 }
 
 impl<'a> PdfFormFieldPrivate<'a> for PdfFormField<'a> {
@@ -67,6 +67,13 @@ impl<'a> PdfFormCheckboxField<'a> {
         self.value_impl()
     }
 
+    /// Returns the export value of this [PdfFormCheckboxField], if any. This is the value
+    /// taken by the control group containing this [PdfFormCheckboxField] when its checkbox is checked.
+    #[inline]
+    pub fn export_value(&self) -> Option<String> {
+        self.export_value_impl()
+    }
+
     /// Returns `true` if this [PdfFormCheckboxField] object has its checkbox checked.
     #[inline]
     pub fn is_checked(&self) -> Result<bool, PdfiumError> {
@@ -1,9 +1,10 @@
 //! Defines the [PdfFormComboBoxField] struct, exposing functionality related to a single
 //! form field of type `PdfFormFieldType::ComboBox`.
 
-use crate::bindgen::{FPDF_ANNOTATION, FPDF_FORMHANDLE};
+use crate::bindgen::{FPDF_ANNOTATION, FPDF_FORMFLAG_CHOICE_EDIT, FPDF_FORMHANDLE, FPDF_PAGE};
 use crate::bindings::PdfiumLibraryBindings;
-use crate::pdf::document::page::field::options::PdfFormFieldOptions;
+use crate::error::PdfiumError;
+use crate::pdf::document::page::field::options::{PdfFormFieldOptionIndex, PdfFormFieldOptions};
 use crate::pdf::document::page::field::private::internal::PdfFormFieldPrivate;
 
 /// A single `PdfFormField` of type `PdfFormFieldType::ComboBox`. The form field object defines
@@ -16,6 +17,7 @@ use crate::pdf::document::page::field::private::internal::PdfFormFieldPrivate;
 /// `PdfForm::field_values()` function.
 pub struct PdfFormComboBoxField<'a> {
     form_handle: FPDF_FORMHANDLE,
+    page_handle: FPDF_PAGE,
     annotation_handle: FPDF_ANNOTATION,
     options: PdfFormFieldOptions<'a>,
     bindings: &'a dyn PdfiumLibraryBindings,
@@ -25,11 +27,13 @@ impl<'a> PdfFormComboBoxField<'a> {
     #[inline]
     pub(crate) fn from_pdfium(
         form_handle: FPDF_FORMHANDLE,
+        page_handle: FPDF_PAGE,
         annotation_handle: FPDF_ANNOTATION,
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Self {
         PdfFormComboBoxField {
             form_handle,
+            page_handle,
             annotation_handle,
             options: PdfFormFieldOptions::from_pdfium(form_handle, annotation_handle, bindings),
             bindings,
@@ -55,6 +59,44 @@ impl<'a> PdfFormComboBoxField<'a> {
             .find(|option| option.is_set())
             .and_then(|option| option.label().cloned())
     }
+
+    /// Returns `true` if this [PdfFormComboBoxField] allows the user to type a custom value
+    /// in addition to selecting a value from its list of options.
+    #[inline]
+    pub fn is_editable(&self) -> bool {
+        self.bindings
+            .FPDFAnnot_GetFormFieldFlags(self.form_handle, self.annotation_handle) as u32
+            & FPDF_FORMFLAG_CHOICE_EDIT
+            != 0
+    }
+
+    /// Sets the value of this [PdfFormComboBoxField] object by selecting the option
+    /// with the given displayed label.
+    ///
+    /// If no option has the given label and this [PdfFormComboBoxField] is editable,
+    /// the given value is set as a custom value; otherwise,
+    /// [PdfiumError::FormFieldOptionNotFound] is returned.
+    pub fn set_value(&mut self, value: &str) -> Result<(), PdfiumError> {
+        match self
+            .options()
+            .iter()
+            .find(|option| option.label().map(|label| label.as_str()) == Some(value))
+        {
+            Some(option) => self.select_option(option.index()),
+            None if self.is_editable() => self.set_value_impl(value),
+            None => Err(PdfiumError::FormFieldOptionNotFound),
+        }
+    }
+
+    /// Selects the option at the given index in this [PdfFormComboBoxField] object,
+    /// replacing any previously selected option.
+    pub fn select_option(&mut self, index: PdfFormFieldOptionIndex) -> Result<(), PdfiumError> {
+        if index >= self.options().len() {
+            return Err(PdfiumError::FormFieldOptionIndexOutOfBounds);
+        }
+
+        self.set_option_selected_impl(self.page_handle, index, true)
+    }
 }
 
 impl<'a> PdfFormFieldPrivate<'a> for PdfFormComboBoxField<'a> {
@@ -73,3 +115,64 @@ impl<'a> PdfFormFieldPrivate<'a> for PdfFormComboBoxField<'a> {
         self.bindings
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_combo_box_set_value() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/form-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let mut annotation = page
+            .annotations()
+            .iter()
+            .find(|annotation| {
+                annotation
+                    .as_form_field()
+                    .and_then(|field| field.as_combo_box_field())
+                    .is_some()
+            })
+            .unwrap();
+
+        let field = match annotation.as_form_field_mut() {
+            Some(PdfFormField::ComboBox(field)) => field,
+            _ => unreachable!(),
+        };
+
+        assert!(field.options().len() > 1);
+
+        let first = field.options().get(0)?.label().cloned().unwrap();
+
+        let second = field.options().get(1)?.label().cloned().unwrap();
+
+        field.set_value(&second)?;
+
+        assert_eq!(field.value(), Some(second));
+
+        field.select_option(0)?;
+
+        assert_eq!(field.value(), Some(first));
+        assert!(field.options().get(0)?.is_set());
+        assert!(!field.options().get(1)?.is_set());
+
+        assert!(matches!(
+            field.select_option(field.options().len()),
+            Err(PdfiumError::FormFieldOptionIndexOutOfBounds)
+        ));
+
+        if !field.is_editable() {
+            assert!(matches!(
+                field.set_value("not an option in this combo box"),
+                Err(PdfiumError::FormFieldOptionNotFound)
+            ));
+        }
+
+        Ok(())
+    }
+}
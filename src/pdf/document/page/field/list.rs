@@ -1,9 +1,12 @@
 //! Defines the [PdfFormListBoxField] struct, exposing functionality related to a single
 //! form field of type `PdfFormFieldType::ListBox`.
 
-use crate::bindgen::{FPDF_ANNOTATION, FPDF_FORMHANDLE};
+use crate::bindgen::{
+    FPDF_ANNOTATION, FPDF_FORMFLAG_CHOICE_MULTI_SELECT, FPDF_FORMHANDLE, FPDF_PAGE,
+};
 use crate::bindings::PdfiumLibraryBindings;
-use crate::pdf::document::page::field::options::PdfFormFieldOptions;
+use crate::error::PdfiumError;
+use crate::pdf::document::page::field::options::{PdfFormFieldOptionIndex, PdfFormFieldOptions};
 use crate::pdf::document::page::field::private::internal::PdfFormFieldPrivate;
 
 /// A single `PdfFormField` of type `PdfFormFieldType::ListBox`. The form field object defines
@@ -16,6 +19,7 @@ use crate::pdf::document::page::field::private::internal::PdfFormFieldPrivate;
 /// `PdfForm::field_values()` function.
 pub struct PdfFormListBoxField<'a> {
     form_handle: FPDF_FORMHANDLE,
+    page_handle: FPDF_PAGE,
     annotation_handle: FPDF_ANNOTATION,
     options: PdfFormFieldOptions<'a>,
     bindings: &'a dyn PdfiumLibraryBindings,
@@ -25,11 +29,13 @@ impl<'a> PdfFormListBoxField<'a> {
     #[inline]
     pub(crate) fn from_pdfium(
         form_handle: FPDF_FORMHANDLE,
+        page_handle: FPDF_PAGE,
         annotation_handle: FPDF_ANNOTATION,
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Self {
         PdfFormListBoxField {
             form_handle,
+            page_handle,
             annotation_handle,
             options: PdfFormFieldOptions::from_pdfium(form_handle, annotation_handle, bindings),
             bindings,
@@ -48,6 +54,9 @@ impl<'a> PdfFormListBoxField<'a> {
     }
 
     /// Returns the displayed label for the currently selected option in this [PdfFormListBoxField] object, if any.
+    /// If this [PdfFormListBoxField] allows multiple selection, the label of the first
+    /// selected option is returned; use the [PdfFormListBoxField::values()] function
+    /// to retrieve the labels of all selected options.
     #[inline]
     pub fn value(&self) -> Option<String> {
         self.options()
@@ -55,6 +64,79 @@ impl<'a> PdfFormListBoxField<'a> {
             .find(|option| option.is_set())
             .and_then(|option| option.label().cloned())
     }
+
+    /// Returns the displayed labels for all the currently selected options in this
+    /// [PdfFormListBoxField] object.
+    #[inline]
+    pub fn values(&self) -> Vec<String> {
+        self.options()
+            .iter()
+            .filter(|option| option.is_set())
+            .filter_map(|option| option.label().cloned())
+            .collect()
+    }
+
+    /// Returns `true` if this [PdfFormListBoxField] allows more than one option
+    /// to be selected at the same time.
+    #[inline]
+    pub fn is_multi_select(&self) -> bool {
+        self.bindings
+            .FPDFAnnot_GetFormFieldFlags(self.form_handle, self.annotation_handle) as u32
+            & FPDF_FORMFLAG_CHOICE_MULTI_SELECT
+            != 0
+    }
+
+    /// Sets the value of this [PdfFormListBoxField] object by selecting the option
+    /// with the given displayed label. Any other selected options are deselected.
+    ///
+    /// Returns [PdfiumError::FormFieldOptionNotFound] if no option has the given label.
+    pub fn set_value(&mut self, value: &str) -> Result<(), PdfiumError> {
+        let index = self
+            .options()
+            .iter()
+            .find(|option| option.label().map(|label| label.as_str()) == Some(value))
+            .map(|option| option.index())
+            .ok_or(PdfiumError::FormFieldOptionNotFound)?;
+
+        if self.is_multi_select() {
+            let selected = self
+                .options()
+                .iter()
+                .filter(|option| option.is_set() && option.index() != index)
+                .map(|option| option.index())
+                .collect::<Vec<_>>();
+
+            for other in selected {
+                self.deselect_option(other)?;
+            }
+        }
+
+        self.select_option(index)
+    }
+
+    /// Selects the option at the given index in this [PdfFormListBoxField] object.
+    ///
+    /// If this [PdfFormListBoxField] allows multiple selection, the option is added to
+    /// the current selection; otherwise, it replaces any previously selected option.
+    pub fn select_option(&mut self, index: PdfFormFieldOptionIndex) -> Result<(), PdfiumError> {
+        if index >= self.options().len() {
+            return Err(PdfiumError::FormFieldOptionIndexOutOfBounds);
+        }
+
+        self.set_option_selected_impl(self.page_handle, index, true)
+    }
+
+    /// Deselects the option at the given index in this [PdfFormListBoxField] object.
+    ///
+    /// Pdfium only supports deselecting individual options in list boxes that allow
+    /// multiple selection.
+    pub fn deselect_option(&mut self, index: PdfFormFieldOptionIndex) -> Result<(), PdfiumError> {
+        if index >= self.options().len() {
+            return Err(PdfiumError::FormFieldOptionIndexOutOfBounds);
+        }
+
+        self.set_option_selected_impl(self.page_handle, index, false)
+    }
 }
 
 impl<'a> PdfFormFieldPrivate<'a> for PdfFormListBoxField<'a> {
@@ -73,3 +155,124 @@ impl<'a> PdfFormFieldPrivate<'a> for PdfFormListBoxField<'a> {
         self.bindings
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    fn list_box_field<'a, 'b>(
+        annotation: &'a mut PdfPageAnnotation<'b>,
+    ) -> &'a mut PdfFormListBoxField<'b> {
+        match annotation.as_form_field_mut() {
+            Some(PdfFormField::ListBox(field)) => field,
+            _ => panic!("annotation is not a list box"),
+        }
+    }
+
+    fn find_list_box<'a>(page: &'a PdfPage, name: &str) -> PdfPageAnnotation<'a> {
+        page.annotations()
+            .iter()
+            .find(|annotation| {
+                annotation
+                    .as_form_field()
+                    .and_then(|field| field.as_list_box_field())
+                    .and_then(|field| field.name())
+                    .as_deref()
+                    == Some(name)
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_multi_select_list_box() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/choice-fields-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let mut annotation = find_list_box(&page, "colors");
+
+        let field = list_box_field(&mut annotation);
+
+        assert!(field.is_multi_select());
+        assert_eq!(field.values(), vec!["Red".to_string()]);
+
+        field.select_option(2)?;
+
+        assert_eq!(field.values(), vec!["Red".to_string(), "Blue".to_string()]);
+
+        field.deselect_option(0)?;
+
+        assert_eq!(field.values(), vec!["Blue".to_string()]);
+        assert_eq!(field.value(), Some("Blue".to_string()));
+
+        field.select_option(1)?;
+        field.set_value("Red")?;
+
+        assert_eq!(field.values(), vec!["Red".to_string()]);
+
+        assert!(matches!(
+            field.set_value("Purple"),
+            Err(PdfiumError::FormFieldOptionNotFound)
+        ));
+        assert!(matches!(
+            field.deselect_option(3),
+            Err(PdfiumError::FormFieldOptionIndexOutOfBounds)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_single_select_list_box() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/choice-fields-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let mut annotation = find_list_box(&page, "size");
+
+        let field = list_box_field(&mut annotation);
+
+        assert!(!field.is_multi_select());
+        assert_eq!(field.value(), Some("Medium".to_string()));
+
+        field.select_option(2)?;
+
+        assert_eq!(field.values(), vec!["Large".to_string()]);
+
+        field.set_value("Small")?;
+
+        assert_eq!(field.value(), Some("Small".to_string()));
+        assert_eq!(field.values(), vec!["Small".to_string()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_option_preserves_form_session_focus() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/choice-fields-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let mut session = page.form_session()?;
+
+        session.set_focused_annotation_index(2)?;
+
+        let mut annotation = find_list_box(&page, "colors");
+
+        let field = list_box_field(&mut annotation);
+
+        field.select_option(1)?;
+
+        assert_eq!(field.values(), vec!["Red".to_string(), "Green".to_string()]);
+        assert_eq!(session.focused_annotation_index(), Some(2));
+
+        Ok(())
+    }
+}
@@ -11,18 +11,22 @@ pub(crate) mod internal {
         FPDF_ANNOTATION, FPDF_ANNOT_FLAG_HIDDEN, FPDF_ANNOT_FLAG_INVISIBLE, FPDF_ANNOT_FLAG_LOCKED,
         FPDF_ANNOT_FLAG_NONE, FPDF_ANNOT_FLAG_NOROTATE, FPDF_ANNOT_FLAG_NOVIEW,
         FPDF_ANNOT_FLAG_NOZOOM, FPDF_ANNOT_FLAG_PRINT, FPDF_ANNOT_FLAG_READONLY,
-        FPDF_ANNOT_FLAG_TOGGLENOVIEW, FPDF_FORMHANDLE, FPDF_WCHAR,
+        FPDF_ANNOT_FLAG_TOGGLENOVIEW, FPDF_FORMHANDLE, FPDF_PAGE, FPDF_WCHAR,
     };
     use crate::bindings::PdfiumLibraryBindings;
     use crate::error::PdfiumError;
     use crate::pdf::appearance_mode::PdfAppearanceMode;
-    use crate::pdf::document::page::field::PdfFormFieldCommon;
+    use crate::pdf::document::page::field::options::PdfFormFieldOptionIndex;
+    use crate::pdf::document::page::field::{
+        PdfFormFieldAdditionalActionEvent, PdfFormFieldCommon,
+    };
     use crate::utils::dates::date_time_to_pdf_string;
     use crate::utils::mem::create_byte_buffer;
     use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
     use bitflags::bitflags;
     use chrono::Utc;
     use std::os::raw::c_int;
+    use std::ptr::null_mut;
 
     bitflags! {
         pub struct FpdfAnnotationFlags: u32 {
@@ -152,6 +156,67 @@ pub(crate) mod internal {
                 })
         }
 
+        /// Internal implementation of `select_option()` function shared by form field widgets
+        /// with selectable options, such as combo boxes and list boxes. Not exposed directly
+        /// by [PdfFormFieldCommon].
+        fn set_option_selected_impl(
+            &mut self,
+            page_handle: FPDF_PAGE,
+            index: PdfFormFieldOptionIndex,
+            is_selected: bool,
+        ) -> Result<(), PdfiumError> {
+            let bindings = self.bindings();
+
+            let form_handle = *self.form_handle();
+
+            // Pdfium only applies option selection changes to the form field that currently
+            // has focus, so we temporarily give this field focus. Removing focus afterwards
+            // commits the change to the field's value and regenerates its appearance stream.
+            // Any form field that had focus beforehand, perhaps as part of a PdfFormSession,
+            // is given focus again once the change is committed.
+
+            bindings.FORM_OnAfterLoadPage(page_handle, form_handle);
+
+            let mut previous_page_index: c_int = -1;
+
+            let mut previous_annotation_handle: FPDF_ANNOTATION = null_mut();
+
+            if !bindings.is_true(bindings.FORM_GetFocusedAnnot(
+                form_handle,
+                &mut previous_page_index,
+                &mut previous_annotation_handle,
+            )) {
+                previous_annotation_handle = null_mut();
+            }
+
+            let is_applied = bindings
+                .is_true(bindings.FORM_SetFocusedAnnot(form_handle, *self.annotation_handle()))
+                && bindings.is_true(bindings.FORM_SetIndexSelected(
+                    form_handle,
+                    page_handle,
+                    index as c_int,
+                    bindings.bool_to_pdfium(is_selected),
+                ))
+                && bindings.is_true(bindings.FORM_IsIndexSelected(
+                    form_handle,
+                    page_handle,
+                    index as c_int,
+                )) == is_selected;
+
+            bindings.FORM_ForceToKillFocus(form_handle);
+
+            if !previous_annotation_handle.is_null() {
+                bindings.FORM_SetFocusedAnnot(form_handle, previous_annotation_handle);
+                bindings.FPDFPage_CloseAnnot(previous_annotation_handle);
+            }
+
+            if is_applied {
+                Ok(())
+            } else {
+                Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+            }
+        }
+
         /// Internal implementation of `export_value()` function shared by on/off form field widgets
         /// such as checkbox and radio button fields. Not exposed directly by [PdfFormFieldCommon].
         fn export_value_impl(&self) -> Option<String> {
@@ -252,6 +317,47 @@ pub(crate) mod internal {
             }
         }
 
+        /// Internal implementation of [PdfFormFieldCommon::javascript()].
+        fn javascript_impl(&self, event: PdfFormFieldAdditionalActionEvent) -> Option<String> {
+            // Retrieving the JavaScript from Pdfium is a two-step operation. First, we call
+            // FPDFAnnot_GetFormAdditionalActionJavaScript() with a null buffer; this will
+            // retrieve the length of the script in bytes, including the 2-byte null terminator.
+            // If the length is zero, an error occurred; if the length is 2, then there is
+            // no script for the given event.
+
+            // If the length is greater than 2, then we reserve a byte buffer of the given
+            // length and call FPDFAnnot_GetFormAdditionalActionJavaScript() again with a pointer
+            // to the buffer; this will write the script to the buffer in UTF16LE format.
+
+            let buffer_length = self.bindings().FPDFAnnot_GetFormAdditionalActionJavaScript(
+                *self.form_handle(),
+                *self.annotation_handle(),
+                event.as_pdfium() as c_int,
+                std::ptr::null_mut(),
+                0,
+            );
+
+            if buffer_length <= 2 {
+                // There is no script for the given event.
+
+                None
+            } else {
+                let mut buffer = create_byte_buffer(buffer_length as usize);
+
+                let result = self.bindings().FPDFAnnot_GetFormAdditionalActionJavaScript(
+                    *self.form_handle(),
+                    *self.annotation_handle(),
+                    event.as_pdfium() as c_int,
+                    buffer.as_mut_ptr() as *mut FPDF_WCHAR,
+                    buffer_length,
+                );
+
+                assert_eq!(result, buffer_length);
+
+                get_string_from_pdfium_utf16le_bytes(buffer)
+            }
+        }
+
         /// Returns the currently set appearance stream for this form field, if any.
         fn appearance_stream_impl(&self) -> Option<String> {
             // Retrieving the appearance stream value from Pdfium is a two-step operation.
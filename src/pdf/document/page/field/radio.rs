@@ -67,6 +67,13 @@ impl<'a> PdfFormRadioButtonField<'a> {
         self.value_impl()
     }
 
+    /// Returns the export value of this [PdfFormRadioButtonField], if any. This is the value
+    /// taken by the control group containing this [PdfFormRadioButtonField] when its radio button is selected.
+    #[inline]
+    pub fn export_value(&self) -> Option<String> {
+        self.export_value_impl()
+    }
+
     /// Returns `true` if this [PdfFormRadioButtonField] object has its radio button selected.
     #[inline]
     pub fn is_checked(&self) -> Result<bool, PdfiumError> {
@@ -1,6 +1,198 @@
-use crate::bindgen::FPDF_PAGE;
+//! A native page flattening operation, used in place of Pdfium's built-in `FPDFPage_Flatten()`
+//! function when this crate's `flatten` feature is enabled.
+
+use crate::bindgen::{FLATTEN_FAIL, FLAT_PRINT};
 use crate::error::PdfiumError;
+use crate::pdf::appearance_mode::PdfAppearanceMode;
+use crate::pdf::document::page::annotation::{
+    PdfPageAnnotationCommon, PdfPageAnnotationFlags, PdfPageAnnotationType,
+};
+use crate::pdf::document::page::annotations::PdfPageAnnotationIndex;
+use crate::pdf::document::page::index_cache::PdfPageIndexCache;
+use crate::pdf::document::page::object::PdfPageObject;
+use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
+use crate::pdf::document::page::{PdfPage, PdfPageContentRegenerationStrategy};
+use crate::pdf::document::pages::PdfPages;
+use crate::pdfium::Pdfium;
+use std::os::raw::c_int;
+
+/// Flattens all printable annotations and form fields on the given [PdfPage] into the
+/// page's content.
+///
+/// Flattening takes place in four steps. First, appearance streams are generated for any
+/// form field widgets that lack them, so that no form field is silently discarded. Second,
+/// the page is copied into a temporary in-memory document, and all page objects and all
+/// annotations that will not be flattened are removed from the copy. Third, the appearance
+/// streams of the remaining annotations are merged into the content of the copy, and the
+/// merged content is added to the given page as a single form XObject. Finally, the flattened
+/// annotations are removed from the given page.
+///
+/// Since the given page is never flattened by Pdfium directly, its `FPDF_PAGE` handle
+/// remains valid throughout and the page does not need to be reloaded.
+pub(crate) fn flatten_page(page: &mut PdfPage) -> Result<(), PdfiumError> {
+    let bindings = page.bindings();
+
+    if let Some(form_handle) = page.form_handle {
+        // Loading the page into Pdfium's form filling module regenerates the appearance
+        // stream of any form field widget whose appearance stream is missing or out of date.
+
+        bindings.FORM_OnAfterLoadPage(page.page_handle(), form_handle);
+    }
+
+    let indices = flattenable_annotation_indices(page)?;
+
+    if indices.is_empty() {
+        return Ok(());
+    }
+
+    let page_index =
+        PdfPageIndexCache::get_index_for_page(page.document_handle(), page.page_handle())
+            .ok_or(PdfiumError::SourcePageIndexNotInCache)?;
+
+    let cache =
+        Pdfium::pdfium_document_handle_to_result(bindings.FPDF_CreateNewDocument(), bindings)?;
+
+    PdfPages::copy_page_range_between_documents(
+        page.document_handle(),
+        page_index..=page_index,
+        cache.handle(),
+        0,
+        bindings,
+    )?;
+
+    {
+        let mut copy = cache.pages().get(0)?;
+
+        copy.set_content_regeneration_strategy(PdfPageContentRegenerationStrategy::Manual);
+
+        for index in (0..copy.objects().len()).rev() {
+            copy.objects_mut().remove_object_at_index(index)?;
+        }
+
+        // Pdfium copies a page's annotations in order, so the indices of the annotations
+        // on the copy match the indices of the annotations on the given page.
+
+        for index in (0..copy.annotations().len()).rev() {
+            if !indices.contains(&index) {
+                let annotation = copy.annotations().get(index)?;
+
+                copy.annotations_mut().delete_annotation(annotation)?;
+            }
+        }
+
+        copy.regenerate_content()?;
+    }
+
+    // Only annotations with appearance streams remain on the copy, so Pdfium's flatten
+    // operation will not discard anything. We use a raw page handle here, since the copy's
+    // page content must not be regenerated after flattening.
+
+    let copy_handle = bindings.FPDF_LoadPage(cache.handle(), 0);
+
+    let result = bindings.FPDFPage_Flatten(copy_handle, FLAT_PRINT as c_int);
+
+    bindings.FPDF_ClosePage(copy_handle);
+
+    if result as u32 == FLATTEN_FAIL {
+        return Err(PdfiumError::PageFlattenFailure);
+    }
+
+    let xobject = bindings.FPDF_NewXObjectFromPage(page.document_handle(), cache.handle(), 0);
+
+    if xobject.is_null() {
+        return Err(PdfiumError::PageFlattenFailure);
+    }
+
+    let object_handle = bindings.FPDF_NewFormObjectFromXObject(xobject);
+
+    bindings.FPDF_CloseXObject(xobject);
+
+    if object_handle.is_null() {
+        return Err(PdfiumError::PageFlattenFailure);
+    }
+
+    page.objects_mut().add_object(PdfPageObject::from_pdfium(
+        object_handle,
+        page.document_handle(),
+        None,
+        None,
+        bindings,
+    ))?;
+
+    for index in indices.into_iter().rev() {
+        let annotation = page.annotations().get(index)?;
+
+        page.annotations_mut().delete_annotation(annotation)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the indices of all annotations on the given [PdfPage] that should be flattened.
+///
+/// Following Pdfium's own flatten operation, popup annotations, hidden annotations,
+/// and annotations that are not printed are not flattened. Annotations without a normal
+/// appearance stream cannot be flattened, and are left on the page unchanged.
+fn flattenable_annotation_indices(
+    page: &PdfPage,
+) -> Result<Vec<PdfPageAnnotationIndex>, PdfiumError> {
+    let annotations = page.annotations();
+
+    let mut result = Vec::new();
+
+    for index in 0..annotations.len() {
+        let annotation = annotations.get(index)?;
+
+        let flags = annotation.flags();
+
+        if annotation.annotation_type() != PdfPageAnnotationType::Popup
+            && !flags.contains(PdfPageAnnotationFlags::HIDDEN)
+            && flags.contains(PdfPageAnnotationFlags::PRINT)
+            && annotation
+                .appearance_stream(PdfAppearanceMode::Normal)
+                .is_some()
+        {
+            result.push(index);
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_flatten_page() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/form-test.pdf", None)?;
+
+        let mut page = document.pages().get(0)?;
+
+        let widgets = page
+            .annotations()
+            .iter()
+            .filter(|annotation| annotation.annotation_type() == PdfPageAnnotationType::Widget)
+            .count();
+
+        assert!(widgets > 0);
+
+        let objects = page.objects().len();
+
+        page.flatten()?;
+
+        assert_eq!(
+            page.annotations()
+                .iter()
+                .filter(|annotation| annotation.annotation_type() == PdfPageAnnotationType::Widget)
+                .count(),
+            0
+        );
+        assert_eq!(page.objects().len(), objects + 1);
 
-fn flatten(_page_handle: FPDF_PAGE) -> Result<(), PdfiumError> {
-    unimplemented!()
+        Ok(())
+    }
 }
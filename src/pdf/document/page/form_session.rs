@@ -0,0 +1,851 @@
+//! Defines the [PdfFormSession] struct, exposing functionality related to interactively
+//! filling in the form fields on a single [PdfPage] by simulating user input events.
+
+use crate::bindgen::{
+    FWL_EVENTFLAG_FWL_EVENTFLAG_AltKey, FWL_EVENTFLAG_FWL_EVENTFLAG_AutoRepeat,
+    FWL_EVENTFLAG_FWL_EVENTFLAG_ControlKey, FWL_EVENTFLAG_FWL_EVENTFLAG_KeyPad,
+    FWL_EVENTFLAG_FWL_EVENTFLAG_LeftButtonDown, FWL_EVENTFLAG_FWL_EVENTFLAG_MetaKey,
+    FWL_EVENTFLAG_FWL_EVENTFLAG_MiddleButtonDown, FWL_EVENTFLAG_FWL_EVENTFLAG_RightButtonDown,
+    FWL_EVENTFLAG_FWL_EVENTFLAG_ShiftKey, FWL_VKEYCODE_FWL_VKEY_Back,
+    FWL_VKEYCODE_FWL_VKEY_Control, FWL_VKEYCODE_FWL_VKEY_Delete, FWL_VKEYCODE_FWL_VKEY_Down,
+    FWL_VKEYCODE_FWL_VKEY_End, FWL_VKEYCODE_FWL_VKEY_Escape, FWL_VKEYCODE_FWL_VKEY_Home,
+    FWL_VKEYCODE_FWL_VKEY_Insert, FWL_VKEYCODE_FWL_VKEY_Left, FWL_VKEYCODE_FWL_VKEY_Menu,
+    FWL_VKEYCODE_FWL_VKEY_Next, FWL_VKEYCODE_FWL_VKEY_Prior, FWL_VKEYCODE_FWL_VKEY_Return,
+    FWL_VKEYCODE_FWL_VKEY_Right, FWL_VKEYCODE_FWL_VKEY_Shift, FWL_VKEYCODE_FWL_VKEY_Space,
+    FWL_VKEYCODE_FWL_VKEY_Tab, FWL_VKEYCODE_FWL_VKEY_Up, FPDF_ANNOTATION, FPDF_FORMHANDLE,
+    FPDF_PAGE, FPDF_WIDESTRING, FS_POINTF,
+};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::page::annotations::PdfPageAnnotationIndex;
+use crate::pdf::document::page::field::PdfFormFieldType;
+use crate::pdf::document::page::PdfPage;
+use crate::pdf::points::PdfPoints;
+use crate::utils::mem::create_byte_buffer;
+use crate::utils::utf16le::{
+    get_pdfium_utf16le_bytes_from_str, get_string_from_pdfium_utf16le_bytes,
+};
+use bitflags::bitflags;
+use std::os::raw::{c_int, c_ulong, c_void};
+use std::ptr::null_mut;
+
+#[cfg(doc)]
+use crate::pdf::document::form::PdfForm;
+
+bitflags! {
+    /// The modifier keys and mouse buttons held down when a user input event is sent
+    /// to a [PdfFormSession].
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+    pub struct PdfFormInputModifiers: u32 {
+        const SHIFT = FWL_EVENTFLAG_FWL_EVENTFLAG_ShiftKey;
+        const CONTROL = FWL_EVENTFLAG_FWL_EVENTFLAG_ControlKey;
+        const ALT = FWL_EVENTFLAG_FWL_EVENTFLAG_AltKey;
+        const META = FWL_EVENTFLAG_FWL_EVENTFLAG_MetaKey;
+        const KEYPAD = FWL_EVENTFLAG_FWL_EVENTFLAG_KeyPad;
+        const AUTO_REPEAT = FWL_EVENTFLAG_FWL_EVENTFLAG_AutoRepeat;
+        const LEFT_BUTTON_DOWN = FWL_EVENTFLAG_FWL_EVENTFLAG_LeftButtonDown;
+        const MIDDLE_BUTTON_DOWN = FWL_EVENTFLAG_FWL_EVENTFLAG_MiddleButtonDown;
+        const RIGHT_BUTTON_DOWN = FWL_EVENTFLAG_FWL_EVENTFLAG_RightButtonDown;
+    }
+}
+
+impl PdfFormInputModifiers {
+    #[inline]
+    pub(crate) fn as_pdfium(&self) -> c_int {
+        self.bits() as c_int
+    }
+}
+
+/// A key on the keyboard that can be sent to a [PdfFormSession] as a key press event.
+///
+/// Printable characters should be sent using the [PdfFormSession::char()] or
+/// [PdfFormSession::type_text()] functions rather than as key presses.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PdfFormKey {
+    Backspace,
+    Tab,
+    Return,
+    Shift,
+    Control,
+    Alt,
+    Escape,
+    Space,
+    PageUp,
+    PageDown,
+    End,
+    Home,
+    Left,
+    Up,
+    Right,
+    Down,
+    Insert,
+    Delete,
+
+    /// Any other key, identified by its Windows virtual key code.
+    Other(u32),
+}
+
+impl PdfFormKey {
+    #[inline]
+    pub(crate) fn as_pdfium(&self) -> c_int {
+        (match self {
+            PdfFormKey::Backspace => FWL_VKEYCODE_FWL_VKEY_Back,
+            PdfFormKey::Tab => FWL_VKEYCODE_FWL_VKEY_Tab,
+            PdfFormKey::Return => FWL_VKEYCODE_FWL_VKEY_Return,
+            PdfFormKey::Shift => FWL_VKEYCODE_FWL_VKEY_Shift,
+            PdfFormKey::Control => FWL_VKEYCODE_FWL_VKEY_Control,
+            PdfFormKey::Alt => FWL_VKEYCODE_FWL_VKEY_Menu,
+            PdfFormKey::Escape => FWL_VKEYCODE_FWL_VKEY_Escape,
+            PdfFormKey::Space => FWL_VKEYCODE_FWL_VKEY_Space,
+            PdfFormKey::PageUp => FWL_VKEYCODE_FWL_VKEY_Prior,
+            PdfFormKey::PageDown => FWL_VKEYCODE_FWL_VKEY_Next,
+            PdfFormKey::End => FWL_VKEYCODE_FWL_VKEY_End,
+            PdfFormKey::Home => FWL_VKEYCODE_FWL_VKEY_Home,
+            PdfFormKey::Left => FWL_VKEYCODE_FWL_VKEY_Left,
+            PdfFormKey::Up => FWL_VKEYCODE_FWL_VKEY_Up,
+            PdfFormKey::Right => FWL_VKEYCODE_FWL_VKEY_Right,
+            PdfFormKey::Down => FWL_VKEYCODE_FWL_VKEY_Down,
+            PdfFormKey::Insert => FWL_VKEYCODE_FWL_VKEY_Insert,
+            PdfFormKey::Delete => FWL_VKEYCODE_FWL_VKEY_Delete,
+            PdfFormKey::Other(code) => *code,
+        }) as c_int
+    }
+}
+
+/// A snapshot of the focus and selection state of a [PdfFormSession].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct PdfFormSessionState {
+    focused_annotation_index: Option<PdfPageAnnotationIndex>,
+    focused_text: String,
+    selected_text: String,
+}
+
+/// The outcome of sending a single user input event to a [PdfFormSession], reporting
+/// whether Pdfium handled the event and any changes to form focus, text, or selection
+/// that resulted from it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdfFormEventOutcome {
+    was_handled: bool,
+    before: PdfFormSessionState,
+    after: PdfFormSessionState,
+}
+
+impl PdfFormEventOutcome {
+    /// Returns `true` if Pdfium's form filling logic handled the event.
+    #[inline]
+    pub fn was_handled(&self) -> bool {
+        self.was_handled
+    }
+
+    /// Returns `true` if the event moved form focus to a different annotation,
+    /// or removed focus altogether.
+    #[inline]
+    pub fn is_focus_changed(&self) -> bool {
+        self.before.focused_annotation_index != self.after.focused_annotation_index
+    }
+
+    /// Returns the index of the form field annotation on the page that had focus
+    /// before the event was sent, if any.
+    #[inline]
+    pub fn previous_focused_annotation_index(&self) -> Option<PdfPageAnnotationIndex> {
+        self.before.focused_annotation_index
+    }
+
+    /// Returns the index of the form field annotation on the page that has focus
+    /// after the event was sent, if any.
+    #[inline]
+    pub fn focused_annotation_index(&self) -> Option<PdfPageAnnotationIndex> {
+        self.after.focused_annotation_index
+    }
+
+    /// Returns `true` if the event changed the text of the focused form field.
+    ///
+    /// A change of focus alone does not count as a text change.
+    #[inline]
+    pub fn is_text_changed(&self) -> bool {
+        !self.is_focus_changed() && self.before.focused_text != self.after.focused_text
+    }
+
+    /// Returns the text of the focused form field after the event was sent.
+    #[inline]
+    pub fn focused_text(&self) -> &str {
+        self.after.focused_text.as_str()
+    }
+
+    /// Returns `true` if the event changed the selected text in the focused form field.
+    #[inline]
+    pub fn is_selection_changed(&self) -> bool {
+        self.before.selected_text != self.after.selected_text
+    }
+
+    /// Returns the selected text in the focused form field after the event was sent.
+    #[inline]
+    pub fn selected_text(&self) -> &str {
+        self.after.selected_text.as_str()
+    }
+}
+
+/// An interactive form filling session for a single [PdfPage].
+///
+/// A [PdfFormSession] accepts mouse and keyboard events in page coordinates and passes
+/// them to Pdfium's own form filling logic, allowing an application to implement a
+/// form editor that behaves in the same way as other Pdfium-based viewers: clicking
+/// into fields, typing, selecting text, toggling checkboxes and radio buttons, choosing
+/// list and combo box options, and undoing and redoing edits. Each event function returns
+/// a [PdfFormEventOutcome] reporting any resulting changes to focus, text, and selection.
+///
+/// Create a [PdfFormSession] by calling the [PdfPage::form_session()] function. The page's
+/// containing document must have an embedded [PdfForm]. Focus is removed from any
+/// focused form field, committing its value, when the [PdfFormSession] is dropped.
+///
+/// Pdfium does not regenerate a form field's appearance stream until the field loses
+/// focus, so changes made during a session may not be visible in rendered output
+/// until focus moves elsewhere or the session ends.
+pub struct PdfFormSession<'a> {
+    page: &'a PdfPage<'a>,
+    page_handle: FPDF_PAGE,
+    form_handle: FPDF_FORMHANDLE,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfFormSession<'a> {
+    pub(crate) fn new(
+        page: &'a PdfPage<'a>,
+        form_handle: FPDF_FORMHANDLE,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        let page_handle = page.page_handle();
+
+        // Pdfium's form filling module tracks form state in a per-page view that must be
+        // explicitly created before any input events are sent for the page.
+
+        bindings.FORM_OnAfterLoadPage(page_handle, form_handle);
+
+        PdfFormSession {
+            page,
+            page_handle,
+            form_handle,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfFormSession].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the [PdfPage] that is the target of this [PdfFormSession].
+    #[inline]
+    pub fn page(&self) -> &'a PdfPage<'a> {
+        self.page
+    }
+
+    /// Sends a mouse move event at the given position to this [PdfFormSession].
+    pub fn mouse_move(
+        &mut self,
+        x: PdfPoints,
+        y: PdfPoints,
+        modifiers: PdfFormInputModifiers,
+    ) -> PdfFormEventOutcome {
+        self.dispatch(|bindings, form, page| {
+            bindings.FORM_OnMouseMove(
+                form,
+                page,
+                modifiers.as_pdfium(),
+                x.value as f64,
+                y.value as f64,
+            )
+        })
+    }
+
+    /// Sends a mouse wheel event at the given position to this [PdfFormSession].
+    /// The given deltas should be normalized to platform-agnostic wheel units;
+    /// negative values scroll left or down.
+    pub fn mouse_wheel(
+        &mut self,
+        x: PdfPoints,
+        y: PdfPoints,
+        delta_x: i32,
+        delta_y: i32,
+        modifiers: PdfFormInputModifiers,
+    ) -> PdfFormEventOutcome {
+        let point = FS_POINTF {
+            x: x.value,
+            y: y.value,
+        };
+
+        self.dispatch(|bindings, form, page| {
+            bindings.FORM_OnMouseWheel(form, page, modifiers.as_pdfium(), &point, delta_x, delta_y)
+        })
+    }
+
+    /// Sends a left mouse button press event at the given position to this [PdfFormSession].
+    pub fn left_button_down(
+        &mut self,
+        x: PdfPoints,
+        y: PdfPoints,
+        modifiers: PdfFormInputModifiers,
+    ) -> PdfFormEventOutcome {
+        self.dispatch(|bindings, form, page| {
+            bindings.FORM_OnLButtonDown(
+                form,
+                page,
+                modifiers.as_pdfium(),
+                x.value as f64,
+                y.value as f64,
+            )
+        })
+    }
+
+    /// Sends a left mouse button release event at the given position to this [PdfFormSession].
+    pub fn left_button_up(
+        &mut self,
+        x: PdfPoints,
+        y: PdfPoints,
+        modifiers: PdfFormInputModifiers,
+    ) -> PdfFormEventOutcome {
+        self.dispatch(|bindings, form, page| {
+            bindings.FORM_OnLButtonUp(
+                form,
+                page,
+                modifiers.as_pdfium(),
+                x.value as f64,
+                y.value as f64,
+            )
+        })
+    }
+
+    /// Sends a left mouse button double click event at the given position
+    /// to this [PdfFormSession].
+    pub fn left_button_double_click(
+        &mut self,
+        x: PdfPoints,
+        y: PdfPoints,
+        modifiers: PdfFormInputModifiers,
+    ) -> PdfFormEventOutcome {
+        self.dispatch(|bindings, form, page| {
+            bindings.FORM_OnLButtonDoubleClick(
+                form,
+                page,
+                modifiers.as_pdfium(),
+                x.value as f64,
+                y.value as f64,
+            )
+        })
+    }
+
+    /// Sends a right mouse button press event at the given position to this [PdfFormSession].
+    pub fn right_button_down(
+        &mut self,
+        x: PdfPoints,
+        y: PdfPoints,
+        modifiers: PdfFormInputModifiers,
+    ) -> PdfFormEventOutcome {
+        self.dispatch(|bindings, form, page| {
+            bindings.FORM_OnRButtonDown(
+                form,
+                page,
+                modifiers.as_pdfium(),
+                x.value as f64,
+                y.value as f64,
+            )
+        })
+    }
+
+    /// Sends a right mouse button release event at the given position to this [PdfFormSession].
+    pub fn right_button_up(
+        &mut self,
+        x: PdfPoints,
+        y: PdfPoints,
+        modifiers: PdfFormInputModifiers,
+    ) -> PdfFormEventOutcome {
+        self.dispatch(|bindings, form, page| {
+            bindings.FORM_OnRButtonUp(
+                form,
+                page,
+                modifiers.as_pdfium(),
+                x.value as f64,
+                y.value as f64,
+            )
+        })
+    }
+
+    /// Sends a left mouse button press event immediately followed by a left mouse button
+    /// release event at the given position to this [PdfFormSession]. The returned
+    /// [PdfFormEventOutcome] reports the combined effect of both events.
+    pub fn click(
+        &mut self,
+        x: PdfPoints,
+        y: PdfPoints,
+        modifiers: PdfFormInputModifiers,
+    ) -> PdfFormEventOutcome {
+        self.dispatch(|bindings, form, page| {
+            let is_down_handled = bindings.is_true(bindings.FORM_OnLButtonDown(
+                form,
+                page,
+                modifiers.as_pdfium(),
+                x.value as f64,
+                y.value as f64,
+            ));
+
+            let is_up_handled = bindings.is_true(bindings.FORM_OnLButtonUp(
+                form,
+                page,
+                modifiers.as_pdfium(),
+                x.value as f64,
+                y.value as f64,
+            ));
+
+            bindings.bool_to_pdfium(is_down_handled || is_up_handled)
+        })
+    }
+
+    /// Moves form focus to the form field annotation at the given position, if any.
+    /// If there is no form field at the given position, focus is removed from any
+    /// currently focused form field.
+    pub fn focus_at(
+        &mut self,
+        x: PdfPoints,
+        y: PdfPoints,
+        modifiers: PdfFormInputModifiers,
+    ) -> PdfFormEventOutcome {
+        self.dispatch(|bindings, form, page| {
+            bindings.FORM_OnFocus(
+                form,
+                page,
+                modifiers.as_pdfium(),
+                x.value as f64,
+                y.value as f64,
+            )
+        })
+    }
+
+    /// Sends a key press event for the given key to this [PdfFormSession].
+    pub fn key_down(
+        &mut self,
+        key: PdfFormKey,
+        modifiers: PdfFormInputModifiers,
+    ) -> PdfFormEventOutcome {
+        self.dispatch(|bindings, form, page| {
+            bindings.FORM_OnKeyDown(form, page, key.as_pdfium(), modifiers.as_pdfium())
+        })
+    }
+
+    /// Sends a key release event for the given key to this [PdfFormSession].
+    pub fn key_up(
+        &mut self,
+        key: PdfFormKey,
+        modifiers: PdfFormInputModifiers,
+    ) -> PdfFormEventOutcome {
+        self.dispatch(|bindings, form, page| {
+            bindings.FORM_OnKeyUp(form, page, key.as_pdfium(), modifiers.as_pdfium())
+        })
+    }
+
+    /// Sends a key press event immediately followed by a key release event for the given key
+    /// to this [PdfFormSession]. The returned [PdfFormEventOutcome] reports the combined
+    /// effect of both events.
+    pub fn key_press(
+        &mut self,
+        key: PdfFormKey,
+        modifiers: PdfFormInputModifiers,
+    ) -> PdfFormEventOutcome {
+        self.dispatch(|bindings, form, page| {
+            let is_down_handled = bindings.is_true(bindings.FORM_OnKeyDown(
+                form,
+                page,
+                key.as_pdfium(),
+                modifiers.as_pdfium(),
+            ));
+
+            let is_up_handled = bindings.is_true(bindings.FORM_OnKeyUp(
+                form,
+                page,
+                key.as_pdfium(),
+                modifiers.as_pdfium(),
+            ));
+
+            bindings.bool_to_pdfium(is_down_handled || is_up_handled)
+        })
+    }
+
+    /// Sends a character input event for the given character to this [PdfFormSession].
+    /// Characters outside the Basic Multilingual Plane are sent as a pair of
+    /// UTF-16 surrogate code units.
+    pub fn char(&mut self, char: char, modifiers: PdfFormInputModifiers) -> PdfFormEventOutcome {
+        let mut units = [0u16; 2];
+
+        let units = char.encode_utf16(&mut units);
+
+        self.dispatch(|bindings, form, page| {
+            let mut is_handled = false;
+
+            for unit in units.iter() {
+                is_handled |= bindings.is_true(bindings.FORM_OnChar(
+                    form,
+                    page,
+                    *unit as c_int,
+                    modifiers.as_pdfium(),
+                ));
+            }
+
+            bindings.bool_to_pdfium(is_handled)
+        })
+    }
+
+    /// Sends a character input event for each character in the given text to this
+    /// [PdfFormSession], as if the text had been typed by the user. The returned
+    /// [PdfFormEventOutcome] reports the combined effect of all events.
+    pub fn type_text(&mut self, text: &str) -> PdfFormEventOutcome {
+        let modifiers = PdfFormInputModifiers::empty().as_pdfium();
+
+        self.dispatch(|bindings, form, page| {
+            let mut is_handled = false;
+
+            for unit in text.encode_utf16() {
+                is_handled |=
+                    bindings.is_true(bindings.FORM_OnChar(form, page, unit as c_int, modifiers));
+            }
+
+            bindings.bool_to_pdfium(is_handled)
+        })
+    }
+
+    /// Returns the index of the form field annotation on this [PdfFormSession]'s page
+    /// that currently has form focus, if any.
+    pub fn focused_annotation_index(&self) -> Option<PdfPageAnnotationIndex> {
+        let mut page_index: c_int = -1;
+
+        let mut annotation_handle: FPDF_ANNOTATION = null_mut();
+
+        if !self.bindings.is_true(self.bindings.FORM_GetFocusedAnnot(
+            self.form_handle,
+            &mut page_index,
+            &mut annotation_handle,
+        )) || annotation_handle.is_null()
+        {
+            return None;
+        }
+
+        // The focused annotation may be on a different page. Pdfium will return -1
+        // if the annotation is not on this session's page.
+
+        let index = self
+            .bindings
+            .FPDFPage_GetAnnotIndex(self.page_handle, annotation_handle);
+
+        self.bindings.FPDFPage_CloseAnnot(annotation_handle);
+
+        if index < 0 {
+            None
+        } else {
+            Some(index as PdfPageAnnotationIndex)
+        }
+    }
+
+    /// Moves form focus to the form field annotation at the given index on this
+    /// [PdfFormSession]'s page.
+    pub fn set_focused_annotation_index(
+        &mut self,
+        index: PdfPageAnnotationIndex,
+    ) -> Result<PdfFormEventOutcome, PdfiumError> {
+        if index
+            >= self
+                .bindings
+                .FPDFPage_GetAnnotCount(self.page_handle)
+                .max(0) as usize
+        {
+            return Err(PdfiumError::PageAnnotationIndexOutOfBounds);
+        }
+
+        let annotation_handle = self
+            .bindings
+            .FPDFPage_GetAnnot(self.page_handle, index as c_int);
+
+        if annotation_handle.is_null() {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        let outcome = self
+            .dispatch(|bindings, form, _| bindings.FORM_SetFocusedAnnot(form, annotation_handle));
+
+        self.bindings.FPDFPage_CloseAnnot(annotation_handle);
+
+        if outcome.was_handled() {
+            Ok(outcome)
+        } else {
+            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+        }
+    }
+
+    /// Removes form focus from any currently focused form field, committing any
+    /// pending changes to the field's value.
+    pub fn kill_focus(&mut self) -> PdfFormEventOutcome {
+        self.dispatch(|bindings, form, _| bindings.FORM_ForceToKillFocus(form))
+    }
+
+    /// Returns the type of the form field at the given position on this [PdfFormSession]'s
+    /// page, if any.
+    pub fn form_field_type_at(&self, x: PdfPoints, y: PdfPoints) -> Option<PdfFormFieldType> {
+        let result = self.bindings.FPDFPage_HasFormFieldAtPoint(
+            self.form_handle,
+            self.page_handle,
+            x.value as f64,
+            y.value as f64,
+        );
+
+        if result < 0 {
+            None
+        } else {
+            PdfFormFieldType::from_pdfium(result).ok()
+        }
+    }
+
+    /// Returns the text of the currently focused form field, if any. An empty string
+    /// is returned if no form field has focus.
+    pub fn focused_text(&self) -> String {
+        self.get_text(|bindings, form, page, buffer, length| {
+            bindings.FORM_GetFocusedText(form, page, buffer, length)
+        })
+    }
+
+    /// Returns the text currently selected in the focused form field, if any. An empty
+    /// string is returned if no form field has focus or no text is selected.
+    pub fn selected_text(&self) -> String {
+        self.get_text(|bindings, form, page, buffer, length| {
+            bindings.FORM_GetSelectedText(form, page, buffer, length)
+        })
+    }
+
+    /// Selects all the text in the currently focused form field.
+    pub fn select_all_text(&mut self) -> PdfFormEventOutcome {
+        self.dispatch(|bindings, form, page| bindings.FORM_SelectAllText(form, page))
+    }
+
+    /// Replaces the text currently selected in the focused form field with the given text,
+    /// leaving the text cursor after the inserted text. If no text is selected, the given
+    /// text is inserted at the text cursor position.
+    pub fn replace_selection(&mut self, text: &str) -> PdfFormEventOutcome {
+        let text = get_pdfium_utf16le_bytes_from_str(text);
+
+        self.dispatch(|bindings, form, page| {
+            bindings.FORM_ReplaceSelection(form, page, text.as_ptr() as FPDF_WIDESTRING);
+
+            bindings.TRUE()
+        })
+    }
+
+    /// Replaces the text currently selected in the focused form field with the given text,
+    /// selecting the inserted text.
+    pub fn replace_and_keep_selection(&mut self, text: &str) -> PdfFormEventOutcome {
+        let text = get_pdfium_utf16le_bytes_from_str(text);
+
+        self.dispatch(|bindings, form, page| {
+            bindings.FORM_ReplaceAndKeepSelection(form, page, text.as_ptr() as FPDF_WIDESTRING);
+
+            bindings.TRUE()
+        })
+    }
+
+    /// Returns `true` if the focused form field has an edit that can be undone.
+    #[inline]
+    pub fn can_undo(&self) -> bool {
+        self.bindings.is_true(
+            self.bindings
+                .FORM_CanUndo(self.form_handle, self.page_handle),
+        )
+    }
+
+    /// Returns `true` if the focused form field has an undone edit that can be redone.
+    #[inline]
+    pub fn can_redo(&self) -> bool {
+        self.bindings.is_true(
+            self.bindings
+                .FORM_CanRedo(self.form_handle, self.page_handle),
+        )
+    }
+
+    /// Undoes the most recent edit in the focused form field.
+    pub fn undo(&mut self) -> PdfFormEventOutcome {
+        self.dispatch(|bindings, form, page| bindings.FORM_Undo(form, page))
+    }
+
+    /// Redoes the most recently undone edit in the focused form field.
+    pub fn redo(&mut self) -> PdfFormEventOutcome {
+        self.dispatch(|bindings, form, page| bindings.FORM_Redo(form, page))
+    }
+
+    /// Selects or deselects the option at the given index in the focused list box or
+    /// combo box form field. Combo boxes and single-selection list boxes only support
+    /// selecting options; selecting an option deselects any previously selected option.
+    pub fn set_index_selected(
+        &mut self,
+        index: usize,
+        is_selected: bool,
+    ) -> Result<PdfFormEventOutcome, PdfiumError> {
+        let outcome = self.dispatch(|bindings, form, page| {
+            bindings.FORM_SetIndexSelected(
+                form,
+                page,
+                index as c_int,
+                bindings.bool_to_pdfium(is_selected),
+            )
+        });
+
+        if outcome.was_handled() {
+            Ok(outcome)
+        } else {
+            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+        }
+    }
+
+    /// Returns `true` if the option at the given index in the focused list box or
+    /// combo box form field is selected.
+    #[inline]
+    pub fn is_index_selected(&self, index: usize) -> bool {
+        self.bindings.is_true(self.bindings.FORM_IsIndexSelected(
+            self.form_handle,
+            self.page_handle,
+            index as c_int,
+        ))
+    }
+
+    /// Captures the current focus and selection state of this [PdfFormSession].
+    fn state(&self) -> PdfFormSessionState {
+        PdfFormSessionState {
+            focused_annotation_index: self.focused_annotation_index(),
+            focused_text: self.focused_text(),
+            selected_text: self.selected_text(),
+        }
+    }
+
+    /// Sends a single event to Pdfium using the given callback, reporting any resulting
+    /// changes in focus and selection state.
+    fn dispatch(
+        &mut self,
+        event: impl FnOnce(&dyn PdfiumLibraryBindings, FPDF_FORMHANDLE, FPDF_PAGE) -> c_int,
+    ) -> PdfFormEventOutcome {
+        let before = self.state();
+
+        let was_handled =
+            self.bindings
+                .is_true(event(self.bindings, self.form_handle, self.page_handle));
+
+        PdfFormEventOutcome {
+            was_handled,
+            before,
+            after: self.state(),
+        }
+    }
+
+    /// Retrieves a UTF-16LE string from Pdfium using the given accessor function, which is
+    /// expected to return the required buffer length in bytes when passed a null buffer.
+    fn get_text(
+        &self,
+        accessor: impl Fn(
+            &dyn PdfiumLibraryBindings,
+            FPDF_FORMHANDLE,
+            FPDF_PAGE,
+            *mut c_void,
+            c_ulong,
+        ) -> c_ulong,
+    ) -> String {
+        let buffer_length = accessor(
+            self.bindings,
+            self.form_handle,
+            self.page_handle,
+            null_mut(),
+            0,
+        );
+
+        if buffer_length == 0 {
+            return String::new();
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = accessor(
+            self.bindings,
+            self.form_handle,
+            self.page_handle,
+            buffer.as_mut_ptr() as *mut c_void,
+            buffer_length,
+        );
+
+        debug_assert_eq!(result, buffer_length);
+
+        get_string_from_pdfium_utf16le_bytes(buffer).unwrap_or_default()
+    }
+}
+
+impl<'a> Drop for PdfFormSession<'a> {
+    /// Ends this [PdfFormSession], committing the value of any focused form field.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FORM_ForceToKillFocus(self.form_handle);
+        self.bindings
+            .FORM_OnBeforeClosePage(self.page_handle, self.form_handle);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_form_session_edits_text_field_with_undo() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/form-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let index = page
+            .annotations()
+            .iter()
+            .position(|annotation| {
+                annotation
+                    .as_form_field()
+                    .and_then(|field| field.as_text_field())
+                    .is_some()
+            })
+            .unwrap();
+
+        let mut session = page.form_session()?;
+
+        let outcome = session.set_focused_annotation_index(index)?;
+
+        assert!(outcome.is_focus_changed());
+        assert_eq!(outcome.focused_annotation_index(), Some(index));
+
+        let outcome = session.select_all_text();
+
+        assert_eq!(outcome.selected_text(), outcome.focused_text());
+
+        let outcome = session.replace_selection("pdfium-render");
+
+        assert!(outcome.is_text_changed());
+        assert_eq!(session.focused_text(), "pdfium-render");
+        assert!(session.can_undo());
+
+        session.undo();
+
+        assert_ne!(session.focused_text(), "pdfium-render");
+        assert!(session.can_redo());
+
+        session.redo();
+
+        assert_eq!(session.focused_text(), "pdfium-render");
+
+        let outcome = session.kill_focus();
+
+        assert!(outcome.is_focus_changed());
+        assert_eq!(outcome.focused_annotation_index(), None);
+
+        Ok(())
+    }
+}
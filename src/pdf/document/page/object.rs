@@ -2,6 +2,8 @@
 
 pub(crate) mod group;
 pub(crate) mod image;
+pub(crate) mod mark;
+pub(crate) mod marks;
 pub(crate) mod path;
 pub(crate) mod private; // Keep private so that the PdfPageObjectPrivate trait is not exposed.
 pub(crate) mod shading;
@@ -20,6 +22,7 @@ use crate::error::PdfiumError;
 use crate::pdf::color::PdfColor;
 use crate::pdf::document::page::annotation::objects::PdfPageAnnotationObjects;
 use crate::pdf::document::page::object::image::PdfPageImageObject;
+use crate::pdf::document::page::object::marks::PdfPageObjectMarks;
 use crate::pdf::document::page::object::path::PdfPagePathObject;
 use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
 use crate::pdf::document::page::object::shading::PdfPageShadingObject;
@@ -307,6 +310,7 @@ impl<'a> PdfPageObject<'a> {
     // We accommodate both possibilities.
     pub(crate) fn from_pdfium(
         object_handle: FPDF_PAGEOBJECT,
+        document_handle: FPDF_DOCUMENT,
         page_handle: Option<FPDF_PAGE>,
         annotation_handle: Option<FPDF_ANNOTATION>,
         bindings: &'a dyn PdfiumLibraryBindings,
@@ -317,6 +321,7 @@ impl<'a> PdfPageObject<'a> {
             PdfPageObjectType::Unsupported => {
                 PdfPageObject::Unsupported(PdfPageUnsupportedObject::from_pdfium(
                     object_handle,
+                    document_handle,
                     page_handle,
                     annotation_handle,
                     bindings,
@@ -324,18 +329,21 @@ impl<'a> PdfPageObject<'a> {
             }
             PdfPageObjectType::Text => PdfPageObject::Text(PdfPageTextObject::from_pdfium(
                 object_handle,
+                document_handle,
                 page_handle,
                 annotation_handle,
                 bindings,
             )),
             PdfPageObjectType::Path => PdfPageObject::Path(PdfPagePathObject::from_pdfium(
                 object_handle,
+                document_handle,
                 page_handle,
                 annotation_handle,
                 bindings,
             )),
             PdfPageObjectType::Image => PdfPageObject::Image(PdfPageImageObject::from_pdfium(
                 object_handle,
+                document_handle,
                 page_handle,
                 annotation_handle,
                 bindings,
@@ -343,6 +351,7 @@ impl<'a> PdfPageObject<'a> {
             PdfPageObjectType::Shading => {
                 PdfPageObject::Shading(PdfPageShadingObject::from_pdfium(
                     object_handle,
+                    document_handle,
                     page_handle,
                     annotation_handle,
                     bindings,
@@ -351,6 +360,7 @@ impl<'a> PdfPageObject<'a> {
             PdfPageObjectType::XObjectForm => {
                 PdfPageObject::XObjectForm(PdfPageXObjectFormObject::from_pdfium(
                     object_handle,
+                    document_handle,
                     page_handle,
                     annotation_handle,
                     bindings,
@@ -547,6 +557,20 @@ pub trait PdfPageObjectCommon<'a> {
     /// Returns `true` if this [PdfPageObject] contains transparency.
     fn has_transparency(&self) -> bool;
 
+    /// Returns an immutable collection of the content marks attached to this [PdfPageObject].
+    ///
+    /// Content marks flag page objects as artifacts, and link page objects to the elements
+    /// of a tagged document's structure tree by way of a marked content identifier ("MCID").
+    fn marks(&self) -> &PdfPageObjectMarks<'a>;
+
+    /// Returns a mutable collection of the content marks attached to this [PdfPageObject].
+    fn marks_mut(&mut self) -> &mut PdfPageObjectMarks<'a>;
+
+    /// Returns the marked content identifier ("MCID") of this [PdfPageObject], if any.
+    /// The marked content identifier links this [PdfPageObject] to a `PdfStructElement`
+    /// in the document's structure tree.
+    fn marked_content_id(&self) -> Option<u32>;
+
     /// Returns the bounding box of this [PdfPageObject].
     ///
     /// For text objects, the bottom of the bounding box is set to the font baseline. Any characters
@@ -764,6 +788,58 @@ where
         self.has_transparency_impl()
     }
 
+    #[inline]
+    fn marks(&self) -> &PdfPageObjectMarks<'a> {
+        self.marks_impl()
+    }
+
+    #[inline]
+    fn marks_mut(&mut self) -> &mut PdfPageObjectMarks<'a> {
+        self.marks_mut_impl()
+    }
+
+    #[cfg(any(
+        feature = "pdfium_6611",
+        feature = "pdfium_6666",
+        feature = "pdfium_future"
+    ))]
+    #[inline]
+    fn marked_content_id(&self) -> Option<u32> {
+        let result = self
+            .bindings()
+            .FPDFPageObj_GetMarkedContentID(self.get_object_handle());
+
+        if result < 0 {
+            None
+        } else {
+            Some(result as u32)
+        }
+    }
+
+    #[cfg(any(
+        feature = "pdfium_5961",
+        feature = "pdfium_6015",
+        feature = "pdfium_6043",
+        feature = "pdfium_6084",
+        feature = "pdfium_6110",
+        feature = "pdfium_6124",
+        feature = "pdfium_6164",
+        feature = "pdfium_6259",
+        feature = "pdfium_6295",
+        feature = "pdfium_6337",
+        feature = "pdfium_6406",
+        feature = "pdfium_6490",
+        feature = "pdfium_6555",
+        feature = "pdfium_6569"
+    ))]
+    #[inline]
+    fn marked_content_id(&self) -> Option<u32> {
+        // FPDFPageObj_GetMarkedContentID() is not available in this version of Pdfium,
+        // so we retrieve the marked content identifier from the object's content marks directly.
+
+        self.marks().marked_content_id()
+    }
+
     #[inline]
     fn bounds(&self) -> Result<PdfRect, PdfiumError> {
         self.bounds_impl()
@@ -1080,6 +1156,16 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPageObject<'a> {
         self.unwrap_as_trait().bindings()
     }
 
+    #[inline]
+    fn marks_impl(&self) -> &PdfPageObjectMarks<'a> {
+        self.unwrap_as_trait().marks_impl()
+    }
+
+    #[inline]
+    fn marks_mut_impl(&mut self) -> &mut PdfPageObjectMarks<'a> {
+        self.unwrap_as_trait_mut().marks_mut_impl()
+    }
+
     #[inline]
     fn is_object_memory_owned_by_container(&self) -> bool {
         self.unwrap_as_trait().is_object_memory_owned_by_container()
@@ -1269,4 +1355,56 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_marks() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut object = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        assert!(object.marks().is_empty());
+        assert!(!object.marks().is_artifact());
+
+        let mut mark = object.marks_mut().add("Span")?;
+
+        mark.set_marked_content_id(7)?;
+        mark.set_param(
+            "Lang",
+            PdfPageObjectMarkParamValue::String("en-US".to_string()),
+        )?;
+        mark.set_blob_param("Data", &[0xFE, 0x00, 0x81])?;
+
+        object.marks_mut().add_artifact()?;
+
+        assert_eq!(object.marks().len(), 2);
+        assert!(object.marks().is_artifact());
+        assert_eq!(object.marks().marked_content_id(), Some(7));
+
+        let mark = object.marks().find("Span").unwrap();
+
+        assert_eq!(mark.params_len(), 3);
+        assert_eq!(
+            mark.param("Lang"),
+            Some(PdfPageObjectMarkParamValue::String("en-US".to_string()))
+        );
+        assert_eq!(mark.blob_param("Data"), Some(vec![0xFE, 0x00, 0x81]));
+
+        object.marks_mut().remove(0)?;
+
+        assert_eq!(object.marks().len(), 1);
+        assert_eq!(object.marks().marked_content_id(), None);
+
+        Ok(())
+    }
 }
@@ -238,6 +238,7 @@ impl<'a> PdfPageGroupObject<'a> {
         for index in 0..self.bindings.FPDFPage_CountObjects(self.page_handle) {
             let mut object = PdfPageObject::from_pdfium(
                 self.bindings.FPDFPage_GetObject(self.page_handle, index),
+                self.document_handle,
                 Some(self.page_handle),
                 None,
                 self.bindings,
@@ -461,6 +462,7 @@ impl<'a> PdfPageGroupObject<'a> {
         for index in 0..self.bindings.FPDFPage_CountObjects(self.page_handle) {
             let object = PdfPageObject::from_pdfium(
                 self.bindings.FPDFPage_GetObject(self.page_handle, index),
+                self.document_handle,
                 Some(self.page_handle),
                 None,
                 self.bindings,
@@ -534,8 +536,14 @@ impl<'a> PdfPageGroupObject<'a> {
     #[inline]
     pub fn has_transparency(&self) -> bool {
         self.object_handles.iter().any(|object_handle| {
-            PdfPageObject::from_pdfium(*object_handle, Some(self.page_handle), None, self.bindings)
-                .has_transparency()
+            PdfPageObject::from_pdfium(
+                *object_handle,
+                self.document_handle,
+                Some(self.page_handle),
+                None,
+                self.bindings,
+            )
+            .has_transparency()
         })
     }
 
@@ -547,6 +555,7 @@ impl<'a> PdfPageGroupObject<'a> {
         self.object_handles.iter().for_each(|object_handle| {
             if let Ok(object_bounds) = PdfPageObject::from_pdfium(
                 *object_handle,
+                self.document_handle,
                 Some(self.page_handle),
                 None,
                 self.bindings,
@@ -685,7 +694,13 @@ impl<'a> PdfPageGroupObject<'a> {
     /// Inflates an internal `FPDF_PAGEOBJECT` handle into a [PdfPageObject].
     #[inline]
     pub(crate) fn get_object_from_handle(&self, handle: &FPDF_PAGEOBJECT) -> PdfPageObject<'a> {
-        PdfPageObject::from_pdfium(*handle, Some(self.page_handle), None, self.bindings)
+        PdfPageObject::from_pdfium(
+            *handle,
+            self.document_handle,
+            Some(self.page_handle),
+            None,
+            self.bindings,
+        )
     }
 
     create_transform_setters!(
@@ -10,6 +10,7 @@ use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::bitmap::PdfBitmap;
 use crate::pdf::bitmap::Pixels;
 use crate::pdf::color_space::PdfColorSpace;
+use crate::pdf::document::page::object::marks::PdfPageObjectMarks;
 use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
 use crate::pdf::document::page::object::PdfPageObject;
 use crate::pdf::document::PdfDocument;
@@ -46,6 +47,7 @@ use {
 /// be attached to a page by using the `PdfPageObjects::add_image_object()` function.
 pub struct PdfPageImageObject<'a> {
     object_handle: FPDF_PAGEOBJECT,
+    marks: PdfPageObjectMarks<'a>,
     page_handle: Option<FPDF_PAGE>,
     annotation_handle: Option<FPDF_ANNOTATION>,
     bindings: &'a dyn PdfiumLibraryBindings,
@@ -55,12 +57,14 @@ impl<'a> PdfPageImageObject<'a> {
     #[inline]
     pub(crate) fn from_pdfium(
         object_handle: FPDF_PAGEOBJECT,
+        document_handle: FPDF_DOCUMENT,
         page_handle: Option<FPDF_PAGE>,
         annotation_handle: Option<FPDF_ANNOTATION>,
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Self {
         PdfPageImageObject {
             object_handle,
+            marks: PdfPageObjectMarks::from_pdfium(object_handle, document_handle, bindings),
             page_handle,
             annotation_handle,
             bindings,
@@ -117,6 +121,7 @@ impl<'a> PdfPageImageObject<'a> {
         } else {
             Ok(PdfPageImageObject {
                 object_handle: handle,
+                marks: PdfPageObjectMarks::from_pdfium(handle, document, bindings),
                 page_handle: None,
                 annotation_handle: None,
                 bindings,
@@ -719,6 +724,16 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPageImageObject<'a> {
         self.bindings
     }
 
+    #[inline]
+    fn marks_impl(&self) -> &PdfPageObjectMarks<'a> {
+        &self.marks
+    }
+
+    #[inline]
+    fn marks_mut_impl(&mut self) -> &mut PdfPageObjectMarks<'a> {
+        &mut self.marks
+    }
+
     #[inline]
     fn is_copyable_impl(&self) -> bool {
         // Image filters cannot be copied.
@@ -0,0 +1,300 @@
+//! Defines the [PdfPageObjectMark] struct, exposing functionality related to a single
+//! content mark attached to a page object.
+
+use crate::bindgen::{
+    FPDF_DOCUMENT, FPDF_OBJECT_NUMBER, FPDF_OBJECT_STRING, FPDF_PAGEOBJECT, FPDF_PAGEOBJECTMARK,
+};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::utils::mem::get_pdfium_byte_buffer;
+use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use std::convert::TryInto;
+use std::os::raw::{c_int, c_ulong, c_void};
+
+#[cfg(doc)]
+use crate::pdf::document::page::object::PdfPageObject;
+
+/// The name of the content mark used to flag page content as an artifact, i.e. content
+/// that is not part of the logical structure of the document, such as page numbers,
+/// running headers and footers, or decorative rules.
+pub const PDF_PAGE_OBJECT_MARK_ARTIFACT: &str = "Artifact";
+
+/// The key of the content mark parameter holding the marked content identifier ("MCID")
+/// linking marked content to an element in the document's structure tree.
+pub const PDF_PAGE_OBJECT_MARK_MCID: &str = "MCID";
+
+/// The value of a single parameter in a [PdfPageObjectMark].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PdfPageObjectMarkParamValue {
+    Int(i32),
+    String(String),
+    Blob(Vec<u8>),
+}
+
+/// A single content mark attached to a [PdfPageObject].
+///
+/// Content marks associate page objects with a tag and an optional dictionary of parameters.
+/// They are used to flag content as an artifact, to attach optional content groups,
+/// and to link page content to the elements of a tagged document's structure tree by way of
+/// a marked content identifier ("MCID").
+pub struct PdfPageObjectMark<'a> {
+    mark_handle: FPDF_PAGEOBJECTMARK,
+    object_handle: FPDF_PAGEOBJECT,
+    document_handle: FPDF_DOCUMENT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageObjectMark<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        mark_handle: FPDF_PAGEOBJECTMARK,
+        object_handle: FPDF_PAGEOBJECT,
+        document_handle: FPDF_DOCUMENT,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPageObjectMark {
+            mark_handle,
+            object_handle,
+            document_handle,
+            bindings,
+        }
+    }
+
+    /// Returns the internal `FPDF_PAGEOBJECTMARK` handle for this [PdfPageObjectMark].
+    #[inline]
+    pub(crate) fn mark_handle(&self) -> FPDF_PAGEOBJECTMARK {
+        self.mark_handle
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfPageObjectMark].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the name (tag) of this [PdfPageObjectMark], if any.
+    pub fn name(&self) -> Option<String> {
+        get_pdfium_byte_buffer(|buffer, length, out_length| {
+            self.bindings
+                .FPDFPageObjMark_GetName(self.mark_handle, buffer, length, out_length)
+        })
+        .and_then(get_string_from_pdfium_utf16le_bytes)
+    }
+
+    /// Returns `true` if this [PdfPageObjectMark] flags its page object as an artifact.
+    #[inline]
+    pub fn is_artifact(&self) -> bool {
+        self.name().as_deref() == Some(PDF_PAGE_OBJECT_MARK_ARTIFACT)
+    }
+
+    /// Returns the marked content identifier ("MCID") stored in this [PdfPageObjectMark],
+    /// if any. The marked content identifier links the content to a `PdfStructElement`
+    /// in the document's structure tree.
+    pub fn marked_content_id(&self) -> Option<u32> {
+        match self.param(PDF_PAGE_OBJECT_MARK_MCID) {
+            Some(PdfPageObjectMarkParamValue::Int(value)) => value.try_into().ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of parameters in this [PdfPageObjectMark].
+    #[inline]
+    pub fn params_len(&self) -> usize {
+        self.bindings
+            .FPDFPageObjMark_CountParams(self.mark_handle)
+            .max(0) as usize
+    }
+
+    /// Returns the key of the parameter at the given index in this [PdfPageObjectMark].
+    pub fn param_key(&self, index: usize) -> Option<String> {
+        get_pdfium_byte_buffer(|buffer, length, out_length| {
+            self.bindings.FPDFPageObjMark_GetParamKey(
+                self.mark_handle,
+                index as c_ulong,
+                buffer,
+                length,
+                out_length,
+            )
+        })
+        .and_then(get_string_from_pdfium_utf16le_bytes)
+    }
+
+    /// Returns the keys of all the parameters in this [PdfPageObjectMark].
+    pub fn param_keys(&self) -> Vec<String> {
+        (0..self.params_len())
+            .filter_map(|index| self.param_key(index))
+            .collect()
+    }
+
+    /// Returns the value of the parameter with the given key in this [PdfPageObjectMark], if any.
+    ///
+    /// Pdfium only supports retrieving integer, string, and blob parameter values;
+    /// `None` will be returned for parameters of any other type. Pdfium does not distinguish
+    /// between text and binary string values, so a blob value will only be returned for
+    /// string values that cannot be decoded as text.
+    pub fn param(&self, key: &str) -> Option<PdfPageObjectMarkParamValue> {
+        match self
+            .bindings
+            .FPDFPageObjMark_GetParamValueType(self.mark_handle, key) as u32
+        {
+            FPDF_OBJECT_NUMBER => {
+                let mut value: c_int = 0;
+
+                if self
+                    .bindings
+                    .is_true(self.bindings.FPDFPageObjMark_GetParamIntValue(
+                        self.mark_handle,
+                        key,
+                        &mut value,
+                    ))
+                {
+                    Some(PdfPageObjectMarkParamValue::Int(value))
+                } else {
+                    None
+                }
+            }
+            FPDF_OBJECT_STRING => {
+                // Pdfium reports both text strings and binary strings as FPDF_OBJECT_STRING.
+                // We first attempt to decode the value as text, falling back to
+                // raw bytes if decoding fails.
+
+                let text = get_pdfium_byte_buffer(|buffer, length, out_length| {
+                    self.bindings.FPDFPageObjMark_GetParamStringValue(
+                        self.mark_handle,
+                        key,
+                        buffer,
+                        length,
+                        out_length,
+                    )
+                })
+                .and_then(get_string_from_pdfium_utf16le_bytes);
+
+                match text {
+                    Some(text) => Some(PdfPageObjectMarkParamValue::String(text)),
+                    None => get_pdfium_byte_buffer(|buffer, length, out_length| {
+                        self.bindings.FPDFPageObjMark_GetParamBlobValue(
+                            self.mark_handle,
+                            key,
+                            buffer,
+                            length,
+                            out_length,
+                        )
+                    })
+                    .map(PdfPageObjectMarkParamValue::Blob),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the raw bytes of the string parameter with the given key in this
+    /// [PdfPageObjectMark], if any, without attempting to decode them as text.
+    pub fn blob_param(&self, key: &str) -> Option<Vec<u8>> {
+        if self
+            .bindings
+            .FPDFPageObjMark_GetParamValueType(self.mark_handle, key) as u32
+            != FPDF_OBJECT_STRING
+        {
+            return None;
+        }
+
+        get_pdfium_byte_buffer(|buffer, length, out_length| {
+            self.bindings.FPDFPageObjMark_GetParamBlobValue(
+                self.mark_handle,
+                key,
+                buffer,
+                length,
+                out_length,
+            )
+        })
+    }
+
+    /// Returns all the parameters in this [PdfPageObjectMark], along with their values.
+    pub fn params(&self) -> Vec<(String, PdfPageObjectMarkParamValue)> {
+        self.param_keys()
+            .into_iter()
+            .filter_map(|key| self.param(key.as_str()).map(|value| (key, value)))
+            .collect()
+    }
+
+    /// Sets the parameter with the given key in this [PdfPageObjectMark] to the given value,
+    /// adding a new parameter if no parameter with the given key exists.
+    pub fn set_param(
+        &mut self,
+        key: &str,
+        value: PdfPageObjectMarkParamValue,
+    ) -> Result<(), PdfiumError> {
+        let result = match value {
+            PdfPageObjectMarkParamValue::Int(value) => self.bindings.FPDFPageObjMark_SetIntParam(
+                self.document_handle,
+                self.object_handle,
+                self.mark_handle,
+                key,
+                value,
+            ),
+            PdfPageObjectMarkParamValue::String(value) => {
+                self.bindings.FPDFPageObjMark_SetStringParam(
+                    self.document_handle,
+                    self.object_handle,
+                    self.mark_handle,
+                    key,
+                    value.as_str(),
+                )
+            }
+            PdfPageObjectMarkParamValue::Blob(mut value) => {
+                self.bindings.FPDFPageObjMark_SetBlobParam(
+                    self.document_handle,
+                    self.object_handle,
+                    self.mark_handle,
+                    key,
+                    value.as_mut_ptr() as *mut c_void,
+                    value.len() as c_ulong,
+                )
+            }
+        };
+
+        if self.bindings.is_true(result) {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Sets the parameter with the given key in this [PdfPageObjectMark] to the given bytes,
+    /// stored as a binary string, adding a new parameter if no parameter with the given
+    /// key exists.
+    #[inline]
+    pub fn set_blob_param(&mut self, key: &str, value: &[u8]) -> Result<(), PdfiumError> {
+        self.set_param(key, PdfPageObjectMarkParamValue::Blob(value.to_vec()))
+    }
+
+    /// Sets the marked content identifier ("MCID") stored in this [PdfPageObjectMark],
+    /// linking the content to an element in the document's structure tree.
+    #[inline]
+    pub fn set_marked_content_id(&mut self, marked_content_id: u32) -> Result<(), PdfiumError> {
+        self.set_param(
+            PDF_PAGE_OBJECT_MARK_MCID,
+            PdfPageObjectMarkParamValue::Int(marked_content_id as i32),
+        )
+    }
+
+    /// Removes the parameter with the given key from this [PdfPageObjectMark].
+    pub fn remove_param(&mut self, key: &str) -> Result<(), PdfiumError> {
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFPageObjMark_RemoveParam(
+                self.object_handle,
+                self.mark_handle,
+                key,
+            ))
+        {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+}
@@ -0,0 +1,209 @@
+//! Defines the [PdfPageObjectMarks] struct, a collection of all the content marks
+//! attached to a single page object.
+
+use crate::bindgen::{FPDF_DOCUMENT, FPDF_PAGEOBJECT};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::page::object::mark::{PdfPageObjectMark, PDF_PAGE_OBJECT_MARK_ARTIFACT};
+use std::ops::{Range, RangeInclusive};
+use std::os::raw::c_ulong;
+
+#[cfg(doc)]
+use crate::pdf::document::page::object::PdfPageObject;
+
+pub type PdfPageObjectMarkIndex = usize;
+
+/// The collection of [PdfPageObjectMark] content marks attached to a single [PdfPageObject].
+pub struct PdfPageObjectMarks<'a> {
+    object_handle: FPDF_PAGEOBJECT,
+    document_handle: FPDF_DOCUMENT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageObjectMarks<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        object_handle: FPDF_PAGEOBJECT,
+        document_handle: FPDF_DOCUMENT,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPageObjectMarks {
+            object_handle,
+            document_handle,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfPageObjectMarks] collection.
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the number of content marks in this [PdfPageObjectMarks] collection.
+    #[inline]
+    pub fn len(&self) -> PdfPageObjectMarkIndex {
+        self.bindings
+            .FPDFPageObj_CountMarks(self.object_handle)
+            .max(0) as PdfPageObjectMarkIndex
+    }
+
+    /// Returns `true` if this [PdfPageObjectMarks] collection is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a Range from `0..(number of content marks)` for this [PdfPageObjectMarks] collection.
+    #[inline]
+    pub fn as_range(&self) -> Range<PdfPageObjectMarkIndex> {
+        0..self.len()
+    }
+
+    /// Returns an inclusive Range from `0..=(number of content marks - 1)`
+    /// for this [PdfPageObjectMarks] collection.
+    #[inline]
+    pub fn as_range_inclusive(&self) -> RangeInclusive<PdfPageObjectMarkIndex> {
+        if self.is_empty() {
+            0..=0
+        } else {
+            0..=(self.len() - 1)
+        }
+    }
+
+    /// Returns a single [PdfPageObjectMark] from this [PdfPageObjectMarks] collection.
+    pub fn get(&self, index: PdfPageObjectMarkIndex) -> Result<PdfPageObjectMark<'_>, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::PageObjectMarkIndexOutOfBounds);
+        }
+
+        let handle = self
+            .bindings
+            .FPDFPageObj_GetMark(self.object_handle, index as c_ulong);
+
+        if handle.is_null() {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        } else {
+            Ok(PdfPageObjectMark::from_pdfium(
+                handle,
+                self.object_handle,
+                self.document_handle,
+                self.bindings,
+            ))
+        }
+    }
+
+    /// Returns the first [PdfPageObjectMark] in this [PdfPageObjectMarks] collection
+    /// with the given name, if any.
+    pub fn find(&self, name: &str) -> Option<PdfPageObjectMark<'_>> {
+        self.as_range()
+            .filter_map(|index| self.get(index).ok())
+            .find(|mark| mark.name().as_deref() == Some(name))
+    }
+
+    /// Returns `true` if this [PdfPageObjectMarks] collection contains an `Artifact`
+    /// content mark, flagging the page object as content that is not part of the
+    /// logical structure of the document.
+    #[inline]
+    pub fn is_artifact(&self) -> bool {
+        self.iter().any(|mark| mark.is_artifact())
+    }
+
+    /// Returns the marked content identifier ("MCID") stored in the first content mark in this
+    /// [PdfPageObjectMarks] collection that carries one, if any.
+    #[inline]
+    pub fn marked_content_id(&self) -> Option<u32> {
+        self.iter().find_map(|mark| mark.marked_content_id())
+    }
+
+    /// Adds a new content mark with the given name to this [PdfPageObjectMarks] collection,
+    /// returning the newly created [PdfPageObjectMark]. Parameters can be added to the
+    /// new content mark by calling [PdfPageObjectMark::set_param()].
+    pub fn add(&mut self, name: &str) -> Result<PdfPageObjectMark<'_>, PdfiumError> {
+        let handle = self.bindings.FPDFPageObj_AddMark(self.object_handle, name);
+
+        if handle.is_null() {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        } else {
+            Ok(PdfPageObjectMark::from_pdfium(
+                handle,
+                self.object_handle,
+                self.document_handle,
+                self.bindings,
+            ))
+        }
+    }
+
+    /// Adds a new `Artifact` content mark to this [PdfPageObjectMarks] collection,
+    /// flagging the page object as content that is not part of the logical structure
+    /// of the document, such as a page number or a running header or footer.
+    #[inline]
+    pub fn add_artifact(&mut self) -> Result<PdfPageObjectMark<'_>, PdfiumError> {
+        self.add(PDF_PAGE_OBJECT_MARK_ARTIFACT)
+    }
+
+    /// Removes the [PdfPageObjectMark] at the given index from this [PdfPageObjectMarks]
+    /// collection.
+    ///
+    /// Content marks returned by this collection borrow it, so no [PdfPageObjectMark]
+    /// referring to the removed content mark can remain in use.
+    pub fn remove(&mut self, index: PdfPageObjectMarkIndex) -> Result<(), PdfiumError> {
+        let mark_handle = self.get(index)?.mark_handle();
+
+        if self.bindings.is_true(
+            self.bindings
+                .FPDFPageObj_RemoveMark(self.object_handle, mark_handle),
+        ) {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Returns an iterator over all the [PdfPageObjectMark] content marks in this
+    /// [PdfPageObjectMarks] collection.
+    #[inline]
+    pub fn iter(&self) -> PdfPageObjectMarksIterator<'_> {
+        PdfPageObjectMarksIterator::new(self)
+    }
+}
+
+/// An iterator over all the [PdfPageObjectMark] content marks in a [PdfPageObjectMarks] collection.
+pub struct PdfPageObjectMarksIterator<'a> {
+    marks: &'a PdfPageObjectMarks<'a>,
+    next_index: PdfPageObjectMarkIndex,
+}
+
+impl<'a> PdfPageObjectMarksIterator<'a> {
+    #[inline]
+    pub(crate) fn new(marks: &'a PdfPageObjectMarks<'a>) -> Self {
+        PdfPageObjectMarksIterator {
+            marks,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfPageObjectMarksIterator<'a> {
+    type Item = PdfPageObjectMark<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next_index < self.marks.len() {
+            let next = self.marks.get(self.next_index);
+
+            self.next_index += 1;
+
+            if let Ok(next) = next {
+                return Some(next);
+            }
+        }
+
+        None
+    }
+}
@@ -8,6 +8,7 @@ use crate::bindgen::{
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::color::PdfColor;
+use crate::pdf::document::page::object::marks::PdfPageObjectMarks;
 use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
 use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectCommon};
 use crate::pdf::document::PdfDocument;
@@ -143,6 +144,7 @@ impl Default for PdfPathFillMode {
 /// `PdfPageObjects::add_path_object()` function.
 pub struct PdfPagePathObject<'a> {
     object_handle: FPDF_PAGEOBJECT,
+    marks: PdfPageObjectMarks<'a>,
     page_handle: Option<FPDF_PAGE>,
     annotation_handle: Option<FPDF_ANNOTATION>,
     bindings: &'a dyn PdfiumLibraryBindings,
@@ -154,12 +156,14 @@ impl<'a> PdfPagePathObject<'a> {
     #[inline]
     pub(crate) fn from_pdfium(
         object_handle: FPDF_PAGEOBJECT,
+        document_handle: FPDF_DOCUMENT,
         page_handle: Option<FPDF_PAGE>,
         annotation_handle: Option<FPDF_ANNOTATION>,
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Self {
         PdfPagePathObject {
             object_handle,
+            marks: PdfPageObjectMarks::from_pdfium(object_handle, document_handle, bindings),
             page_handle,
             annotation_handle,
             bindings,
@@ -194,6 +198,7 @@ impl<'a> PdfPagePathObject<'a> {
         fill_color: Option<PdfColor>,
     ) -> Result<Self, PdfiumError> {
         Self::new_from_bindings(
+            document.handle(),
             document.bindings(),
             x,
             y,
@@ -204,6 +209,7 @@ impl<'a> PdfPagePathObject<'a> {
     }
 
     pub(crate) fn new_from_bindings(
+        document: FPDF_DOCUMENT,
         bindings: &'a dyn PdfiumLibraryBindings,
         x: PdfPoints,
         y: PdfPoints,
@@ -220,6 +226,7 @@ impl<'a> PdfPagePathObject<'a> {
         } else {
             let mut result = PdfPagePathObject {
                 object_handle: handle,
+                marks: PdfPageObjectMarks::from_pdfium(handle, document, bindings),
                 page_handle: None,
                 annotation_handle: None,
                 bindings,
@@ -257,7 +264,9 @@ impl<'a> PdfPagePathObject<'a> {
     }
 
     #[inline]
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new_line_from_bindings(
+        document: FPDF_DOCUMENT,
         bindings: &'a dyn PdfiumLibraryBindings,
         x1: PdfPoints,
         y1: PdfPoints,
@@ -267,6 +276,7 @@ impl<'a> PdfPagePathObject<'a> {
         stroke_width: PdfPoints,
     ) -> Result<Self, PdfiumError> {
         let mut result = Self::new_from_bindings(
+            document,
             bindings,
             x1,
             y1,
@@ -297,6 +307,7 @@ impl<'a> PdfPagePathObject<'a> {
         stroke_width: PdfPoints,
     ) -> Result<Self, PdfiumError> {
         Self::new_line_from_bindings(
+            document.handle(),
             document.bindings(),
             x1,
             y1,
@@ -310,6 +321,7 @@ impl<'a> PdfPagePathObject<'a> {
     #[allow(clippy::too_many_arguments)]
     #[inline]
     pub(crate) fn new_bezier_from_bindings(
+        document: FPDF_DOCUMENT,
         bindings: &'a dyn PdfiumLibraryBindings,
         x1: PdfPoints,
         y1: PdfPoints,
@@ -323,6 +335,7 @@ impl<'a> PdfPagePathObject<'a> {
         stroke_width: PdfPoints,
     ) -> Result<Self, PdfiumError> {
         let mut result = Self::new_from_bindings(
+            document,
             bindings,
             x1,
             y1,
@@ -358,6 +371,7 @@ impl<'a> PdfPagePathObject<'a> {
         stroke_width: PdfPoints,
     ) -> Result<Self, PdfiumError> {
         Self::new_bezier_from_bindings(
+            document.handle(),
             document.bindings(),
             x1,
             y1,
@@ -374,6 +388,7 @@ impl<'a> PdfPagePathObject<'a> {
 
     #[inline]
     pub(crate) fn new_rect_from_bindings(
+        document: FPDF_DOCUMENT,
         bindings: &'a dyn PdfiumLibraryBindings,
         rect: PdfRect,
         stroke_color: Option<PdfColor>,
@@ -381,6 +396,7 @@ impl<'a> PdfPagePathObject<'a> {
         fill_color: Option<PdfColor>,
     ) -> Result<Self, PdfiumError> {
         let mut result = Self::new_from_bindings(
+            document,
             bindings,
             rect.left,
             rect.bottom,
@@ -410,6 +426,7 @@ impl<'a> PdfPagePathObject<'a> {
         fill_color: Option<PdfColor>,
     ) -> Result<Self, PdfiumError> {
         Self::new_rect_from_bindings(
+            document.handle(),
             document.bindings(),
             rect,
             stroke_color,
@@ -420,6 +437,7 @@ impl<'a> PdfPagePathObject<'a> {
 
     #[inline]
     pub(crate) fn new_circle_from_bindings(
+        document: FPDF_DOCUMENT,
         bindings: &'a dyn PdfiumLibraryBindings,
         rect: PdfRect,
         stroke_color: Option<PdfColor>,
@@ -427,6 +445,7 @@ impl<'a> PdfPagePathObject<'a> {
         fill_color: Option<PdfColor>,
     ) -> Result<Self, PdfiumError> {
         let mut result = Self::new_from_bindings(
+            document,
             bindings,
             rect.left,
             rect.bottom,
@@ -456,6 +475,7 @@ impl<'a> PdfPagePathObject<'a> {
         fill_color: Option<PdfColor>,
     ) -> Result<Self, PdfiumError> {
         Self::new_circle_from_bindings(
+            document.handle(),
             document.bindings(),
             rect,
             stroke_color,
@@ -465,7 +485,9 @@ impl<'a> PdfPagePathObject<'a> {
     }
 
     #[inline]
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new_circle_at_from_bindings(
+        document: FPDF_DOCUMENT,
         bindings: &'a dyn PdfiumLibraryBindings,
         center_x: PdfPoints,
         center_y: PdfPoints,
@@ -475,6 +497,7 @@ impl<'a> PdfPagePathObject<'a> {
         fill_color: Option<PdfColor>,
     ) -> Result<Self, PdfiumError> {
         Self::new_circle_from_bindings(
+            document,
             bindings,
             PdfRect::new(
                 center_y - radius,
@@ -506,6 +529,7 @@ impl<'a> PdfPagePathObject<'a> {
         fill_color: Option<PdfColor>,
     ) -> Result<Self, PdfiumError> {
         Self::new_circle_at_from_bindings(
+            document.handle(),
             document.bindings(),
             center_x,
             center_y,
@@ -518,6 +542,7 @@ impl<'a> PdfPagePathObject<'a> {
 
     #[inline]
     pub(crate) fn new_ellipse_from_bindings(
+        document: FPDF_DOCUMENT,
         bindings: &'a dyn PdfiumLibraryBindings,
         rect: PdfRect,
         stroke_color: Option<PdfColor>,
@@ -525,6 +550,7 @@ impl<'a> PdfPagePathObject<'a> {
         fill_color: Option<PdfColor>,
     ) -> Result<Self, PdfiumError> {
         let mut result = Self::new_from_bindings(
+            document,
             bindings,
             rect.left,
             rect.bottom,
@@ -554,6 +580,7 @@ impl<'a> PdfPagePathObject<'a> {
         fill_color: Option<PdfColor>,
     ) -> Result<Self, PdfiumError> {
         Self::new_ellipse_from_bindings(
+            document.handle(),
             document.bindings(),
             rect,
             stroke_color,
@@ -565,6 +592,7 @@ impl<'a> PdfPagePathObject<'a> {
     #[allow(clippy::too_many_arguments)]
     #[inline]
     pub(crate) fn new_ellipse_at_from_bindings(
+        document: FPDF_DOCUMENT,
         bindings: &'a dyn PdfiumLibraryBindings,
         center_x: PdfPoints,
         center_y: PdfPoints,
@@ -575,6 +603,7 @@ impl<'a> PdfPagePathObject<'a> {
         fill_color: Option<PdfColor>,
     ) -> Result<Self, PdfiumError> {
         Self::new_ellipse_from_bindings(
+            document,
             bindings,
             PdfRect::new(
                 center_y - y_radius,
@@ -608,6 +637,7 @@ impl<'a> PdfPagePathObject<'a> {
         fill_color: Option<PdfColor>,
     ) -> Result<Self, PdfiumError> {
         Self::new_ellipse_at_from_bindings(
+            document.handle(),
             document.bindings(),
             center_x,
             center_y,
@@ -938,6 +968,16 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPagePathObject<'a> {
         self.bindings
     }
 
+    #[inline]
+    fn marks_impl(&self) -> &PdfPageObjectMarks<'a> {
+        &self.marks
+    }
+
+    #[inline]
+    fn marks_mut_impl(&mut self) -> &mut PdfPageObjectMarks<'a> {
+        &mut self.marks
+    }
+
     #[inline]
     fn is_copyable_impl(&self) -> bool {
         // The path object can only be copied if it contains no Bézier path segments.
@@ -952,10 +992,11 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPagePathObject<'a> {
 
     fn try_copy_impl<'b>(
         &self,
-        _: FPDF_DOCUMENT,
+        document: FPDF_DOCUMENT,
         bindings: &'b dyn PdfiumLibraryBindings,
     ) -> Result<PdfPageObject<'b>, PdfiumError> {
         let mut copy = PdfPagePathObject::new_from_bindings(
+            document,
             bindings,
             PdfPoints::ZERO,
             PdfPoints::ZERO,
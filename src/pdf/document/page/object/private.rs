@@ -13,6 +13,7 @@ pub(crate) mod internal {
     use crate::bindings::PdfiumLibraryBindings;
     use crate::error::{PdfiumError, PdfiumInternalError};
     use crate::pdf::document::page::annotation::objects::PdfPageAnnotationObjects;
+    use crate::pdf::document::page::object::marks::PdfPageObjectMarks;
     use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectCommon};
     use crate::pdf::document::page::objects::PdfPageObjects;
     use crate::pdf::matrix::{PdfMatrix, PdfMatrixValue};
@@ -49,6 +50,12 @@ pub(crate) mod internal {
         /// Returns the [PdfiumLibraryBindings] used by this [PdfPageObject].
         fn bindings(&self) -> &dyn PdfiumLibraryBindings;
 
+        /// Internal implementation of [PdfPageObjectCommon::marks()].
+        fn marks_impl(&self) -> &PdfPageObjectMarks<'a>;
+
+        /// Internal implementation of [PdfPageObjectCommon::marks_mut()].
+        fn marks_mut_impl(&mut self) -> &mut PdfPageObjectMarks<'a>;
+
         /// Returns `true` if the memory allocated to this [PdfPageObject] is owned by either
         /// a containing [PdfPage] or a containing [PdfPageAnnotation].
         ///
@@ -4,11 +4,13 @@
 use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE, FPDF_PAGEOBJECT};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
+use crate::pdf::document::page::object::marks::PdfPageObjectMarks;
 use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
 use crate::pdf::document::page::object::PdfPageObject;
 
 pub struct PdfPageShadingObject<'a> {
     object_handle: FPDF_PAGEOBJECT,
+    marks: PdfPageObjectMarks<'a>,
     page_handle: Option<FPDF_PAGE>,
     annotation_handle: Option<FPDF_ANNOTATION>,
     bindings: &'a dyn PdfiumLibraryBindings,
@@ -17,12 +19,14 @@ pub struct PdfPageShadingObject<'a> {
 impl<'a> PdfPageShadingObject<'a> {
     pub(crate) fn from_pdfium(
         object_handle: FPDF_PAGEOBJECT,
+        document_handle: FPDF_DOCUMENT,
         page_handle: Option<FPDF_PAGE>,
         annotation_handle: Option<FPDF_ANNOTATION>,
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Self {
         PdfPageShadingObject {
             object_handle,
+            marks: PdfPageObjectMarks::from_pdfium(object_handle, document_handle, bindings),
             page_handle,
             annotation_handle,
             bindings,
@@ -71,6 +75,16 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPageShadingObject<'a> {
         self.bindings
     }
 
+    #[inline]
+    fn marks_impl(&self) -> &PdfPageObjectMarks<'a> {
+        &self.marks
+    }
+
+    #[inline]
+    fn marks_mut_impl(&mut self) -> &mut PdfPageObjectMarks<'a> {
+        &mut self.marks
+    }
+
     #[inline]
     fn is_copyable_impl(&self) -> bool {
         false
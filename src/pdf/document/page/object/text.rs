@@ -15,6 +15,7 @@ use crate::bindgen::{
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::document::fonts::ToPdfFontToken;
+use crate::pdf::document::page::object::marks::PdfPageObjectMarks;
 use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
 use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectCommon};
 use crate::pdf::document::page::text::chars::PdfPageTextChars;
@@ -140,6 +141,7 @@ impl PdfPageTextRenderMode {
 /// be attached to a page by using the `PdfPageObjects::add_text_object()` function.
 pub struct PdfPageTextObject<'a> {
     object_handle: FPDF_PAGEOBJECT,
+    marks: PdfPageObjectMarks<'a>,
     page_handle: Option<FPDF_PAGE>,
     annotation_handle: Option<FPDF_ANNOTATION>,
     bindings: &'a dyn PdfiumLibraryBindings,
@@ -149,12 +151,14 @@ impl<'a> PdfPageTextObject<'a> {
     #[inline]
     pub(crate) fn from_pdfium(
         object_handle: FPDF_PAGEOBJECT,
+        document_handle: FPDF_DOCUMENT,
         page_handle: Option<FPDF_PAGE>,
         annotation_handle: Option<FPDF_ANNOTATION>,
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Self {
         PdfPageTextObject {
             object_handle,
+            marks: PdfPageObjectMarks::from_pdfium(object_handle, document_handle, bindings),
             page_handle,
             annotation_handle,
             bindings,
@@ -205,6 +209,7 @@ impl<'a> PdfPageTextObject<'a> {
         } else {
             let mut result = PdfPageTextObject {
                 object_handle: handle,
+                marks: PdfPageObjectMarks::from_pdfium(handle, document, bindings),
                 page_handle: None,
                 annotation_handle: None,
                 bindings,
@@ -477,6 +482,16 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPageTextObject<'a> {
         self.bindings
     }
 
+    #[inline]
+    fn marks_impl(&self) -> &PdfPageObjectMarks<'a> {
+        &self.marks
+    }
+
+    #[inline]
+    fn marks_mut_impl(&mut self) -> &mut PdfPageObjectMarks<'a> {
+        &mut self.marks
+    }
+
     #[inline]
     fn is_copyable_impl(&self) -> bool {
         true
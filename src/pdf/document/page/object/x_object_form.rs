@@ -4,6 +4,7 @@
 use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE, FPDF_PAGEOBJECT};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::page::object::marks::PdfPageObjectMarks;
 use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
 use crate::pdf::document::page::object::PdfPageObject;
 use crate::pdf::document::page::objects::common::{PdfPageObjectIndex, PdfPageObjectsIterator};
@@ -20,6 +21,8 @@ use std::os::raw::c_ulong;
 /// to an interactive form containing form fields.
 pub struct PdfPageXObjectFormObject<'a> {
     object_handle: FPDF_PAGEOBJECT,
+    document_handle: FPDF_DOCUMENT,
+    marks: PdfPageObjectMarks<'a>,
     page_handle: Option<FPDF_PAGE>,
     annotation_handle: Option<FPDF_ANNOTATION>,
     bindings: &'a dyn PdfiumLibraryBindings,
@@ -28,12 +31,15 @@ pub struct PdfPageXObjectFormObject<'a> {
 impl<'a> PdfPageXObjectFormObject<'a> {
     pub(crate) fn from_pdfium(
         object_handle: FPDF_PAGEOBJECT,
+        document_handle: FPDF_DOCUMENT,
         page_handle: Option<FPDF_PAGE>,
         annotation_handle: Option<FPDF_ANNOTATION>,
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Self {
         PdfPageXObjectFormObject {
             object_handle,
+            document_handle,
+            marks: PdfPageObjectMarks::from_pdfium(object_handle, document_handle, bindings),
             page_handle,
             annotation_handle,
             bindings,
@@ -144,6 +150,16 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPageXObjectFormObject<'a> {
         self.bindings
     }
 
+    #[inline]
+    fn marks_impl(&self) -> &PdfPageObjectMarks<'a> {
+        &self.marks
+    }
+
+    #[inline]
+    fn marks_mut_impl(&mut self) -> &mut PdfPageObjectMarks<'a> {
+        &mut self.marks
+    }
+
     #[inline]
     fn is_copyable_impl(&self) -> bool {
         false
@@ -162,7 +178,7 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPageXObjectFormObject<'a> {
 impl<'a> PdfPageObjectsPrivate<'a> for PdfPageXObjectFormObject<'a> {
     #[inline]
     fn document_handle(&self) -> FPDF_DOCUMENT {
-        unimplemented!()
+        self.document_handle
     }
 
     #[inline]
@@ -191,6 +207,7 @@ impl<'a> PdfPageObjectsPrivate<'a> for PdfPageXObjectFormObject<'a> {
         } else {
             Ok(PdfPageObject::from_pdfium(
                 object_handle,
+                self.document_handle,
                 self.page_handle,
                 self.annotation_handle,
                 self.bindings,
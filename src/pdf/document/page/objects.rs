@@ -125,6 +125,7 @@ impl<'a> PdfPageObjectsPrivate<'a> for PdfPageObjects<'a> {
         } else {
             Ok(PdfPageObject::from_pdfium(
                 object_handle,
+                self.document_handle,
                 Some(self.page_handle),
                 None,
                 self.bindings,
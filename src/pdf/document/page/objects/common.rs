@@ -400,6 +400,7 @@ where
         stroke_width: PdfPoints,
     ) -> Result<PdfPageObject<'a>, PdfiumError> {
         let object = PdfPagePathObject::new_line_from_bindings(
+            self.document_handle(),
             self.bindings(),
             x1,
             y1,
@@ -427,6 +428,7 @@ where
         stroke_width: PdfPoints,
     ) -> Result<PdfPageObject<'a>, PdfiumError> {
         let object = PdfPagePathObject::new_bezier_from_bindings(
+            self.document_handle(),
             self.bindings(),
             x1,
             y1,
@@ -452,6 +454,7 @@ where
         fill_color: Option<PdfColor>,
     ) -> Result<PdfPageObject<'a>, PdfiumError> {
         let object = PdfPagePathObject::new_rect_from_bindings(
+            self.document_handle(),
             self.bindings(),
             rect,
             stroke_color,
@@ -471,6 +474,7 @@ where
         fill_color: Option<PdfColor>,
     ) -> Result<PdfPageObject<'a>, PdfiumError> {
         let object = PdfPagePathObject::new_circle_from_bindings(
+            self.document_handle(),
             self.bindings(),
             rect,
             stroke_color,
@@ -492,6 +496,7 @@ where
         fill_color: Option<PdfColor>,
     ) -> Result<PdfPageObject<'a>, PdfiumError> {
         let object = PdfPagePathObject::new_circle_at_from_bindings(
+            self.document_handle(),
             self.bindings(),
             center_x,
             center_y,
@@ -513,6 +518,7 @@ where
         fill_color: Option<PdfColor>,
     ) -> Result<PdfPageObject<'a>, PdfiumError> {
         let object = PdfPagePathObject::new_ellipse_from_bindings(
+            self.document_handle(),
             self.bindings(),
             rect,
             stroke_color,
@@ -535,6 +541,7 @@ where
         fill_color: Option<PdfColor>,
     ) -> Result<PdfPageObject<'a>, PdfiumError> {
         let object = PdfPagePathObject::new_ellipse_at_from_bindings(
+            self.document_handle(),
             self.bindings(),
             center_x,
             center_y,
@@ -0,0 +1,877 @@
+//! Applies the redaction annotations on a single `PdfPage`, permanently removing
+//! all page content lying underneath each redaction.
+
+use crate::bindgen::{
+    FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGEOBJECT, FPDF_PAGEOBJ_FORM, FPDF_PAGEOBJ_IMAGE,
+};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::appearance_mode::PdfAppearanceMode;
+use crate::pdf::bitmap::{PdfBitmap, PdfBitmapFormat};
+use crate::pdf::color::PdfColor;
+use crate::pdf::document::page::annotation::private::internal::PdfPageAnnotationPrivate;
+use crate::pdf::document::page::annotation::{PdfPageAnnotationCommon, PdfPageAnnotationType};
+use crate::pdf::document::page::annotations::PdfPageAnnotationIndex;
+use crate::pdf::document::page::object::image::PdfPageImageObject;
+use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
+use crate::pdf::document::page::object::text::{PdfPageTextObject, PdfPageTextRenderMode};
+use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectCommon};
+use crate::pdf::document::page::objects::common::{PdfPageObjectIndex, PdfPageObjectsCommon};
+use crate::pdf::document::page::text::PdfPageText;
+use crate::pdf::document::page::{PdfPage, PdfPageContentRegenerationStrategy};
+use crate::pdf::points::PdfPoints;
+use crate::pdf::rect::PdfRect;
+use crate::utils::mem::create_byte_buffer;
+use std::os::raw::{c_int, c_ulong, c_void};
+
+/// A single area of a page covered by a redaction, along with the color used to paint
+/// over the area once its content has been removed.
+struct PdfRedactedArea {
+    rect: PdfRect,
+    color: PdfColor,
+}
+
+/// A single character in a partially redacted text object that lies outside all redacted areas.
+struct PdfRetainedTextChar {
+    text: String,
+    x: PdfPoints,
+    y: PdfPoints,
+}
+
+/// The change to make to a single page object lying at least partially inside a redacted area.
+enum PdfPageObjectRedaction {
+    /// The page object is removed from the page.
+    Remove,
+
+    /// The pixels of the image object that lie inside any redacted area are overwritten.
+    ///
+    /// If the image data is shared with another image object, the image object is instead
+    /// replaced with a copy of itself with its pixels overwritten, so that the other image
+    /// object is unaffected.
+    OverwritePixels,
+
+    /// The image object is removed from the page after its image data has been replaced
+    /// with a single pixel of the given color.
+    ///
+    /// Removing an image object from a page does not remove the image data from the document;
+    /// the image XObject remains in the page's resources, and is written out when the document
+    /// is saved. Replacing the image data first ensures the redacted pixels do not survive.
+    /// If the image data is shared with another image object, the image data is left unchanged,
+    /// since it remains visible elsewhere in the document.
+    ClearImage(PdfColor),
+
+    /// The text object is replaced with new text objects for each of the given characters.
+    ReplaceText(Vec<PdfRetainedTextChar>),
+}
+
+/// Applies all redaction annotations on the given [PdfPage], returning the number of
+/// redaction annotations applied.
+pub(crate) fn apply_redactions(page: &mut PdfPage) -> Result<usize, PdfiumError> {
+    let strategy = page.content_regeneration_strategy();
+
+    // Stage all changes before regenerating the page content just once.
+
+    page.set_content_regeneration_strategy(PdfPageContentRegenerationStrategy::Manual);
+
+    let result = apply_redactions_to_page(page);
+
+    page.set_content_regeneration_strategy(strategy);
+
+    let count = result?;
+
+    if count > 0 {
+        page.regenerate_content()?;
+    }
+
+    Ok(count)
+}
+
+fn apply_redactions_to_page(page: &mut PdfPage) -> Result<usize, PdfiumError> {
+    let (areas, annotations_to_delete, count) = find_redacted_areas(page)?;
+
+    if count == 0 {
+        return Ok(0);
+    }
+
+    let redactions = find_redacted_objects(page, areas.as_slice())?;
+
+    // Work backwards through the page objects so that removing an object does not change
+    // the index of any object yet to be processed.
+
+    for (index, redaction) in redactions.into_iter().rev() {
+        let mut object = page.objects().get(index)?;
+
+        match redaction {
+            PdfPageObjectRedaction::Remove => {
+                page.objects_mut().remove_object(object)?;
+            }
+            PdfPageObjectRedaction::OverwritePixels => {
+                let copy = match object.as_image_object_mut() {
+                    Some(image) => {
+                        let bitmap = redact_pixels(image, areas.as_slice(), page.bindings())?;
+
+                        if is_image_shared(image, page.document_handle(), page.bindings()) {
+                            let mut copy = PdfPageImageObject::new_from_handle(
+                                page.document_handle(),
+                                page.bindings(),
+                            )?;
+
+                            let matrix = image.matrix()?;
+
+                            copy.set_bitmap(&bitmap)?;
+
+                            copy.transform(
+                                matrix.a(),
+                                matrix.b(),
+                                matrix.c(),
+                                matrix.d(),
+                                matrix.e(),
+                                matrix.f(),
+                            )?;
+
+                            Some(copy)
+                        } else {
+                            image.set_bitmap(&bitmap)?;
+
+                            None
+                        }
+                    }
+                    None => None,
+                };
+
+                if let Some(copy) = copy {
+                    page.objects_mut().add_image_object(copy)?;
+                    page.objects_mut().remove_object(object)?;
+                }
+            }
+            PdfPageObjectRedaction::ClearImage(color) => {
+                if let Some(image) = object.as_image_object_mut() {
+                    if !is_image_shared(image, page.document_handle(), page.bindings()) {
+                        clear_image(image, color, page.bindings())?;
+                    }
+                }
+
+                page.objects_mut().remove_object(object)?;
+            }
+            PdfPageObjectRedaction::ReplaceText(chars) => {
+                if let Some(text) = object.as_text_object() {
+                    for replacement in replace_text(
+                        text,
+                        chars.as_slice(),
+                        page.document_handle(),
+                        page.bindings(),
+                    )? {
+                        page.objects_mut().add_text_object(replacement)?;
+                    }
+                }
+
+                page.objects_mut().remove_object(object)?;
+            }
+        }
+    }
+
+    for area in areas.iter() {
+        page.objects_mut()
+            .create_path_object_rect(area.rect, None, None, Some(area.color))?;
+    }
+
+    for index in annotations_to_delete.into_iter().rev() {
+        let annotation = page.annotations().get(index)?;
+
+        if matches!(
+            annotation.annotation_type(),
+            PdfPageAnnotationType::Widget | PdfPageAnnotationType::XfaWidget
+        ) {
+            clear_widget(annotation.handle(), page.bindings())?;
+        }
+
+        page.annotations_mut().delete_annotation(annotation)?;
+    }
+
+    Ok(count)
+}
+
+/// Returns the areas covered by all redaction annotations on the given [PdfPage],
+/// the indices of all annotations to be removed from the page, and the number of
+/// redaction annotations found.
+///
+/// Annotations to be removed include the redaction annotations themselves, along with any
+/// other annotation overlapping a redacted area, since an annotation can carry the same
+/// information as the page content underneath it. Popup annotations are retained.
+fn find_redacted_areas(
+    page: &PdfPage,
+) -> Result<(Vec<PdfRedactedArea>, Vec<PdfPageAnnotationIndex>, usize), PdfiumError> {
+    let annotations = page.annotations();
+
+    let mut areas = Vec::new();
+
+    let mut count = 0;
+
+    for index in 0..annotations.len() {
+        let annotation = annotations.get(index)?;
+
+        if annotation.annotation_type() != PdfPageAnnotationType::Redacted {
+            continue;
+        }
+
+        count += 1;
+
+        let color = annotation.fill_color().unwrap_or(PdfColor::BLACK);
+
+        let attachment_points = annotation.attachment_points();
+
+        if attachment_points.is_empty() {
+            areas.push(PdfRedactedArea {
+                rect: annotation.bounds()?,
+                color,
+            });
+        } else {
+            for quad_points in attachment_points.iter() {
+                areas.push(PdfRedactedArea {
+                    rect: quad_points.to_rect(),
+                    color,
+                });
+            }
+        }
+    }
+
+    let mut annotations_to_delete = Vec::new();
+
+    for index in 0..annotations.len() {
+        let annotation = annotations.get(index)?;
+
+        let is_redacted = match annotation.annotation_type() {
+            PdfPageAnnotationType::Redacted => true,
+            PdfPageAnnotationType::Popup => false,
+            _ => annotation
+                .bounds()
+                .map(|bounds| overlaps_any(&bounds, areas.as_slice()))
+                .unwrap_or(false),
+        };
+
+        if is_redacted {
+            annotations_to_delete.push(index);
+        }
+    }
+
+    Ok((areas, annotations_to_delete, count))
+}
+
+/// Returns the index of every page object on the given [PdfPage] that lies at least partially
+/// inside one of the given redacted areas, along with the change to make to that object.
+///
+/// Pdfium does not provide a way to change the content of a form XObject, and removing a form
+/// XObject from the page leaves its content in the document, so an error is returned if any
+/// form XObject overlaps a redacted area.
+fn find_redacted_objects(
+    page: &PdfPage,
+    areas: &[PdfRedactedArea],
+) -> Result<Vec<(PdfPageObjectIndex, PdfPageObjectRedaction)>, PdfiumError> {
+    let text = page.text()?;
+
+    let objects = page.objects();
+
+    let mut result = Vec::new();
+
+    for index in 0..objects.len() {
+        let object = objects.get(index)?;
+
+        let mut bounds = object.bounds()?;
+
+        if let PdfPageObject::Text(text_object) = &object {
+            // The bounds of a text object extend only as far down as the font baseline.
+            // Allow for any glyphs that descend below it.
+
+            bounds.bottom -= text_object.scaled_font_size();
+        }
+
+        if !overlaps_any(&bounds, areas) {
+            continue;
+        }
+
+        let redaction = match &object {
+            PdfPageObject::Text(text_object) => redact_text(text_object, &text, areas),
+            PdfPageObject::Image(_) => {
+                match areas.iter().find(|area| bounds.is_inside(&area.rect)) {
+                    Some(area) => Some(PdfPageObjectRedaction::ClearImage(area.color)),
+                    None => Some(PdfPageObjectRedaction::OverwritePixels),
+                }
+            }
+            PdfPageObject::XObjectForm(_) => {
+                return Err(PdfiumError::RedactionOfFormObjectNotSupported)
+            }
+            _ => Some(PdfPageObjectRedaction::Remove),
+        };
+
+        if let Some(redaction) = redaction {
+            result.push((index, redaction));
+        }
+    }
+
+    Ok(result)
+}
+
+/// Returns the change to make to the given text object, or `None` if none of the characters
+/// in the text object lie inside any of the given redacted areas.
+fn redact_text(
+    object: &PdfPageTextObject,
+    text: &PdfPageText,
+    areas: &[PdfRedactedArea],
+) -> Option<PdfPageObjectRedaction> {
+    let chars = match text.chars_for_object(object) {
+        Ok(chars) => chars,
+
+        // If the characters in the text object cannot be retrieved, then there is no way
+        // of knowing which characters are redacted. Err on the side of caution.
+        Err(_) => return Some(PdfPageObjectRedaction::Remove),
+    };
+
+    let mut retained = Vec::new();
+
+    let mut is_redacted = false;
+
+    for char in chars.iter() {
+        match char.loose_bounds() {
+            Ok(bounds) if overlaps_any(&bounds, areas) => is_redacted = true,
+            Ok(_) => {
+                // Whitespace does not need to be retained; the position of each retained
+                // character is preserved independently.
+
+                if let (Some(text), Ok((x, y))) = (char.unicode_string(), char.origin()) {
+                    if !text.trim().is_empty() {
+                        retained.push(PdfRetainedTextChar { text, x, y });
+                    }
+                }
+            }
+            Err(_) => is_redacted = true,
+        }
+    }
+
+    if !is_redacted {
+        None
+    } else if retained.is_empty() {
+        Some(PdfPageObjectRedaction::Remove)
+    } else {
+        Some(PdfPageObjectRedaction::ReplaceText(retained))
+    }
+}
+
+/// Creates a new text object for each of the given characters, positioned at the character's
+/// original origin and styled to match the given text object.
+fn replace_text<'a>(
+    object: &PdfPageTextObject,
+    chars: &[PdfRetainedTextChar],
+    document_handle: FPDF_DOCUMENT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+) -> Result<Vec<PdfPageTextObject<'a>>, PdfiumError> {
+    let matrix = object.matrix()?;
+
+    let font = object.font();
+
+    let font_size = object.unscaled_font_size();
+
+    let fill_color = object.fill_color().ok();
+
+    let stroke_color = object.stroke_color().ok();
+
+    let render_mode = object.render_mode();
+
+    let mut result = Vec::with_capacity(chars.len());
+
+    for char in chars {
+        let mut replacement = PdfPageTextObject::new_from_handles(
+            document_handle,
+            char.text.as_str(),
+            font.handle(),
+            font_size,
+            bindings,
+        )?;
+
+        replacement.transform(
+            matrix.a(),
+            matrix.b(),
+            matrix.c(),
+            matrix.d(),
+            char.x.value,
+            char.y.value,
+        )?;
+
+        if let Some(color) = fill_color {
+            replacement.set_fill_color(color)?;
+        }
+
+        if let Some(color) = stroke_color {
+            replacement.set_stroke_color(color)?;
+        }
+
+        if render_mode != PdfPageTextRenderMode::Unknown {
+            replacement.set_render_mode(render_mode)?;
+        }
+
+        result.push(replacement);
+    }
+
+    Ok(result)
+}
+
+/// Returns a copy of the bitmap of the given image object in which every pixel that lies inside
+/// any of the given redacted areas has been overwritten with the color of that area.
+fn redact_pixels<'a>(
+    image: &PdfPageImageObject,
+    areas: &[PdfRedactedArea],
+    bindings: &'a dyn PdfiumLibraryBindings,
+) -> Result<PdfBitmap<'a>, PdfiumError> {
+    let matrix = image.matrix()?;
+
+    let (format, width, height, mut buffer) = {
+        let bitmap = image.get_raw_bitmap()?;
+
+        if bitmap.handle().is_null() {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+
+        (
+            bitmap.format()?,
+            bitmap.width(),
+            bitmap.height(),
+            bitmap.as_raw_bytes().to_vec(),
+        )
+    };
+
+    if width <= 0 || height <= 0 {
+        return Err(PdfiumError::PdfiumLibraryInternalError(
+            PdfiumInternalError::Unknown,
+        ));
+    }
+
+    let stride = buffer.len() / height as usize;
+
+    let bytes_per_pixel = match format {
+        PdfBitmapFormat::Gray => 1,
+        PdfBitmapFormat::BGR => 3,
+        #[allow(deprecated)]
+        PdfBitmapFormat::BGRA | PdfBitmapFormat::BGRx | PdfBitmapFormat::BRGx => 4,
+    };
+
+    for row in 0..height {
+        for column in 0..width {
+            // An image object's matrix maps the unit square onto the page. The first row
+            // of the bitmap lies at the top of the unit square.
+
+            let (x, y) = matrix.apply_to_points(
+                PdfPoints::new((column as f32 + 0.5) / width as f32),
+                PdfPoints::new(1.0 - (row as f32 + 0.5) / height as f32),
+            );
+
+            if let Some(area) = areas.iter().find(|area| area.rect.contains(x, y)) {
+                let offset = row as usize * stride + column as usize * bytes_per_pixel;
+
+                let pixel = &mut buffer[offset..offset + bytes_per_pixel];
+
+                let color = area.color;
+
+                match format {
+                    PdfBitmapFormat::Gray => {
+                        pixel[0] = ((color.red() as u32 * 299
+                            + color.green() as u32 * 587
+                            + color.blue() as u32 * 114)
+                            / 1000) as u8;
+                    }
+                    _ => {
+                        pixel[0] = color.blue();
+                        pixel[1] = color.green();
+                        pixel[2] = color.red();
+
+                        if bytes_per_pixel == 4 {
+                            pixel[3] = 255;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let bitmap = PdfBitmap::empty(width, height, format, bindings)?;
+
+    if !bindings.FPDFBitmap_SetBuffer(*bitmap.handle(), buffer.as_slice()) {
+        return Err(PdfiumError::PdfiumLibraryInternalError(
+            PdfiumInternalError::Unknown,
+        ));
+    }
+
+    Ok(bitmap)
+}
+
+/// Replaces all the image data of the given image object with a single pixel of the given color.
+fn clear_image(
+    image: &mut PdfPageImageObject,
+    color: PdfColor,
+    bindings: &dyn PdfiumLibraryBindings,
+) -> Result<(), PdfiumError> {
+    let bitmap = PdfBitmap::empty(1, 1, PdfBitmapFormat::BGRA, bindings)?;
+
+    if !bindings.FPDFBitmap_SetBuffer(
+        *bitmap.handle(),
+        &[color.blue(), color.green(), color.red(), 255],
+    ) {
+        return Err(PdfiumError::PdfiumLibraryInternalError(
+            PdfiumInternalError::Unknown,
+        ));
+    }
+
+    image.set_bitmap(&bitmap)
+}
+
+/// Returns `true` if the image data of the given image object is also used by at least one other
+/// image object on any page in the document with the given handle, including image objects
+/// nested inside form XObjects.
+///
+/// Pdfium does not expose the identity of the image XObject underlying an image object, so image
+/// objects are compared using their raw image data. Two image objects with identical raw image
+/// data are considered to share their image data even if they use separate image XObjects;
+/// either way, the image data remains visible elsewhere in the document.
+fn is_image_shared(
+    image: &PdfPageImageObject,
+    document_handle: FPDF_DOCUMENT,
+    bindings: &dyn PdfiumLibraryBindings,
+) -> bool {
+    let data = raw_image_data(image.get_object_handle(), bindings);
+
+    let mut count = 0;
+
+    for page_index in 0..bindings.FPDF_GetPageCount(document_handle) {
+        let page_handle = bindings.FPDF_LoadPage(document_handle, page_index);
+
+        if page_handle.is_null() {
+            continue;
+        }
+
+        for object_index in 0..bindings.FPDFPage_CountObjects(page_handle) {
+            count += count_images_with_data(
+                bindings.FPDFPage_GetObject(page_handle, object_index),
+                data.as_slice(),
+                bindings,
+            );
+        }
+
+        bindings.FPDF_ClosePage(page_handle);
+
+        if count > 1 {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Returns the number of image objects with the given raw image data in the given page object,
+/// descending into form XObjects.
+fn count_images_with_data(
+    object_handle: FPDF_PAGEOBJECT,
+    data: &[u8],
+    bindings: &dyn PdfiumLibraryBindings,
+) -> usize {
+    match bindings.FPDFPageObj_GetType(object_handle) as u32 {
+        FPDF_PAGEOBJ_IMAGE => {
+            let length =
+                bindings.FPDFImageObj_GetImageDataRaw(object_handle, std::ptr::null_mut(), 0);
+
+            if length as usize == data.len() && raw_image_data(object_handle, bindings) == data {
+                1
+            } else {
+                0
+            }
+        }
+        FPDF_PAGEOBJ_FORM => (0..bindings.FPDFFormObj_CountObjects(object_handle))
+            .map(|index| {
+                count_images_with_data(
+                    bindings.FPDFFormObj_GetObject(object_handle, index as c_ulong),
+                    data,
+                    bindings,
+                )
+            })
+            .sum(),
+        _ => 0,
+    }
+}
+
+/// Returns the raw image data of the given image object, before any filters are applied.
+fn raw_image_data(object_handle: FPDF_PAGEOBJECT, bindings: &dyn PdfiumLibraryBindings) -> Vec<u8> {
+    let length = bindings.FPDFImageObj_GetImageDataRaw(object_handle, std::ptr::null_mut(), 0);
+
+    let mut buffer = create_byte_buffer(length as usize);
+
+    if length > 0 {
+        bindings.FPDFImageObj_GetImageDataRaw(
+            object_handle,
+            buffer.as_mut_ptr() as *mut c_void,
+            length,
+        );
+    }
+
+    buffer
+}
+
+/// Removes the value and appearance of the form field widget annotation with the given handle,
+/// along with the value of its parent form field, if any.
+///
+/// Deleting a widget annotation from a page does not remove its form field from the document's
+/// interactive form, so the field's value and appearance stream would still be written out
+/// when the document is saved. The objects in the widget's normal appearance stream are removed
+/// first, so that the appearance stream itself no longer contains the field's value.
+fn clear_widget(
+    annotation_handle: FPDF_ANNOTATION,
+    bindings: &dyn PdfiumLibraryBindings,
+) -> Result<(), PdfiumError> {
+    while bindings.FPDFAnnot_GetObjectCount(annotation_handle) > 0 {
+        if !bindings.is_true(bindings.FPDFAnnot_RemoveObject(annotation_handle, 0)) {
+            return Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ));
+        }
+    }
+
+    // Setting the normal appearance stream to null removes all appearance streams.
+
+    if bindings.is_true(bindings.FPDFAnnot_HasKey(annotation_handle, "AP")) {
+        bindings.to_result(bindings.FPDFAnnot_SetAP(
+            annotation_handle,
+            PdfAppearanceMode::Normal as c_int,
+            std::ptr::null(),
+        ))?;
+    }
+
+    clear_field_value(annotation_handle, bindings)?;
+
+    let parent_handle = bindings.FPDFAnnot_GetLinkedAnnot(annotation_handle, "Parent");
+
+    if !parent_handle.is_null() {
+        let result = clear_field_value(parent_handle, bindings);
+
+        bindings.FPDFPage_CloseAnnot(parent_handle);
+
+        result?;
+    }
+
+    Ok(())
+}
+
+/// Replaces the value and rich text value of the form field dictionary with the given handle,
+/// if present, with empty strings.
+fn clear_field_value(
+    handle: FPDF_ANNOTATION,
+    bindings: &dyn PdfiumLibraryBindings,
+) -> Result<(), PdfiumError> {
+    for key in ["V", "RV"] {
+        if bindings.is_true(bindings.FPDFAnnot_HasKey(handle, key)) {
+            bindings.to_result(bindings.FPDFAnnot_SetStringValue_str(handle, key, ""))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns `true` if the given rectangle overlaps any of the given redacted areas.
+#[inline]
+fn overlaps_any(rect: &PdfRect, areas: &[PdfRedactedArea]) -> bool {
+    areas.iter().any(|area| rect.does_overlap(&area.rect))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_apply_redactions_without_redactions() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(700.0),
+            "Not redacted",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        page.annotations_mut().create_square_annotation()?;
+
+        assert_eq!(page.apply_redactions()?, 0);
+        assert_eq!(page.objects().len(), 1);
+        assert_eq!(page.annotations().len(), 1);
+        assert_eq!(page.text()?.all(), "Not redacted");
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_apply_redactions_survives_save() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = {
+            let document = pdfium.load_pdf_from_file("./test/redaction-test.pdf", None)?;
+
+            let mut page = document.pages().first()?;
+
+            assert!(page.text()?.all().contains("Public Classified"));
+
+            assert_eq!(page.apply_redactions()?, 3);
+
+            document.save_to_bytes()?
+        };
+
+        // Neither the redacted text nor the image data of the fully redacted image
+        // should appear anywhere in the saved document.
+
+        let contains = |needle: &[u8]| bytes.windows(needle.len()).any(|window| window == needle);
+
+        assert!(!contains(b"Classified"));
+        assert!(!contains(b"TOP-SECRET-IMAGE-PAYLOAD"));
+        assert!(!contains(&[255, 0, 0, 255, 0, 0]));
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().first()?;
+
+        assert_eq!(page.annotations().len(), 0);
+
+        let text = page.text()?.all();
+
+        assert!(text.contains("Public"));
+        assert!(!text.contains('C'));
+        assert!(!text.contains("assified"));
+
+        // Only the partially redacted image remains. Its left half lay inside a redacted
+        // area and has been painted black; its right half is unchanged.
+
+        let images = page
+            .objects()
+            .iter()
+            .filter_map(|object| object.as_image_object().map(|image| image.get_raw_image()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(images.len(), 1);
+
+        let image = images[0].to_rgb8();
+
+        assert_eq!(image.dimensions(), (4, 1));
+        assert_eq!(image.get_pixel(0, 0).0, [0, 0, 0]);
+        assert_eq!(image.get_pixel(1, 0).0, [0, 0, 0]);
+        assert_eq!(image.get_pixel(2, 0).0, [0, 0, 255]);
+        assert_eq!(image.get_pixel(3, 0).0, [0, 0, 255]);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "image")]
+    fn test_apply_redactions_to_widgets_and_shared_images() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = {
+            let document = pdfium.load_pdf_from_file("./test/redaction-forms-test.pdf", None)?;
+
+            let mut page = document.pages().first()?;
+
+            assert_eq!(page.annotations().len(), 4);
+
+            assert_eq!(page.apply_redactions()?, 3);
+
+            document.save_to_bytes()?
+        };
+
+        // Neither the value of the redacted form field nor its appearance stream should
+        // appear anywhere in the saved document.
+
+        let contains = |needle: &[u8]| bytes.windows(needle.len()).any(|window| window == needle);
+
+        assert!(!contains(b"WidgetSecret"));
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let first = document.pages().first()?;
+
+        assert_eq!(first.annotations().len(), 0);
+
+        let text = first.text()?.all();
+
+        assert!(text.contains("Keep"));
+        assert!(!text.contains("Remove"));
+
+        // The form XObject did not overlap a redaction, and is retained.
+
+        assert!(text.contains("Form Content"));
+
+        // The image data is shared with the second page, so the first page's image has been
+        // replaced with a redacted copy, and the second page's image is unchanged.
+
+        let image = |page: &PdfPage| -> Result<_, PdfiumError> {
+            let images = page
+                .objects()
+                .iter()
+                .filter_map(|object| object.as_image_object().map(|image| image.get_raw_image()))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            assert_eq!(images.len(), 1);
+
+            Ok(images[0].to_rgb8())
+        };
+
+        let redacted = image(&first)?;
+
+        assert_eq!(redacted.get_pixel(0, 0).0, [0, 0, 0]);
+        assert_eq!(redacted.get_pixel(1, 0).0, [0, 0, 0]);
+        assert_eq!(redacted.get_pixel(2, 0).0, [0, 0, 255]);
+        assert_eq!(redacted.get_pixel(3, 0).0, [0, 0, 255]);
+
+        let unchanged = image(&document.pages().get(1)?)?;
+
+        assert_eq!(unchanged.get_pixel(0, 0).0, [255, 0, 0]);
+        assert_eq!(unchanged.get_pixel(1, 0).0, [255, 0, 0]);
+        assert_eq!(unchanged.get_pixel(2, 0).0, [0, 0, 255]);
+        assert_eq!(unchanged.get_pixel(3, 0).0, [0, 0, 255]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_redactions_rejects_form_objects() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/redaction-forms-test.pdf", None)?;
+
+        let mut page = document.pages().get(1)?;
+
+        assert!(matches!(
+            page.apply_redactions(),
+            Err(PdfiumError::RedactionOfFormObjectNotSupported)
+        ));
+
+        // The page is left unchanged.
+
+        assert_eq!(page.annotations().len(), 1);
+        assert!(page.text()?.all().contains("FormSecret"));
+
+        drop(page);
+
+        let document = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        let page = document.pages().get(1)?;
+
+        assert_eq!(page.annotations().len(), 1);
+        assert!(page.text()?.all().contains("FormSecret"));
+
+        Ok(())
+    }
+}
@@ -2,10 +2,10 @@
 //! the rendering of [PdfBitmap] objects from one or more [PdfPage] objects.
 
 use crate::bindgen::{
-    FPDF_ANNOT, FPDF_CONVERT_FILL_TO_STROKE, FPDF_DWORD, FPDF_GRAYSCALE, FPDF_LCD_TEXT,
-    FPDF_NO_NATIVETEXT, FPDF_PRINTING, FPDF_RENDER_FORCEHALFTONE, FPDF_RENDER_LIMITEDIMAGECACHE,
-    FPDF_RENDER_NO_SMOOTHIMAGE, FPDF_RENDER_NO_SMOOTHPATH, FPDF_RENDER_NO_SMOOTHTEXT,
-    FPDF_REVERSE_BYTE_ORDER, FS_MATRIX, FS_RECTF,
+    FPDF_ANNOT, FPDF_COLORSCHEME, FPDF_CONVERT_FILL_TO_STROKE, FPDF_DWORD, FPDF_GRAYSCALE,
+    FPDF_LCD_TEXT, FPDF_NO_NATIVETEXT, FPDF_PRINTING, FPDF_RENDER_FORCEHALFTONE,
+    FPDF_RENDER_LIMITEDIMAGECACHE, FPDF_RENDER_NO_SMOOTHIMAGE, FPDF_RENDER_NO_SMOOTHPATH,
+    FPDF_RENDER_NO_SMOOTHTEXT, FPDF_REVERSE_BYTE_ORDER, FS_MATRIX, FS_RECTF,
 };
 use crate::create_transform_setters;
 use crate::error::PdfiumError;
@@ -56,6 +56,83 @@ impl PdfBitmapConfig {
     }
 }
 
+/// A set of colors that override the colors of paths and text during rendering of a [PdfPage],
+/// for instance to produce a dark mode or high contrast rendering of the page.
+///
+/// Apply a color scheme to a [PdfRenderConfig] by calling the
+/// [PdfRenderConfig::set_color_scheme()] function. When a color scheme is applied,
+/// Pdfium fills and strokes every path and every text glyph on the page using the colors
+/// specified in the color scheme, rather than the colors specified in the document itself.
+/// Images are not affected.
+#[derive(Debug, Copy, Clone)]
+pub struct PdfRenderColorScheme {
+    path_fill_color: PdfColor,
+    path_stroke_color: PdfColor,
+    text_fill_color: PdfColor,
+    text_stroke_color: PdfColor,
+}
+
+impl PdfRenderColorScheme {
+    /// Creates a new [PdfRenderColorScheme] from the given path and text colors.
+    #[inline]
+    pub const fn new(
+        path_fill_color: PdfColor,
+        path_stroke_color: PdfColor,
+        text_fill_color: PdfColor,
+        text_stroke_color: PdfColor,
+    ) -> Self {
+        PdfRenderColorScheme {
+            path_fill_color,
+            path_stroke_color,
+            text_fill_color,
+            text_stroke_color,
+        }
+    }
+
+    /// Creates a new [PdfRenderColorScheme] that fills and strokes all paths and text
+    /// using the given single color.
+    #[inline]
+    pub const fn monochrome(color: PdfColor) -> Self {
+        Self::new(color, color, color, color)
+    }
+
+    /// Returns the color used to fill paths when this [PdfRenderColorScheme] is applied.
+    #[inline]
+    pub fn path_fill_color(&self) -> PdfColor {
+        self.path_fill_color
+    }
+
+    /// Returns the color used to stroke paths when this [PdfRenderColorScheme] is applied.
+    #[inline]
+    pub fn path_stroke_color(&self) -> PdfColor {
+        self.path_stroke_color
+    }
+
+    /// Returns the color used to fill text when this [PdfRenderColorScheme] is applied.
+    #[inline]
+    pub fn text_fill_color(&self) -> PdfColor {
+        self.text_fill_color
+    }
+
+    /// Returns the color used to stroke text when this [PdfRenderColorScheme] is applied.
+    #[inline]
+    pub fn text_stroke_color(&self) -> PdfColor {
+        self.text_stroke_color
+    }
+
+    /// Returns this [PdfRenderColorScheme] as an `FPDF_COLORSCHEME` struct,
+    /// suitable for passing to Pdfium.
+    #[inline]
+    pub(crate) fn as_pdfium(&self) -> FPDF_COLORSCHEME {
+        FPDF_COLORSCHEME {
+            path_fill_color: self.path_fill_color.as_pdfium_argb(),
+            path_stroke_color: self.path_stroke_color.as_pdfium_argb(),
+            text_fill_color: self.text_fill_color.as_pdfium_argb(),
+            text_stroke_color: self.text_stroke_color.as_pdfium_argb(),
+        }
+    }
+}
+
 /// Configures the scaling, rotation, and rendering settings that should be applied to
 /// a [PdfPage] to create a [PdfBitmap] for that page. [PdfRenderConfig] can accommodate pages of
 /// different sizes while correctly maintaining each page's aspect ratio, automatically
@@ -85,6 +162,7 @@ pub struct PdfRenderConfig {
     clear_color: PdfColor,
     do_render_form_data: bool,
     form_field_highlight: Option<Vec<(PdfFormFieldType, PdfColor)>>,
+    color_scheme: Option<PdfRenderColorScheme>,
     transformation_matrix: PdfMatrix,
     clip_rect: Option<(Pixels, Pixels, Pixels, Pixels)>,
 
@@ -123,6 +201,7 @@ impl PdfRenderConfig {
             clear_color: PdfColor::WHITE,
             do_render_form_data: true,
             form_field_highlight: None,
+            color_scheme: None,
             transformation_matrix: PdfMatrix::IDENTITY,
             clip_rect: None,
             do_set_flag_render_annotations: true,
@@ -591,6 +670,32 @@ impl PdfRenderConfig {
         self
     }
 
+    /// Overrides the colors used to fill and stroke paths and text during rendering of the
+    /// [PdfPage] with the colors in the given [PdfRenderColorScheme]. This can be used to produce
+    /// dark mode or high contrast renderings without post-processing the rendered bitmap.
+    /// The default is to render all paths and text using the colors specified in the document.
+    ///
+    /// Pdfium's color scheme rendering pipeline does not support custom transformation matrices
+    /// or clipping. When a color scheme is set, any transformation or clipping rectangle applied
+    /// to this [PdfRenderConfig] will be ignored; the target pixel dimensions and rotation settings
+    /// will be respected.
+    #[inline]
+    pub fn set_color_scheme(mut self, color_scheme: PdfRenderColorScheme) -> Self {
+        self.color_scheme = Some(color_scheme);
+
+        self
+    }
+
+    /// Removes any [PdfRenderColorScheme] previously set by a call to
+    /// [PdfRenderConfig::set_color_scheme()], so that paths and text are rendered using
+    /// the colors specified in the document.
+    #[inline]
+    pub fn clear_color_scheme(mut self) -> Self {
+        self.color_scheme = None;
+
+        self
+    }
+
     create_transform_setters!(
         Self,
         Result<Self, PdfiumError>,
@@ -874,6 +979,10 @@ impl PdfRenderConfig {
                         .collect::<Vec<_>>(),
                 )
             },
+            color_scheme: self
+                .color_scheme
+                .as_ref()
+                .map(PdfRenderColorScheme::as_pdfium),
             matrix: transformation_matrix
                 .unwrap_or(PdfMatrix::IDENTITY)
                 .as_pdfium(),
@@ -917,6 +1026,7 @@ pub(crate) struct PdfRenderSettings {
     pub(crate) clear_color: FPDF_DWORD,
     pub(crate) do_render_form_data: bool,
     pub(crate) form_field_highlight: Option<Vec<(c_int, (FPDF_DWORD, u8))>>,
+    pub(crate) color_scheme: Option<FPDF_COLORSCHEME>,
     pub(crate) matrix: FS_MATRIX,
     pub(crate) clipping: FS_RECTF,
     pub(crate) render_flags: c_int,
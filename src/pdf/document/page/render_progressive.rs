@@ -0,0 +1,354 @@
+//! Defines the [PdfPageProgressiveRender] struct, exposing functionality related to
+//! rendering a single [PdfPage] incrementally over a number of separate steps.
+
+use crate::bindgen::{
+    FPDF_BOOL, FPDF_RENDER_DONE, FPDF_RENDER_FAILED, FPDF_RENDER_READY, FPDF_RENDER_TOBECONTINUED,
+    IFSDK_PAUSE,
+};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
+use crate::pdf::bitmap::PdfBitmap;
+use crate::pdf::document::page::render_config::PdfRenderSettings;
+use crate::pdf::document::page::PdfPage;
+use std::os::raw::{c_int, c_void};
+use std::time::{Duration, Instant};
+
+#[cfg(doc)]
+use crate::pdf::document::page::render_config::PdfRenderConfig;
+
+/// The current status of a [PdfPageProgressiveRender] operation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PdfPageProgressiveRenderStatus {
+    /// Rendering has been paused and can be resumed by calling one of the
+    /// [PdfPageProgressiveRender::step()], [PdfPageProgressiveRender::step_for()],
+    /// [PdfPageProgressiveRender::step_until()], or [PdfPageProgressiveRender::finish()] functions.
+    InProgress,
+
+    /// Rendering has finished. The completed image can be retrieved by calling the
+    /// [PdfPageProgressiveRender::bitmap()] or [PdfPageProgressiveRender::into_bitmap()] functions.
+    Complete,
+}
+
+impl PdfPageProgressiveRenderStatus {
+    pub(crate) fn from_pdfium(status: c_int) -> Result<Self, PdfiumError> {
+        match status as u32 {
+            FPDF_RENDER_READY | FPDF_RENDER_TOBECONTINUED => Ok(Self::InProgress),
+            FPDF_RENDER_DONE => Ok(Self::Complete),
+            FPDF_RENDER_FAILED => Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure),
+            _ => Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure),
+        }
+    }
+}
+
+/// A rendering operation for a single [PdfPage] that can be performed incrementally,
+/// rather than in a single blocking call.
+///
+/// Progressive rendering is useful when rendering complex pages, such as large engineering
+/// drawings or maps, in interactive applications. Rendering can be advanced in small steps,
+/// given a time budget, or paused by a user-supplied callback, and it can be abandoned at any
+/// time by calling [PdfPageProgressiveRender::cancel()] or by simply dropping the
+/// [PdfPageProgressiveRender] object.
+///
+/// Create a [PdfPageProgressiveRender] by calling the [PdfPage::render_progressively()] function.
+/// Pdfium supports only one progressive rendering operation per page at any one time.
+///
+/// Pdfium's progressive rendering API does not support custom transformation matrices.
+/// Any transformation matrix or clipping rectangle configured in the [PdfRenderConfig] used
+/// to create a [PdfPageProgressiveRender] will be ignored; the target pixel dimensions and
+/// rotation settings, together with all other rendering options including any
+/// color scheme, will be respected.
+pub struct PdfPageProgressiveRender<'a> {
+    page: &'a PdfPage<'a>,
+    bitmap: Option<PdfBitmap<'a>>,
+    settings: PdfRenderSettings,
+    status: PdfPageProgressiveRenderStatus,
+    is_closed: bool,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageProgressiveRender<'a> {
+    /// Starts a new progressive rendering operation of the given [PdfPage] into the given
+    /// [PdfBitmap] using the given [PdfRenderSettings]. Pdfium will perform as much rendering
+    /// as it can before the given callback requests a pause.
+    pub(crate) fn start(
+        page: &'a PdfPage<'a>,
+        mut bitmap: PdfBitmap<'a>,
+        settings: PdfRenderSettings,
+        should_pause: &mut dyn FnMut() -> bool,
+    ) -> Result<Self, PdfiumError> {
+        let bindings = page.bindings();
+
+        let bitmap_handle = *bitmap.handle();
+
+        if settings.do_clear_bitmap_before_rendering {
+            // Clear the bitmap buffer by setting every pixel to a known color.
+
+            bindings.FPDFBitmap_FillRect(
+                bitmap_handle,
+                0,
+                0,
+                settings.width,
+                settings.height,
+                settings.clear_color,
+            );
+        }
+
+        bitmap.set_byte_order_from_render_settings(&settings);
+
+        let mut pause = PdfPageProgressiveRenderPause::new(should_pause);
+
+        let status = if let Some(color_scheme) = settings.color_scheme.as_ref() {
+            bindings.FPDF_RenderPageBitmapWithColorScheme_Start(
+                bitmap_handle,
+                page.page_handle(),
+                0,
+                0,
+                settings.width,
+                settings.height,
+                settings.rotate,
+                settings.render_flags,
+                color_scheme,
+                pause.as_ifsdk_pause_mut_ptr(),
+            )
+        } else {
+            bindings.FPDF_RenderPageBitmap_Start(
+                bitmap_handle,
+                page.page_handle(),
+                0,
+                0,
+                settings.width,
+                settings.height,
+                settings.rotate,
+                settings.render_flags,
+                pause.as_ifsdk_pause_mut_ptr(),
+            )
+        };
+
+        let mut result = PdfPageProgressiveRender {
+            page,
+            bitmap: Some(bitmap),
+            settings,
+            status: PdfPageProgressiveRenderStatus::InProgress,
+            is_closed: false,
+            bindings,
+        };
+
+        result.update_status(status)?;
+
+        Ok(result)
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfPageProgressiveRender].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the [PdfPage] being rendered by this [PdfPageProgressiveRender].
+    #[inline]
+    pub fn page(&self) -> &'a PdfPage<'a> {
+        self.page
+    }
+
+    /// Returns the current status of this [PdfPageProgressiveRender].
+    #[inline]
+    pub fn status(&self) -> PdfPageProgressiveRenderStatus {
+        self.status
+    }
+
+    /// Returns `true` if this [PdfPageProgressiveRender] has finished rendering.
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.status == PdfPageProgressiveRenderStatus::Complete
+    }
+
+    /// Advances this [PdfPageProgressiveRender] by a single step, returning the updated status.
+    ///
+    /// Pdfium decides how much work constitutes a single step; typically, a step covers
+    /// the rendering of a batch of page objects.
+    #[inline]
+    pub fn step(&mut self) -> Result<PdfPageProgressiveRenderStatus, PdfiumError> {
+        self.step_until(|| true)
+    }
+
+    /// Advances this [PdfPageProgressiveRender] until either rendering is complete or the given
+    /// time budget has elapsed, returning the updated status.
+    ///
+    /// Pdfium only checks whether it should pause between batches of rendering work,
+    /// so the time budget may be exceeded slightly.
+    pub fn step_for(
+        &mut self,
+        budget: Duration,
+    ) -> Result<PdfPageProgressiveRenderStatus, PdfiumError> {
+        let deadline = Instant::now() + budget;
+
+        self.step_until(|| Instant::now() >= deadline)
+    }
+
+    /// Advances this [PdfPageProgressiveRender] until either rendering is complete or the given
+    /// callback returns `true`, returning the updated status.
+    ///
+    /// Pdfium invokes the callback periodically between batches of rendering work to ask
+    /// whether rendering should pause. The callback can be used to interrupt rendering in
+    /// response to an external event, such as a viewer scrolling the page out of view.
+    pub fn step_until<F>(
+        &mut self,
+        mut should_pause: F,
+    ) -> Result<PdfPageProgressiveRenderStatus, PdfiumError>
+    where
+        F: FnMut() -> bool,
+    {
+        if self.status == PdfPageProgressiveRenderStatus::Complete {
+            return Ok(self.status);
+        }
+
+        let mut pause = PdfPageProgressiveRenderPause::new(&mut should_pause);
+
+        let status = self
+            .bindings
+            .FPDF_RenderPage_Continue(self.page.page_handle(), pause.as_ifsdk_pause_mut_ptr());
+
+        self.update_status(status)
+    }
+
+    /// Advances this [PdfPageProgressiveRender] until rendering is complete.
+    #[inline]
+    pub fn finish(&mut self) -> Result<PdfPageProgressiveRenderStatus, PdfiumError> {
+        self.step_until(|| false)
+    }
+
+    /// Cancels this [PdfPageProgressiveRender], releasing all held resources.
+    /// The partially rendered bitmap is discarded.
+    #[inline]
+    pub fn cancel(self) {
+        // The rendering operation is closed when this object is dropped.
+    }
+
+    /// Returns the [PdfBitmap] targeted by this [PdfPageProgressiveRender].
+    ///
+    /// If rendering is not yet complete, the bitmap will contain a partially rendered image.
+    #[inline]
+    pub fn bitmap(&self) -> &PdfBitmap<'a> {
+        // The bitmap is only ever taken when this object is consumed.
+
+        self.bitmap.as_ref().unwrap()
+    }
+
+    /// Consumes this [PdfPageProgressiveRender], returning the targeted [PdfBitmap].
+    ///
+    /// If rendering is not yet complete, the returned bitmap will contain a partially rendered
+    /// image; call [PdfPageProgressiveRender::finish()] first to ensure rendering is complete.
+    #[inline]
+    pub fn into_bitmap(mut self) -> PdfBitmap<'a> {
+        self.close();
+
+        // The bitmap is only ever taken here, when this object is consumed.
+
+        self.bitmap.take().unwrap()
+    }
+
+    /// Updates the status of this [PdfPageProgressiveRender] from the given Pdfium
+    /// rendering status code, completing the rendering operation if necessary.
+    fn update_status(
+        &mut self,
+        status: c_int,
+    ) -> Result<PdfPageProgressiveRenderStatus, PdfiumError> {
+        match PdfPageProgressiveRenderStatus::from_pdfium(status) {
+            Ok(PdfPageProgressiveRenderStatus::Complete) => {
+                self.close();
+
+                // Pdfium's progressive rendering functions do not render user-supplied
+                // form data, so we render it separately once page rendering is complete.
+
+                if self.settings.do_render_form_data {
+                    if let Some(bitmap) = self.bitmap.as_ref() {
+                        self.page
+                            .render_form_data_into_bitmap(*bitmap.handle(), &self.settings);
+                    }
+                }
+
+                self.status = PdfPageProgressiveRenderStatus::Complete;
+
+                Ok(self.status)
+            }
+            Ok(status) => {
+                self.status = status;
+
+                Ok(status)
+            }
+            Err(err) => {
+                self.close();
+
+                Err(err)
+            }
+        }
+    }
+
+    /// Releases the resources held by Pdfium for this rendering operation.
+    fn close(&mut self) {
+        if !self.is_closed {
+            self.bindings.FPDF_RenderPage_Close(self.page.page_handle());
+
+            self.is_closed = true;
+        }
+    }
+}
+
+impl<'a> Drop for PdfPageProgressiveRender<'a> {
+    /// Closes this [PdfPageProgressiveRender], releasing held resources.
+    #[inline]
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+/// A wrapped Pdfium `IFSDK_PAUSE` struct that connects Pdfium's pause callback to
+/// a Rust closure.
+pub(crate) struct PdfPageProgressiveRenderPause<'a> {
+    pause: IFSDK_PAUSE,
+
+    // The user field of the IFSDK_PAUSE struct points to this field, so that the callback
+    // invoked by Pdfium can retrieve the Rust closure.
+    should_pause: &'a mut dyn FnMut() -> bool,
+}
+
+impl<'a> PdfPageProgressiveRenderPause<'a> {
+    #[inline]
+    pub(crate) fn new(should_pause: &'a mut dyn FnMut() -> bool) -> Self {
+        PdfPageProgressiveRenderPause {
+            pause: IFSDK_PAUSE {
+                version: 1,
+                NeedToPauseNow: Some(need_to_pause_now_from_callback),
+                user: std::ptr::null_mut(), // We'll set this value when the pointer is requested.
+            },
+            should_pause,
+        }
+    }
+
+    /// Returns a pointer to the wrapped `IFSDK_PAUSE` struct, suitable for passing to Pdfium.
+    /// The pointer is only valid for as long as this object is neither moved nor dropped.
+    #[inline]
+    pub(crate) fn as_ifsdk_pause_mut_ptr(&mut self) -> *mut IFSDK_PAUSE {
+        self.pause.user = &mut self.should_pause as *mut &'a mut dyn FnMut() -> bool as *mut c_void;
+
+        &mut self.pause
+    }
+}
+
+/// The callback function invoked by Pdfium to determine whether a progressive rendering
+/// operation should pause.
+extern "C" fn need_to_pause_now_from_callback(pause_ptr: *mut IFSDK_PAUSE) -> FPDF_BOOL {
+    unsafe {
+        let user = (*pause_ptr).user as *mut &mut dyn FnMut() -> bool;
+
+        if user.is_null() {
+            return 0;
+        }
+
+        if (*user)() {
+            1
+        } else {
+            0
+        }
+    }
+}
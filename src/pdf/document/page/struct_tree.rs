@@ -0,0 +1,280 @@
+//! Defines the [PdfStructTree] struct, exposing functionality related to the
+//! logical structure tree of a single tagged [PdfPage].
+
+pub mod attribute;
+pub mod element;
+
+use crate::bindgen::FPDF_STRUCTTREE;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::page::struct_tree::element::PdfStructElement;
+use crate::pdf::document::page::PdfPage;
+use std::ops::{Range, RangeInclusive};
+use std::os::raw::c_int;
+
+pub type PdfStructElementIndex = usize;
+
+/// The logical structure tree of a single tagged [PdfPage].
+///
+/// Tagged PDF files carry a structure tree describing the logical organisation of the content
+/// in the document, such as headings, paragraphs, figures, lists, and tables. Each node in the
+/// tree is a [PdfStructElement]; elements can be nested arbitrarily deeply. The structure tree
+/// is primarily used to support accessibility tools such as screen readers and to allow
+/// content to be reflowed.
+///
+/// The top-level elements of the tree can be retrieved using the [PdfStructTree::get()] and
+/// [PdfStructTree::iter()] functions. Child elements of any element can be retrieved from
+/// the element itself using the [PdfStructElement::children()] function.
+///
+/// Pages in untagged documents return an empty [PdfStructTree].
+pub struct PdfStructTree<'a> {
+    handle: FPDF_STRUCTTREE,
+    page: &'a PdfPage<'a>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfStructTree<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        handle: FPDF_STRUCTTREE,
+        page: &'a PdfPage<'a>,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfStructTree {
+            handle,
+            page,
+            bindings,
+        }
+    }
+
+    /// Returns the internal `FPDF_STRUCTTREE` handle for this [PdfStructTree].
+    #[inline]
+    #[allow(dead_code)]
+    pub(crate) fn handle(&self) -> FPDF_STRUCTTREE {
+        self.handle
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfStructTree].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the [PdfPage] containing this [PdfStructTree].
+    #[inline]
+    pub fn page(&self) -> &'a PdfPage<'a> {
+        self.page
+    }
+
+    /// Returns the number of top-level elements in this [PdfStructTree].
+    #[inline]
+    pub fn len(&self) -> PdfStructElementIndex {
+        self.bindings
+            .FPDF_StructTree_CountChildren(self.handle)
+            .max(0) as PdfStructElementIndex
+    }
+
+    /// Returns `true` if this [PdfStructTree] contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a Range from `0..(number of top-level elements)` for this [PdfStructTree].
+    #[inline]
+    pub fn as_range(&self) -> Range<PdfStructElementIndex> {
+        0..self.len()
+    }
+
+    /// Returns an inclusive Range from `0..=(number of top-level elements - 1)`
+    /// for this [PdfStructTree].
+    #[inline]
+    pub fn as_range_inclusive(&self) -> RangeInclusive<PdfStructElementIndex> {
+        if self.is_empty() {
+            0..=0
+        } else {
+            0..=(self.len() - 1)
+        }
+    }
+
+    /// Returns a single top-level [PdfStructElement] from this [PdfStructTree].
+    pub fn get(&self, index: PdfStructElementIndex) -> Result<PdfStructElement<'_>, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::StructElementIndexOutOfBounds);
+        }
+
+        let handle = self
+            .bindings
+            .FPDF_StructTree_GetChildAtIndex(self.handle, index as c_int);
+
+        if handle.is_null() {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        } else {
+            Ok(PdfStructElement::from_pdfium(handle, self.bindings))
+        }
+    }
+
+    /// Returns an iterator over all the top-level [PdfStructElement] objects in this [PdfStructTree].
+    #[inline]
+    pub fn iter(&self) -> PdfStructTreeIterator<'_> {
+        PdfStructTreeIterator::new(self)
+    }
+
+    /// Returns a depth-first iterator over every [PdfStructElement] in this [PdfStructTree],
+    /// including all nested child elements.
+    #[inline]
+    pub fn iter_all(&self) -> PdfStructTreeDepthFirstIterator<'_> {
+        PdfStructTreeDepthFirstIterator::new(self.iter().collect())
+    }
+}
+
+impl<'a> Drop for PdfStructTree<'a> {
+    /// Closes this [PdfStructTree], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDF_StructTree_Close(self.handle);
+    }
+}
+
+/// An iterator over all the top-level [PdfStructElement] objects in a [PdfStructTree].
+pub struct PdfStructTreeIterator<'a> {
+    tree: &'a PdfStructTree<'a>,
+    next_index: PdfStructElementIndex,
+}
+
+impl<'a> PdfStructTreeIterator<'a> {
+    #[inline]
+    pub(crate) fn new(tree: &'a PdfStructTree<'a>) -> Self {
+        PdfStructTreeIterator {
+            tree,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfStructTreeIterator<'a> {
+    type Item = PdfStructElement<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next_index < self.tree.len() {
+            let next = self.tree.get(self.next_index);
+
+            self.next_index += 1;
+
+            if let Ok(next) = next {
+                return Some(next);
+            }
+        }
+
+        None
+    }
+}
+
+/// A depth-first iterator over every [PdfStructElement] in a [PdfStructTree],
+/// including all nested child elements.
+pub struct PdfStructTreeDepthFirstIterator<'a> {
+    pending: Vec<PdfStructElement<'a>>,
+}
+
+impl<'a> PdfStructTreeDepthFirstIterator<'a> {
+    #[inline]
+    pub(crate) fn new(mut roots: Vec<PdfStructElement<'a>>) -> Self {
+        // We pop elements from the end of the pending list, so reverse the roots
+        // to preserve document order.
+
+        roots.reverse();
+
+        PdfStructTreeDepthFirstIterator { pending: roots }
+    }
+}
+
+impl<'a> Iterator for PdfStructTreeDepthFirstIterator<'a> {
+    type Item = PdfStructElement<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.pending.pop()?;
+
+        let mut children = next.children().collect::<Vec<_>>();
+
+        children.reverse();
+
+        self.pending.extend(children);
+
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_structure_tree() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/tagged-structure-test.pdf", None)?;
+
+        let page = document.pages().get(0)?;
+
+        let tree = page.structure_tree()?;
+
+        assert_eq!(tree.len(), 1);
+
+        let root = tree.get(0)?;
+
+        assert_eq!(root.element_type(), Some(PdfStructElementType::Document));
+        assert!(root.parent().is_none());
+        assert!(root.marked_content_ids().is_empty());
+        assert_eq!(root.children_len(), 3);
+
+        let children = root.children().collect::<Vec<_>>();
+
+        assert_eq!(
+            children
+                .iter()
+                .map(|child| child.element_type())
+                .collect::<Vec<_>>(),
+            vec![
+                Some(PdfStructElementType::H1),
+                Some(PdfStructElementType::P),
+                Some(PdfStructElementType::Figure),
+            ]
+        );
+
+        let heading = &children[0];
+
+        assert_eq!(heading.title(), Some("Introduction".to_owned()));
+        assert_eq!(heading.marked_content_ids(), vec![0]);
+        assert_eq!(
+            heading.parent().and_then(|parent| parent.type_name()),
+            Some("Document".to_owned())
+        );
+
+        // Marked content references are counted as children, but are not
+        // themselves structure elements.
+
+        let paragraph = &children[1];
+
+        assert_eq!(paragraph.children_len(), 2);
+        assert_eq!(paragraph.children().count(), 0);
+        assert_eq!(paragraph.marked_content_ids(), vec![1, 2]);
+        assert_eq!(paragraph.language(), Some("fr-FR".to_owned()));
+
+        let figure = &children[2];
+
+        assert_eq!(figure.alt_text(), Some("A blue square".to_owned()));
+        assert_eq!(figure.marked_content_ids(), vec![3]);
+
+        assert_eq!(
+            tree.iter_all()
+                .filter_map(|element| element.type_name())
+                .collect::<Vec<_>>(),
+            vec!["Document", "H1", "P", "Figure"]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,315 @@
+//! Defines the [PdfStructElementAttribute] struct, exposing functionality related to a single
+//! attribute object attached to a `PdfStructElement`.
+
+use crate::bindgen::{
+    FPDF_BOOL, FPDF_OBJECT_ARRAY, FPDF_OBJECT_BOOLEAN, FPDF_OBJECT_NAME, FPDF_OBJECT_NUMBER,
+    FPDF_OBJECT_STRING, FPDF_STRUCTELEMENT_ATTR,
+};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::utils::mem::{create_byte_buffer, get_pdfium_byte_buffer};
+use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use std::os::raw::{c_int, c_ulong, c_void};
+
+#[cfg(any(
+    feature = "pdfium_6490",
+    feature = "pdfium_6555",
+    feature = "pdfium_6569",
+    feature = "pdfium_6611",
+    feature = "pdfium_6666",
+    feature = "pdfium_future"
+))]
+use crate::bindgen::FPDF_STRUCTELEMENT_ATTR_VALUE;
+
+#[cfg(doc)]
+use crate::pdf::document::page::struct_tree::element::PdfStructElement;
+
+/// The value of a single named entry in a [PdfStructElementAttribute].
+#[derive(Debug, Clone, PartialEq)]
+pub enum PdfStructElementAttributeValue {
+    Boolean(bool),
+    Number(f32),
+
+    /// A text string or a name. Pdfium does not distinguish between the two when
+    /// returning attribute values.
+    String(String),
+
+    /// Raw bytes retrieved from a value that could not be decoded as any other type.
+    Blob(Vec<u8>),
+
+    /// An array of values. Arrays can only be inspected when using Pdfium 6490 or later;
+    /// earlier versions return [PdfStructElementAttributeValue::Unsupported] for array values.
+    Array(Vec<PdfStructElementAttributeValue>),
+
+    /// A value of a type that cannot be retrieved from Pdfium, such as a dictionary or stream.
+    Unsupported,
+}
+
+/// A single attribute object attached to a [PdfStructElement].
+///
+/// An attribute object is a dictionary mapping names to values. Attribute objects
+/// specify additional properties of a structure element, such as the placement and
+/// bounding box of a figure, the numbering style of a list, or the row and column span
+/// of a table cell.
+pub struct PdfStructElementAttribute<'a> {
+    handle: FPDF_STRUCTELEMENT_ATTR,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfStructElementAttribute<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        handle: FPDF_STRUCTELEMENT_ATTR,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfStructElementAttribute { handle, bindings }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfStructElementAttribute].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the number of named entries in this [PdfStructElementAttribute].
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.bindings
+            .FPDF_StructElement_Attr_GetCount(self.handle)
+            .max(0) as usize
+    }
+
+    /// Returns `true` if this [PdfStructElementAttribute] contains no named entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the names of all the entries in this [PdfStructElementAttribute].
+    pub fn names(&self) -> Vec<String> {
+        (0..self.len())
+            .filter_map(|index| self.name(index))
+            .collect()
+    }
+
+    /// Returns the name of the entry at the given index in this [PdfStructElementAttribute].
+    pub fn name(&self, index: usize) -> Option<String> {
+        // Retrieving the name from Pdfium is a two-step operation. First, we call
+        // FPDF_StructElement_Attr_GetName() with a null buffer; this will retrieve the length
+        // of the name in bytes. If the call fails, then there is no name at the given index.
+
+        // If the call succeeds, then we reserve a byte buffer of the given length and call
+        // FPDF_StructElement_Attr_GetName() again with a pointer to the buffer; this will
+        // write the name to the buffer as a null-terminated byte string.
+
+        let mut buffer_length: c_ulong = 0;
+
+        if !self
+            .bindings
+            .is_true(self.bindings.FPDF_StructElement_Attr_GetName(
+                self.handle,
+                index as c_int,
+                std::ptr::null_mut(),
+                0,
+                &mut buffer_length,
+            ))
+            || buffer_length == 0
+        {
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        if !self
+            .bindings
+            .is_true(self.bindings.FPDF_StructElement_Attr_GetName(
+                self.handle,
+                index as c_int,
+                buffer.as_mut_ptr() as *mut c_void,
+                buffer_length,
+                &mut buffer_length,
+            ))
+        {
+            return None;
+        }
+
+        let name = String::from_utf8_lossy(&buffer)
+            .trim_end_matches(char::from(0))
+            .to_owned();
+
+        if name.is_empty() {
+            None
+        } else {
+            Some(name)
+        }
+    }
+
+    /// Returns the value of the entry with the given name in this [PdfStructElementAttribute],
+    /// if any.
+    #[cfg(any(
+        feature = "pdfium_6490",
+        feature = "pdfium_6555",
+        feature = "pdfium_6569",
+        feature = "pdfium_6611",
+        feature = "pdfium_6666",
+        feature = "pdfium_future"
+    ))]
+    pub fn value(&self, name: &str) -> Option<PdfStructElementAttributeValue> {
+        let value = self
+            .bindings
+            .FPDF_StructElement_Attr_GetValue(self.handle, name);
+
+        if value.is_null() {
+            None
+        } else {
+            Some(self.get_value(value))
+        }
+    }
+
+    /// Returns the value of the entry with the given name in this [PdfStructElementAttribute],
+    /// if any.
+    #[cfg(any(
+        feature = "pdfium_5961",
+        feature = "pdfium_6015",
+        feature = "pdfium_6043",
+        feature = "pdfium_6084",
+        feature = "pdfium_6110",
+        feature = "pdfium_6124",
+        feature = "pdfium_6164",
+        feature = "pdfium_6259",
+        feature = "pdfium_6295",
+        feature = "pdfium_6337",
+        feature = "pdfium_6406"
+    ))]
+    pub fn value(&self, name: &str) -> Option<PdfStructElementAttributeValue> {
+        let bindings = self.bindings;
+
+        let handle = self.handle;
+
+        match bindings.FPDF_StructElement_Attr_GetType(handle, name) as u32 {
+            FPDF_OBJECT_BOOLEAN => {
+                let mut value: FPDF_BOOL = 0;
+
+                if bindings.is_true(
+                    bindings.FPDF_StructElement_Attr_GetBooleanValue(handle, name, &mut value),
+                ) {
+                    Some(PdfStructElementAttributeValue::Boolean(
+                        bindings.is_true(value),
+                    ))
+                } else {
+                    None
+                }
+            }
+            FPDF_OBJECT_NUMBER => {
+                let mut value = 0.0;
+
+                if bindings.is_true(
+                    bindings.FPDF_StructElement_Attr_GetNumberValue(handle, name, &mut value),
+                ) {
+                    Some(PdfStructElementAttributeValue::Number(value))
+                } else {
+                    None
+                }
+            }
+            FPDF_OBJECT_STRING | FPDF_OBJECT_NAME => {
+                get_pdfium_byte_buffer(|buffer, length, out_length| {
+                    bindings.FPDF_StructElement_Attr_GetStringValue(
+                        handle, name, buffer, length, out_length,
+                    )
+                })
+                .map(|bytes| {
+                    PdfStructElementAttributeValue::String(
+                        get_string_from_pdfium_utf16le_bytes(bytes).unwrap_or_default(),
+                    )
+                })
+            }
+            FPDF_OBJECT_ARRAY => Some(PdfStructElementAttributeValue::Unsupported),
+            _ => get_pdfium_byte_buffer(|buffer, length, out_length| {
+                bindings
+                    .FPDF_StructElement_Attr_GetBlobValue(handle, name, buffer, length, out_length)
+            })
+            .map(PdfStructElementAttributeValue::Blob),
+        }
+    }
+
+    /// Returns all the named entries in this [PdfStructElementAttribute], along with their values.
+    pub fn entries(&self) -> Vec<(String, PdfStructElementAttributeValue)> {
+        self.names()
+            .into_iter()
+            .filter_map(|name| self.value(name.as_str()).map(|value| (name, value)))
+            .collect()
+    }
+
+    /// Converts the given `FPDF_STRUCTELEMENT_ATTR_VALUE` handle into a
+    /// [PdfStructElementAttributeValue], recursing into array values as necessary.
+    #[cfg(any(
+        feature = "pdfium_6490",
+        feature = "pdfium_6555",
+        feature = "pdfium_6569",
+        feature = "pdfium_6611",
+        feature = "pdfium_6666",
+        feature = "pdfium_future"
+    ))]
+    fn get_value(&self, value: FPDF_STRUCTELEMENT_ATTR_VALUE) -> PdfStructElementAttributeValue {
+        let bindings = self.bindings;
+
+        match bindings.FPDF_StructElement_Attr_GetType(value) as u32 {
+            FPDF_OBJECT_BOOLEAN => {
+                let mut result: FPDF_BOOL = 0;
+
+                if bindings
+                    .is_true(bindings.FPDF_StructElement_Attr_GetBooleanValue(value, &mut result))
+                {
+                    PdfStructElementAttributeValue::Boolean(bindings.is_true(result))
+                } else {
+                    PdfStructElementAttributeValue::Unsupported
+                }
+            }
+            FPDF_OBJECT_NUMBER => {
+                let mut result = 0.0;
+
+                if bindings
+                    .is_true(bindings.FPDF_StructElement_Attr_GetNumberValue(value, &mut result))
+                {
+                    PdfStructElementAttributeValue::Number(result)
+                } else {
+                    PdfStructElementAttributeValue::Unsupported
+                }
+            }
+            FPDF_OBJECT_STRING | FPDF_OBJECT_NAME => {
+                get_pdfium_byte_buffer(|buffer, length, out_length| {
+                    bindings
+                        .FPDF_StructElement_Attr_GetStringValue(value, buffer, length, out_length)
+                })
+                .map(|bytes| {
+                    PdfStructElementAttributeValue::String(
+                        get_string_from_pdfium_utf16le_bytes(bytes).unwrap_or_default(),
+                    )
+                })
+                .unwrap_or(PdfStructElementAttributeValue::Unsupported)
+            }
+            FPDF_OBJECT_ARRAY => {
+                let count = bindings.FPDF_StructElement_Attr_CountChildren(value);
+
+                PdfStructElementAttributeValue::Array(
+                    (0..count)
+                        .map(|index| {
+                            let child =
+                                bindings.FPDF_StructElement_Attr_GetChildAtIndex(value, index);
+
+                            if child.is_null() {
+                                PdfStructElementAttributeValue::Unsupported
+                            } else {
+                                self.get_value(child)
+                            }
+                        })
+                        .collect(),
+                )
+            }
+            _ => get_pdfium_byte_buffer(|buffer, length, out_length| {
+                bindings.FPDF_StructElement_Attr_GetBlobValue(value, buffer, length, out_length)
+            })
+            .map(PdfStructElementAttributeValue::Blob)
+            .unwrap_or(PdfStructElementAttributeValue::Unsupported),
+        }
+    }
+}
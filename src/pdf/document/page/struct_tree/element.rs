@@ -0,0 +1,568 @@
+//! Defines the [PdfStructElement] struct, exposing functionality related to a single
+//! element in a `PdfStructTree`.
+
+use crate::bindgen::FPDF_STRUCTELEMENT;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::page::struct_tree::attribute::PdfStructElementAttribute;
+use crate::pdf::document::page::struct_tree::PdfStructElementIndex;
+use crate::utils::mem::create_byte_buffer;
+use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use std::os::raw::{c_int, c_ulong, c_void};
+
+#[cfg(doc)]
+use crate::pdf::document::page::struct_tree::PdfStructTree;
+
+/// The structure type of a single [PdfStructElement], as defined in section 14.8.4
+/// of the PDF Reference Manual version 1.7.
+///
+/// Documents may define their own custom structure types and map them onto the standard
+/// structure types using a role map. Pdfium does not expose the role map, so custom
+/// structure types are returned as [PdfStructElementType::Other].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PdfStructElementType {
+    // Grouping elements, section 14.8.4.2.
+    Document,
+    Part,
+    Art,
+    Sect,
+    Div,
+    BlockQuote,
+    Caption,
+    TOC,
+    TOCI,
+    Index,
+    NonStruct,
+    Private,
+
+    // Block-level structure elements, section 14.8.4.3.
+    P,
+    H,
+    H1,
+    H2,
+    H3,
+    H4,
+    H5,
+    H6,
+    L,
+    LI,
+    Lbl,
+    LBody,
+    Table,
+    TR,
+    TH,
+    TD,
+    THead,
+    TBody,
+    TFoot,
+
+    // Inline-level structure elements, section 14.8.4.4.
+    Span,
+    Quote,
+    Note,
+    Reference,
+    BibEntry,
+    Code,
+    Link,
+    Annot,
+    Ruby,
+    RB,
+    RT,
+    RP,
+    Warichu,
+    WT,
+    WP,
+
+    // Illustration elements, section 14.8.4.5.
+    Figure,
+    Formula,
+    Form,
+
+    /// A structure type not defined by the PDF specification.
+    Other(String),
+}
+
+impl PdfStructElementType {
+    pub(crate) fn from_name(name: &str) -> Self {
+        match name {
+            "Document" => PdfStructElementType::Document,
+            "Part" => PdfStructElementType::Part,
+            "Art" => PdfStructElementType::Art,
+            "Sect" => PdfStructElementType::Sect,
+            "Div" => PdfStructElementType::Div,
+            "BlockQuote" => PdfStructElementType::BlockQuote,
+            "Caption" => PdfStructElementType::Caption,
+            "TOC" => PdfStructElementType::TOC,
+            "TOCI" => PdfStructElementType::TOCI,
+            "Index" => PdfStructElementType::Index,
+            "NonStruct" => PdfStructElementType::NonStruct,
+            "Private" => PdfStructElementType::Private,
+            "P" => PdfStructElementType::P,
+            "H" => PdfStructElementType::H,
+            "H1" => PdfStructElementType::H1,
+            "H2" => PdfStructElementType::H2,
+            "H3" => PdfStructElementType::H3,
+            "H4" => PdfStructElementType::H4,
+            "H5" => PdfStructElementType::H5,
+            "H6" => PdfStructElementType::H6,
+            "L" => PdfStructElementType::L,
+            "LI" => PdfStructElementType::LI,
+            "Lbl" => PdfStructElementType::Lbl,
+            "LBody" => PdfStructElementType::LBody,
+            "Table" => PdfStructElementType::Table,
+            "TR" => PdfStructElementType::TR,
+            "TH" => PdfStructElementType::TH,
+            "TD" => PdfStructElementType::TD,
+            "THead" => PdfStructElementType::THead,
+            "TBody" => PdfStructElementType::TBody,
+            "TFoot" => PdfStructElementType::TFoot,
+            "Span" => PdfStructElementType::Span,
+            "Quote" => PdfStructElementType::Quote,
+            "Note" => PdfStructElementType::Note,
+            "Reference" => PdfStructElementType::Reference,
+            "BibEntry" => PdfStructElementType::BibEntry,
+            "Code" => PdfStructElementType::Code,
+            "Link" => PdfStructElementType::Link,
+            "Annot" => PdfStructElementType::Annot,
+            "Ruby" => PdfStructElementType::Ruby,
+            "RB" => PdfStructElementType::RB,
+            "RT" => PdfStructElementType::RT,
+            "RP" => PdfStructElementType::RP,
+            "Warichu" => PdfStructElementType::Warichu,
+            "WT" => PdfStructElementType::WT,
+            "WP" => PdfStructElementType::WP,
+            "Figure" => PdfStructElementType::Figure,
+            "Formula" => PdfStructElementType::Formula,
+            "Form" => PdfStructElementType::Form,
+            _ => PdfStructElementType::Other(name.to_owned()),
+        }
+    }
+
+    /// Returns the name of this [PdfStructElementType], as it appears in the /S entry
+    /// of a structure element dictionary.
+    pub fn as_name(&self) -> &str {
+        match self {
+            PdfStructElementType::Document => "Document",
+            PdfStructElementType::Part => "Part",
+            PdfStructElementType::Art => "Art",
+            PdfStructElementType::Sect => "Sect",
+            PdfStructElementType::Div => "Div",
+            PdfStructElementType::BlockQuote => "BlockQuote",
+            PdfStructElementType::Caption => "Caption",
+            PdfStructElementType::TOC => "TOC",
+            PdfStructElementType::TOCI => "TOCI",
+            PdfStructElementType::Index => "Index",
+            PdfStructElementType::NonStruct => "NonStruct",
+            PdfStructElementType::Private => "Private",
+            PdfStructElementType::P => "P",
+            PdfStructElementType::H => "H",
+            PdfStructElementType::H1 => "H1",
+            PdfStructElementType::H2 => "H2",
+            PdfStructElementType::H3 => "H3",
+            PdfStructElementType::H4 => "H4",
+            PdfStructElementType::H5 => "H5",
+            PdfStructElementType::H6 => "H6",
+            PdfStructElementType::L => "L",
+            PdfStructElementType::LI => "LI",
+            PdfStructElementType::Lbl => "Lbl",
+            PdfStructElementType::LBody => "LBody",
+            PdfStructElementType::Table => "Table",
+            PdfStructElementType::TR => "TR",
+            PdfStructElementType::TH => "TH",
+            PdfStructElementType::TD => "TD",
+            PdfStructElementType::THead => "THead",
+            PdfStructElementType::TBody => "TBody",
+            PdfStructElementType::TFoot => "TFoot",
+            PdfStructElementType::Span => "Span",
+            PdfStructElementType::Quote => "Quote",
+            PdfStructElementType::Note => "Note",
+            PdfStructElementType::Reference => "Reference",
+            PdfStructElementType::BibEntry => "BibEntry",
+            PdfStructElementType::Code => "Code",
+            PdfStructElementType::Link => "Link",
+            PdfStructElementType::Annot => "Annot",
+            PdfStructElementType::Ruby => "Ruby",
+            PdfStructElementType::RB => "RB",
+            PdfStructElementType::RT => "RT",
+            PdfStructElementType::RP => "RP",
+            PdfStructElementType::Warichu => "Warichu",
+            PdfStructElementType::WT => "WT",
+            PdfStructElementType::WP => "WP",
+            PdfStructElementType::Figure => "Figure",
+            PdfStructElementType::Formula => "Formula",
+            PdfStructElementType::Form => "Form",
+            PdfStructElementType::Other(name) => name.as_str(),
+        }
+    }
+
+    /// Returns `true` if this [PdfStructElementType] is one of the heading types
+    /// `H`, `H1`, `H2`, `H3`, `H4`, `H5`, or `H6`.
+    pub fn is_heading(&self) -> bool {
+        self.heading_level().is_some()
+    }
+
+    /// Returns the heading level of this [PdfStructElementType], if it is a heading type.
+    /// The untitled heading type `H` has a heading level of 0.
+    pub fn heading_level(&self) -> Option<u8> {
+        match self {
+            PdfStructElementType::H => Some(0),
+            PdfStructElementType::H1 => Some(1),
+            PdfStructElementType::H2 => Some(2),
+            PdfStructElementType::H3 => Some(3),
+            PdfStructElementType::H4 => Some(4),
+            PdfStructElementType::H5 => Some(5),
+            PdfStructElementType::H6 => Some(6),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this [PdfStructElementType] is one of the table element types
+    /// `Table`, `TR`, `TH`, `TD`, `THead`, `TBody`, or `TFoot`.
+    pub fn is_table_element(&self) -> bool {
+        matches!(
+            self,
+            PdfStructElementType::Table
+                | PdfStructElementType::TR
+                | PdfStructElementType::TH
+                | PdfStructElementType::TD
+                | PdfStructElementType::THead
+                | PdfStructElementType::TBody
+                | PdfStructElementType::TFoot
+        )
+    }
+}
+
+/// A single element in a [PdfStructTree].
+pub struct PdfStructElement<'a> {
+    handle: FPDF_STRUCTELEMENT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfStructElement<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        handle: FPDF_STRUCTELEMENT,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfStructElement { handle, bindings }
+    }
+
+    /// Returns the internal `FPDF_STRUCTELEMENT` handle for this [PdfStructElement].
+    #[inline]
+    #[allow(dead_code)]
+    pub(crate) fn handle(&self) -> FPDF_STRUCTELEMENT {
+        self.handle
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfStructElement].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the raw structure type name of this [PdfStructElement], as it appears in the /S
+    /// entry of the structure element dictionary, if any.
+    #[inline]
+    pub fn type_name(&self) -> Option<String> {
+        self.get_string(|handle, buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetType(handle, buffer, length)
+        })
+    }
+
+    /// Returns the [PdfStructElementType] of this [PdfStructElement], if any.
+    #[inline]
+    pub fn element_type(&self) -> Option<PdfStructElementType> {
+        self.type_name()
+            .map(|name| PdfStructElementType::from_name(name.as_str()))
+    }
+
+    /// Returns the object type of this [PdfStructElement], as it appears in the /Type entry
+    /// of the structure element dictionary, if any. This is usually `StructElem`.
+    #[inline]
+    pub fn object_type(&self) -> Option<String> {
+        self.get_string(|handle, buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetObjType(handle, buffer, length)
+        })
+    }
+
+    /// Returns the title of this [PdfStructElement], if any.
+    #[inline]
+    pub fn title(&self) -> Option<String> {
+        self.get_string(|handle, buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetTitle(handle, buffer, length)
+        })
+    }
+
+    /// Returns the unique identifier of this [PdfStructElement], if any.
+    #[inline]
+    pub fn id(&self) -> Option<String> {
+        self.get_string(|handle, buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetID(handle, buffer, length)
+        })
+    }
+
+    /// Returns the alternate description of this [PdfStructElement], if any. Alternate
+    /// descriptions are typically provided for figures and formulae so that their meaning
+    /// can be conveyed to users who cannot see them.
+    #[inline]
+    pub fn alt_text(&self) -> Option<String> {
+        self.get_string(|handle, buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetAltText(handle, buffer, length)
+        })
+    }
+
+    /// Returns the replacement text of this [PdfStructElement], if any. The replacement text
+    /// should be used in place of the element's content when extracting text.
+    #[inline]
+    pub fn actual_text(&self) -> Option<String> {
+        self.get_string(|handle, buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetActualText(handle, buffer, length)
+        })
+    }
+
+    /// Returns the natural language of the content of this [PdfStructElement], if any,
+    /// expressed as a language identifier such as `en-US`.
+    #[inline]
+    pub fn language(&self) -> Option<String> {
+        self.get_string(|handle, buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetLang(handle, buffer, length)
+        })
+    }
+
+    /// Returns the value of the attribute with the given name in this [PdfStructElement],
+    /// if the attribute exists and has a string or name value.
+    ///
+    /// Only attributes defined directly in the structure element dictionary can be
+    /// retrieved using this function. To inspect attributes defined in attribute objects,
+    /// use the [PdfStructElement::attributes()] function.
+    pub fn string_attribute(&self, name: &str) -> Option<String> {
+        self.get_string(|handle, buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetStringAttribute(handle, name, buffer, length)
+        })
+    }
+
+    /// Returns all the marked content IDs associated with this [PdfStructElement].
+    ///
+    /// Marked content IDs link this structure element to content on the page;
+    /// the same marked content ID will be returned by `PdfPageObjectCommon::marked_content_id()`
+    /// for every page object belonging to this structure element.
+    pub fn marked_content_ids(&self) -> Vec<i32> {
+        let count = self
+            .bindings
+            .FPDF_StructElement_GetMarkedContentIdCount(self.handle);
+
+        if count <= 0 {
+            // Fall back to the single marked content ID exposed by older versions of Pdfium.
+
+            let id = self
+                .bindings
+                .FPDF_StructElement_GetMarkedContentID(self.handle);
+
+            return if id >= 0 { vec![id] } else { Vec::new() };
+        }
+
+        (0..count)
+            .map(|index| {
+                self.bindings
+                    .FPDF_StructElement_GetMarkedContentIdAtIndex(self.handle, index)
+            })
+            .filter(|id| *id >= 0)
+            .collect()
+    }
+
+    /// Returns the parent of this [PdfStructElement], if any. Top-level elements in a
+    /// [PdfStructTree] have no parent.
+    pub fn parent(&self) -> Option<PdfStructElement<'a>> {
+        let handle = self.bindings.FPDF_StructElement_GetParent(self.handle);
+
+        if handle.is_null() {
+            None
+        } else {
+            Some(PdfStructElement::from_pdfium(handle, self.bindings))
+        }
+    }
+
+    /// Returns the number of direct children of this [PdfStructElement].
+    ///
+    /// Not every child is itself a structure element; children may also be references to
+    /// marked content on the page. Only children that are structure elements are returned
+    /// by [PdfStructElement::children()].
+    #[inline]
+    pub fn children_len(&self) -> PdfStructElementIndex {
+        self.bindings
+            .FPDF_StructElement_CountChildren(self.handle)
+            .max(0) as PdfStructElementIndex
+    }
+
+    /// Returns the direct child of this [PdfStructElement] at the given index.
+    pub fn child(&self, index: PdfStructElementIndex) -> Result<PdfStructElement<'a>, PdfiumError> {
+        if index >= self.children_len() {
+            return Err(PdfiumError::StructElementIndexOutOfBounds);
+        }
+
+        let handle = self
+            .bindings
+            .FPDF_StructElement_GetChildAtIndex(self.handle, index as c_int);
+
+        if handle.is_null() {
+            // The child at this index is a marked content reference rather than
+            // a structure element.
+
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        } else {
+            Ok(PdfStructElement::from_pdfium(handle, self.bindings))
+        }
+    }
+
+    /// Returns an iterator over all the direct children of this [PdfStructElement]
+    /// that are themselves structure elements.
+    #[inline]
+    pub fn children(&self) -> PdfStructElementChildrenIterator<'a> {
+        PdfStructElementChildrenIterator::new(self.handle, self.children_len(), self.bindings)
+    }
+
+    /// Returns all the attribute objects attached to this [PdfStructElement].
+    ///
+    /// Each attribute object is a dictionary of named values, typically scoped to a single
+    /// owner such as `Layout`, `List`, `Table`, or `PrintField`.
+    pub fn attributes(&self) -> Vec<PdfStructElementAttribute<'a>> {
+        let count = self
+            .bindings
+            .FPDF_StructElement_GetAttributeCount(self.handle);
+
+        (0..count)
+            .filter_map(|index| {
+                let handle = self
+                    .bindings
+                    .FPDF_StructElement_GetAttributeAtIndex(self.handle, index);
+
+                if handle.is_null() {
+                    None
+                } else {
+                    Some(PdfStructElementAttribute::from_pdfium(
+                        handle,
+                        self.bindings,
+                    ))
+                }
+            })
+            .collect()
+    }
+
+    /// Retrieves a UTF16-LE string value from Pdfium using the given accessor function.
+    fn get_string(
+        &self,
+        accessor: impl Fn(FPDF_STRUCTELEMENT, *mut c_void, c_ulong) -> c_ulong,
+    ) -> Option<String> {
+        // Retrieving a string value from Pdfium is a two-step operation. First, we call
+        // the accessor function with a null buffer; this will retrieve the length of
+        // the value in bytes. If the length is zero, then there is no value.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call the accessor function again with a pointer to the buffer;
+        // this will write the value to the buffer in UTF16-LE format.
+
+        let buffer_length = accessor(self.handle, std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            // No value is defined.
+
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = accessor(
+            self.handle,
+            buffer.as_mut_ptr() as *mut c_void,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        get_string_from_pdfium_utf16le_bytes(buffer)
+    }
+}
+
+/// An iterator over all the direct children of a [PdfStructElement] that are
+/// themselves structure elements.
+pub struct PdfStructElementChildrenIterator<'a> {
+    handle: FPDF_STRUCTELEMENT,
+    len: PdfStructElementIndex,
+    next_index: PdfStructElementIndex,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfStructElementChildrenIterator<'a> {
+    #[inline]
+    pub(crate) fn new(
+        handle: FPDF_STRUCTELEMENT,
+        len: PdfStructElementIndex,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfStructElementChildrenIterator {
+            handle,
+            len,
+            next_index: 0,
+            bindings,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfStructElementChildrenIterator<'a> {
+    type Item = PdfStructElement<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next_index < self.len {
+            let handle = self
+                .bindings
+                .FPDF_StructElement_GetChildAtIndex(self.handle, self.next_index as c_int);
+
+            self.next_index += 1;
+
+            // Skip over children that are marked content references rather than
+            // structure elements.
+
+            if !handle.is_null() {
+                return Some(PdfStructElement::from_pdfium(handle, self.bindings));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_struct_element_type_round_trip() {
+        for name in ["Document", "H1", "H6", "Figure", "Table", "TD", "LBody"] {
+            assert_eq!(PdfStructElementType::from_name(name).as_name(), name);
+        }
+
+        assert_eq!(
+            PdfStructElementType::from_name("MyCustomHeading"),
+            PdfStructElementType::Other("MyCustomHeading".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_struct_element_type_classification() {
+        assert_eq!(PdfStructElementType::H3.heading_level(), Some(3));
+        assert!(PdfStructElementType::H.is_heading());
+        assert!(!PdfStructElementType::P.is_heading());
+        assert!(PdfStructElementType::TH.is_table_element());
+        assert!(!PdfStructElementType::Figure.is_table_element());
+    }
+}
@@ -6,6 +6,8 @@ pub mod chars;
 pub mod search;
 pub mod segment;
 pub mod segments;
+pub mod web_link;
+pub mod web_links;
 
 use crate::bindgen::{FPDF_TEXTPAGE, FPDF_WCHAR, FPDF_WIDESTRING};
 use crate::bindings::PdfiumLibraryBindings;
@@ -19,6 +21,7 @@ use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
 use crate::pdf::document::page::text::chars::{PdfPageTextCharIndex, PdfPageTextChars};
 use crate::pdf::document::page::text::search::{PdfPageTextSearch, PdfSearchOptions};
 use crate::pdf::document::page::text::segments::PdfPageTextSegments;
+use crate::pdf::document::page::text::web_links::PdfPageWebLinks;
 use crate::pdf::document::page::PdfPage;
 use crate::pdf::points::PdfPoints;
 use crate::pdf::rect::PdfRect;
@@ -39,6 +42,9 @@ use std::ptr::null_mut;
 /// Use the [PdfPageText::search()] function to initialise a new [PdfPageTextSearch] object,
 /// yielding the results of searching for a target string within the character collection.
 ///
+/// Use the [PdfPageText::web_links()] function to detect URLs and email addresses
+/// in the character collection.
+///
 /// In complex custom layouts, the order in which characters are defined in the document
 /// and the order in which they appear visually during rendering (and thus the order in
 /// which they are read by a user) may not necessarily match.
@@ -111,6 +117,7 @@ impl<'a> PdfPageText<'a> {
     #[inline]
     pub fn chars(&self) -> PdfPageTextChars {
         PdfPageTextChars::new(
+            self.page.document_handle(),
             self.page.page_handle(),
             self.handle,
             0,
@@ -245,6 +252,7 @@ impl<'a> PdfPageText<'a> {
             chars.get_char_near_point(rect.right, tolerance_x, center_height, tolerance_y),
         ) {
             (Some(start), Some(end)) => Ok(PdfPageTextChars::new(
+                self.page.document_handle(),
                 self.page.page_handle(),
                 self.handle,
                 start.index() as i32,
@@ -428,6 +436,18 @@ impl<'a> PdfPageText<'a> {
             self.bindings,
         )
     }
+
+    /// Scans the characters in this [PdfPageText] object for spans of text that look like
+    /// URLs or email addresses, returning a new [PdfPageWebLinks] collection containing
+    /// every detected web link.
+    #[inline]
+    pub fn web_links(&self) -> PdfPageWebLinks<'_> {
+        PdfPageWebLinks::from_pdfium(
+            self.bindings.FPDFLink_LoadWebLinks(self.handle),
+            self,
+            self.bindings,
+        )
+    }
 }
 
 impl<'a> Display for PdfPageText<'a> {
@@ -509,6 +529,41 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_web_links() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(700.0),
+            "Visit https://example.com/docs for details",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        let page_text = page.text()?;
+
+        let web_links = page_text.web_links();
+
+        assert_eq!(web_links.len(), 1);
+
+        let web_link = web_links.get(0)?;
+
+        assert_eq!(web_link.url(), "https://example.com/docs");
+        assert_eq!(web_link.rects().len(), 1);
+        assert_eq!(web_link.char_range()?, 6..30);
+
+        Ok(())
+    }
+
     fn test_one_overlapping_text_object_results(
         object: &PdfPageObject,
         page_text: &PdfPageText,
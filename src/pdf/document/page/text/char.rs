@@ -1,7 +1,7 @@
 //! Defines the [PdfPageTextChar] struct, exposing functionality related to a single character
 //! in a `PdfPageTextChars` collection.
 
-use crate::bindgen::{FPDF_PAGE, FPDF_TEXTPAGE, FS_MATRIX, FS_RECTF};
+use crate::bindgen::{FPDF_DOCUMENT, FPDF_PAGE, FPDF_TEXTPAGE, FS_MATRIX, FS_RECTF};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::color::PdfColor;
@@ -19,6 +19,7 @@ use crate::pdf::document::page::object::text::PdfPageTextObject;
 
 /// A single character in a `PdfPageTextChars` collection.
 pub struct PdfPageTextChar<'a> {
+    document_handle: FPDF_DOCUMENT,
     page_handle: FPDF_PAGE,
     text_page_handle: FPDF_TEXTPAGE,
     index: i32,
@@ -28,12 +29,14 @@ pub struct PdfPageTextChar<'a> {
 impl<'a> PdfPageTextChar<'a> {
     #[inline]
     pub(crate) fn from_pdfium(
+        document_handle: FPDF_DOCUMENT,
         page_handle: FPDF_PAGE,
         text_page_handle: FPDF_TEXTPAGE,
         index: i32,
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Self {
         PdfPageTextChar {
+            document_handle,
             page_handle,
             text_page_handle,
             index,
@@ -309,6 +312,7 @@ impl<'a> PdfPageTextChar<'a> {
         } else {
             Ok(PdfPageTextObject::from_pdfium(
                 object_handle,
+                self.document_handle,
                 Some(self.page_handle),
                 None,
                 self.bindings,
@@ -16,6 +16,7 @@ use std::os::raw::c_int;
 pub type PdfPageTextCharIndex = usize;
 
 pub struct PdfPageTextChars<'a> {
+    document_handle: FPDF_DOCUMENT,
     page_handle: FPDF_PAGE,
     text_page_handle: FPDF_TEXTPAGE,
     source_page: Option<PdfPage<'a>>,
@@ -27,6 +28,7 @@ pub struct PdfPageTextChars<'a> {
 impl<'a> PdfPageTextChars<'a> {
     #[inline]
     pub(crate) fn new(
+        document_handle: FPDF_DOCUMENT,
         page_handle: FPDF_PAGE,
         text_page_handle: FPDF_TEXTPAGE,
         start: i32,
@@ -34,6 +36,7 @@ impl<'a> PdfPageTextChars<'a> {
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Self {
         PdfPageTextChars {
+            document_handle,
             page_handle,
             text_page_handle,
             source_page: None,
@@ -70,6 +73,7 @@ impl<'a> PdfPageTextChars<'a> {
         let text_page_handle = bindings.FPDFText_LoadPage(page.page_handle());
 
         PdfPageTextChars {
+            document_handle,
             page_handle,
             text_page_handle,
             source_page: Some(page),
@@ -120,6 +124,7 @@ impl<'a> PdfPageTextChars<'a> {
             Err(PdfiumError::CharIndexOutOfBounds)
         } else {
             Ok(PdfPageTextChar::from_pdfium(
+                self.document_handle,
                 self.page_handle,
                 self.text_page_handle,
                 index,
@@ -0,0 +1,155 @@
+//! Defines the [PdfPageWebLink] struct, exposing functionality related to a single
+//! web link detected in the text of a [PdfPage].
+
+use crate::bindgen::FS_RECTF;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::page::text::chars::PdfPageTextCharIndex;
+use crate::pdf::document::page::text::segments::PdfPageTextSegments;
+use crate::pdf::document::page::text::web_links::{PdfPageWebLinkIndex, PdfPageWebLinks};
+use crate::pdf::rect::PdfRect;
+use crate::utils::mem::create_sized_buffer;
+use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use bytemuck::cast_slice;
+use std::ops::Range;
+use std::os::raw::{c_double, c_int};
+
+#[cfg(doc)]
+use crate::pdf::document::page::PdfPage;
+
+/// A single web link detected in the text of a [PdfPage].
+///
+/// A web link is a span of page text that looks like a URL or an email address.
+/// Unlike a link annotation, a web link is not defined in the page itself,
+/// and so is not clickable in most viewers.
+pub struct PdfPageWebLink<'a> {
+    links: &'a PdfPageWebLinks<'a>,
+    index: PdfPageWebLinkIndex,
+}
+
+impl<'a> PdfPageWebLink<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(links: &'a PdfPageWebLinks<'a>, index: PdfPageWebLinkIndex) -> Self {
+        PdfPageWebLink { links, index }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfPageWebLink].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.links.bindings()
+    }
+
+    /// Returns the index of this [PdfPageWebLink] in its containing [PdfPageWebLinks] collection.
+    #[inline]
+    pub fn index(&self) -> PdfPageWebLinkIndex {
+        self.index
+    }
+
+    /// Returns the URL of this [PdfPageWebLink].
+    ///
+    /// Pdfium normalizes detected URLs, so the returned value may differ from the page text
+    /// covered by this web link. For example, an `http://` scheme is added to URLs
+    /// starting with `www.`, and a `mailto:` scheme is added to email addresses.
+    pub fn url(&self) -> String {
+        // Retrieving the URL from Pdfium is a two-step operation. First, we call
+        // FPDFLink_GetURL() with a null buffer; this will retrieve the length of
+        // the URL in 16-bit code units, including a trailing nul.
+
+        // We then reserve a buffer of the given length and call FPDFLink_GetURL() again
+        // with a pointer to the buffer; this will write the URL to the buffer in UTF16-LE format.
+
+        let buffer_length = self.bindings().FPDFLink_GetURL(
+            self.links.handle(),
+            self.index as c_int,
+            std::ptr::null_mut(),
+            0,
+        );
+
+        if buffer_length <= 0 {
+            return String::new();
+        }
+
+        let mut buffer = create_sized_buffer(buffer_length as usize);
+
+        let result = self.bindings().FPDFLink_GetURL(
+            self.links.handle(),
+            self.index as c_int,
+            buffer.as_mut_ptr(),
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        get_string_from_pdfium_utf16le_bytes(cast_slice(buffer.as_slice()).to_vec())
+            .unwrap_or_default()
+    }
+
+    /// Returns the bounding rectangles of the page text covered by this [PdfPageWebLink].
+    /// A web link that wraps across multiple lines of text will have one rectangle per line.
+    pub fn rects(&self) -> Vec<PdfRect> {
+        let count = self
+            .bindings()
+            .FPDFLink_CountRects(self.links.handle(), self.index as c_int);
+
+        (0..count)
+            .filter_map(|rect_index| {
+                let mut left: c_double = 0.0;
+                let mut top: c_double = 0.0;
+                let mut right: c_double = 0.0;
+                let mut bottom: c_double = 0.0;
+
+                let result = self.bindings().FPDFLink_GetRect(
+                    self.links.handle(),
+                    self.index as c_int,
+                    rect_index,
+                    &mut left,
+                    &mut top,
+                    &mut right,
+                    &mut bottom,
+                );
+
+                if self.bindings().is_true(result) {
+                    Some(PdfRect::from_pdfium(FS_RECTF {
+                        left: left as f32,
+                        top: top as f32,
+                        right: right as f32,
+                        bottom: bottom as f32,
+                    }))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the range of character indices in the containing `PdfPageText` object
+    /// covered by this [PdfPageWebLink].
+    pub fn char_range(&self) -> Result<Range<PdfPageTextCharIndex>, PdfiumError> {
+        let mut start: c_int = 0;
+        let mut count: c_int = 0;
+
+        if self
+            .bindings()
+            .is_true(self.bindings().FPDFLink_GetTextRange(
+                self.links.handle(),
+                self.index as c_int,
+                &mut start,
+                &mut count,
+            ))
+        {
+            let start = start as PdfPageTextCharIndex;
+
+            Ok(start..start + count as PdfPageTextCharIndex)
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Returns the `PdfPageTextSegment` text segments covered by this [PdfPageWebLink].
+    pub fn segments(&self) -> Result<PdfPageTextSegments<'a>, PdfiumError> {
+        self.char_range()
+            .map(|range| self.links.text().segments_subset(range.start, range.len()))
+    }
+}
@@ -0,0 +1,141 @@
+//! Defines the [PdfPageWebLinks] struct, a collection of all the web links detected
+//! in the text of a single [PdfPage].
+
+use crate::bindgen::FPDF_PAGELINK;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
+use crate::pdf::document::page::text::web_link::PdfPageWebLink;
+use crate::pdf::document::page::text::PdfPageText;
+use std::ops::{Range, RangeInclusive};
+
+#[cfg(doc)]
+use crate::pdf::document::page::PdfPage;
+
+pub type PdfPageWebLinkIndex = usize;
+
+/// A collection of all the web links detected in the text of a single [PdfPage].
+///
+/// Pdfium detects web links by scanning the page text for character sequences that look
+/// like URLs or email addresses. Detected web links are distinct from the link annotations
+/// defined in the page itself, which can be retrieved using the `PdfPage::links()` function.
+pub struct PdfPageWebLinks<'a> {
+    handle: FPDF_PAGELINK,
+    text: &'a PdfPageText<'a>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageWebLinks<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        handle: FPDF_PAGELINK,
+        text: &'a PdfPageText<'a>,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPageWebLinks {
+            handle,
+            text,
+            bindings,
+        }
+    }
+
+    /// Returns the internal `FPDF_PAGELINK` handle for this [PdfPageWebLinks] collection.
+    #[inline]
+    pub(crate) fn handle(&self) -> FPDF_PAGELINK {
+        self.handle
+    }
+
+    /// Returns the [PdfPageText] object from which the web links in this
+    /// [PdfPageWebLinks] collection were detected.
+    #[inline]
+    pub(crate) fn text(&self) -> &'a PdfPageText<'a> {
+        self.text
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfPageWebLinks] collection.
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the number of web links detected in the containing [PdfPage].
+    #[inline]
+    pub fn len(&self) -> PdfPageWebLinkIndex {
+        // FPDFLink_CountWebLinks() returns -1 on error.
+
+        self.bindings.FPDFLink_CountWebLinks(self.handle).max(0) as PdfPageWebLinkIndex
+    }
+
+    /// Returns `true` if this [PdfPageWebLinks] collection is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a Range from `0..(number of web links)` for this [PdfPageWebLinks] collection.
+    #[inline]
+    pub fn as_range(&self) -> Range<PdfPageWebLinkIndex> {
+        0..self.len()
+    }
+
+    /// Returns an inclusive Range from `0..=(number of web links - 1)` for this
+    /// [PdfPageWebLinks] collection.
+    #[inline]
+    pub fn as_range_inclusive(&self) -> RangeInclusive<PdfPageWebLinkIndex> {
+        if self.is_empty() {
+            0..=0
+        } else {
+            0..=(self.len() - 1)
+        }
+    }
+
+    /// Returns a single [PdfPageWebLink] from this [PdfPageWebLinks] collection.
+    pub fn get(&self, index: PdfPageWebLinkIndex) -> Result<PdfPageWebLink<'_>, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::WebLinkIndexOutOfBounds);
+        }
+
+        Ok(PdfPageWebLink::from_pdfium(self, index))
+    }
+
+    /// Returns an iterator over all the web links in this [PdfPageWebLinks] collection.
+    #[inline]
+    pub fn iter(&self) -> PdfPageWebLinksIterator<'_> {
+        PdfPageWebLinksIterator::new(self)
+    }
+}
+
+impl<'a> Drop for PdfPageWebLinks<'a> {
+    /// Closes this [PdfPageWebLinks] collection, releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFLink_CloseWebLinks(self.handle);
+    }
+}
+
+/// An iterator over all the [PdfPageWebLink] objects in a [PdfPageWebLinks] collection.
+pub struct PdfPageWebLinksIterator<'a> {
+    links: &'a PdfPageWebLinks<'a>,
+    next_index: PdfPageWebLinkIndex,
+}
+
+impl<'a> PdfPageWebLinksIterator<'a> {
+    #[inline]
+    pub(crate) fn new(links: &'a PdfPageWebLinks<'a>) -> Self {
+        PdfPageWebLinksIterator {
+            links,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfPageWebLinksIterator<'a> {
+    type Item = PdfPageWebLink<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.links.get(self.next_index);
+
+        self.next_index += 1;
+
+        next.ok()
+    }
+}
@@ -433,16 +433,32 @@ impl<'a> PdfPages<'a> {
     /// Copies all pages in the given source [PdfDocument], appending them sequentially
     /// to the end of this [PdfPages] collection.
     ///
+    /// If this [PdfPages] collection is empty, the viewer preferences of the source
+    /// [PdfDocument] (including its print settings) are also copied into the containing
+    /// document, so that a document built by appending several others retains the
+    /// viewer preferences of the first. Viewer preferences are not otherwise changed.
+    ///
     /// For finer control over which pages are imported, and where they should be inserted,
     /// use one of the [PdfPages::copy_page_from_document()], [PdfPages::copy_pages_from_document()],
     ///  or [PdfPages::copy_page_range_from_document()] functions.
-    #[inline]
     pub fn append(&mut self, document: &PdfDocument) -> Result<(), PdfiumError> {
+        let is_empty = self.is_empty();
+
         self.copy_page_range_from_document(
             document,
             document.pages().as_range_inclusive(),
             self.len(),
-        )
+        )?;
+
+        if is_empty {
+            // FPDF_CopyViewerPreferences() returns false if the source document has no
+            // viewer preferences. This is not an error.
+
+            self.bindings()
+                .FPDF_CopyViewerPreferences(self.document_handle, document.handle());
+        }
+
+        Ok(())
     }
 
     /// Creates a new [PdfDocument] by copying the pages in this [PdfPages] collection
@@ -0,0 +1,547 @@
+//! Defines the [PdfProgressiveLoader] struct, exposing functionality related to loading
+//! a `PdfDocument` progressively while its data is still being downloaded.
+
+use crate::bindgen::{
+    FPDF_AVAIL, PDF_DATA_AVAIL, PDF_DATA_ERROR, PDF_FORM_ERROR, PDF_FORM_NOTAVAIL, PDF_LINEARIZED,
+    PDF_NOT_LINEARIZED,
+};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::pages::PdfPageIndex;
+use crate::pdf::document::PdfDocument;
+use crate::pdfium::Pdfium;
+use crate::utils::files::{
+    get_pdfium_download_hints_from_fetcher, get_pdfium_file_accessor_from_reader,
+    get_pdfium_file_avail_from_fetcher, FpdfFileAccessExt, FxDownloadHintsExt, FxFileAvailExt,
+};
+use std::cell::RefCell;
+use std::convert::TryFrom;
+use std::io::{Error, ErrorKind, Read, Seek, SeekFrom};
+use std::os::raw::c_int;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::rc::Rc;
+
+/// A source of document data that may only be partially available, such as a document
+/// that is still being downloaded over the network.
+///
+/// Pdfium uses a [PdfRangeFetcher] to check whether the byte ranges it needs to
+/// load a document or a page have already arrived, to read those byte ranges once they have,
+/// and to report the byte ranges it still needs so they can be fetched.
+pub trait PdfRangeFetcher {
+    /// Returns the total length of the document data in bytes. Pdfium requires the total
+    /// length to be known in advance, even before any data is available; for documents being
+    /// downloaded over HTTP, this is usually taken from the `Content-Length` response header.
+    fn content_length(&self) -> u64;
+
+    /// Returns `true` if all `length` bytes of document data starting at the given
+    /// `offset` are available to be read.
+    fn is_range_available(&self, offset: u64, length: u64) -> bool;
+
+    /// Fills the given buffer with document data starting at the given `offset`,
+    /// returning the number of bytes read.
+    ///
+    /// Pdfium usually checks that a byte range is available before reading it, but this is
+    /// not guaranteed; Pdfium may ask to read data that [PdfRangeFetcher::is_range_available()]
+    /// has not reported as available. If the entire buffer cannot be filled, the read is
+    /// treated as having failed, and Pdfium will not use any of the data in the buffer.
+    fn read_range(&mut self, offset: u64, buffer: &mut [u8]) -> usize;
+
+    /// Reports that Pdfium needs `length` bytes of document data starting at the given `offset`
+    /// before it can continue loading. Ranges may overlap with each other and with data
+    /// that is already available; the fetcher is responsible for de-duplicating requests.
+    fn request_range(&mut self, offset: u64, length: u64);
+}
+
+/// Whether a document is linearized (sometimes called "fast web view"). Linearized documents
+/// are arranged so that the first page can be displayed before the remainder of the document
+/// has been downloaded.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PdfLinearization {
+    /// The document is linearized.
+    Linearized,
+
+    /// The document is not linearized. All document data must be available before any
+    /// page can be loaded.
+    NotLinearized,
+
+    /// Not enough document data is available yet to determine whether the document is
+    /// linearized. Pdfium needs at least the first 1 KB of document data.
+    Unknown,
+}
+
+impl PdfLinearization {
+    #[inline]
+    pub(crate) fn from_pdfium(value: c_int) -> Self {
+        if value == PDF_LINEARIZED as c_int {
+            PdfLinearization::Linearized
+        } else if value == PDF_NOT_LINEARIZED as c_int {
+            PdfLinearization::NotLinearized
+        } else {
+            PdfLinearization::Unknown
+        }
+    }
+}
+
+/// Loads a `PdfDocument` from a [PdfRangeFetcher] whose data may only be partially available.
+///
+/// Create a [PdfProgressiveLoader] using the `Pdfium::load_pdf_progressively()` function.
+/// Each time new data arrives, call [PdfProgressiveLoader::is_document_available()];
+/// any byte ranges Pdfium still needs are reported to the fetcher's
+/// [PdfRangeFetcher::request_range()] function. Once [PdfProgressiveLoader::is_document_available()]
+/// returns `true`, call [PdfProgressiveLoader::load_document()] to open the document.
+pub struct PdfProgressiveLoader<'a> {
+    availability: PdfDocumentAvailability<'a>,
+}
+
+impl<'a> PdfProgressiveLoader<'a> {
+    pub(crate) fn from_fetcher(
+        fetcher: impl PdfRangeFetcher + 'a,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Result<Self, PdfiumError> {
+        let fetcher: Rc<RefCell<dyn PdfRangeFetcher + 'a>> = Rc::new(RefCell::new(fetcher));
+
+        let mut file_access = get_pdfium_file_accessor_from_reader(PdfRangeFetcherReader {
+            fetcher: fetcher.clone(),
+            position: 0,
+        });
+
+        let mut file_avail = get_pdfium_file_avail_from_fetcher(fetcher.clone());
+
+        let hints = get_pdfium_download_hints_from_fetcher(fetcher);
+
+        let handle = bindings.FPDFAvail_Create(
+            file_avail.as_fx_file_avail_mut_ptr(),
+            file_access.as_fpdf_file_access_mut_ptr(),
+        );
+
+        if handle.is_null() {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        } else {
+            Ok(PdfProgressiveLoader {
+                availability: PdfDocumentAvailability {
+                    handle,
+                    file_avail,
+                    file_access,
+                    hints,
+                    bindings,
+                },
+            })
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfProgressiveLoader].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.availability.bindings
+    }
+
+    /// Returns whether the document being loaded is linearized, if enough document data
+    /// is available to tell.
+    #[inline]
+    pub fn linearization(&self) -> PdfLinearization {
+        PdfLinearization::from_pdfium(
+            self.bindings()
+                .FPDFAvail_IsLinearized(self.availability.handle),
+        )
+    }
+
+    /// Returns `true` if enough document data is available to load the document.
+    ///
+    /// If not, the byte ranges that are still needed are reported to the fetcher's
+    /// [PdfRangeFetcher::request_range()] function. This function should be called again
+    /// whenever new data arrives, until it returns `true` or an error.
+    #[inline]
+    pub fn is_document_available(&mut self) -> Result<bool, PdfiumError> {
+        self.availability.is_document_available()
+    }
+
+    /// Opens the document being loaded, returning a [PdfProgressiveDocument] that can be used
+    /// to check the availability of individual pages as the remaining data arrives.
+    ///
+    /// If the document is password protected, the given password will be used to unlock it.
+    ///
+    /// Returns [PdfiumError::DocumentDataNotAvailable] if not enough document data
+    /// is available yet.
+    pub fn load_document(
+        mut self,
+        password: Option<&str>,
+    ) -> Result<PdfProgressiveDocument<'a>, PdfiumError> {
+        if !self.availability.is_document_available()? {
+            return Err(PdfiumError::DocumentDataNotAvailable);
+        }
+
+        let handle = self
+            .bindings()
+            .FPDFAvail_GetDocument(self.availability.handle, password);
+
+        Pdfium::pdfium_document_handle_to_result(handle, self.bindings()).map(|document| {
+            PdfProgressiveDocument {
+                document,
+                availability: self.availability,
+            }
+        })
+    }
+}
+
+/// A `PdfDocument` opened by a [PdfProgressiveLoader] whose data may still be downloading.
+///
+/// For linearized documents, individual pages can be loaded as soon as their data arrives.
+/// Use [PdfProgressiveDocument::is_page_available()] to check whether a page can be loaded
+/// before retrieving it from the document's `PdfPages` collection.
+pub struct PdfProgressiveDocument<'a> {
+    // The document must be closed before the availability provider is destroyed,
+    // so the document must be declared first.
+    document: PdfDocument<'a>,
+    availability: PdfDocumentAvailability<'a>,
+}
+
+impl<'a> PdfProgressiveDocument<'a> {
+    /// Returns an immutable reference to the [PdfDocument] being loaded.
+    #[inline]
+    pub fn document(&self) -> &PdfDocument<'a> {
+        &self.document
+    }
+
+    /// Returns a mutable reference to the [PdfDocument] being loaded.
+    #[inline]
+    pub fn document_mut(&mut self) -> &mut PdfDocument<'a> {
+        &mut self.document
+    }
+
+    /// Returns whether the document being loaded is linearized.
+    #[inline]
+    pub fn linearization(&self) -> PdfLinearization {
+        PdfLinearization::from_pdfium(
+            self.availability
+                .bindings
+                .FPDFAvail_IsLinearized(self.availability.handle),
+        )
+    }
+
+    /// Returns the index of the first page that becomes available when loading this document.
+    /// For most linearized documents this will be the first page, but linearized documents
+    /// can nominate a different page. For non-linearized documents, this is always zero.
+    #[inline]
+    pub fn first_available_page_index(&self) -> PdfPageIndex {
+        self.availability
+            .bindings
+            .FPDFAvail_GetFirstPageNum(self.document.handle()) as PdfPageIndex
+    }
+
+    /// Returns `true` if enough document data is available to load the page at the given index.
+    ///
+    /// If not, the byte ranges that are still needed are reported to the fetcher's
+    /// [PdfRangeFetcher::request_range()] function. This function should be called again
+    /// whenever new data arrives, until it returns `true` or an error.
+    pub fn is_page_available(&mut self, index: PdfPageIndex) -> Result<bool, PdfiumError> {
+        let result = self.availability.bindings.FPDFAvail_IsPageAvail(
+            self.availability.handle,
+            index as c_int,
+            self.availability.hints.as_fx_download_hints_mut_ptr(),
+        );
+
+        PdfDocumentAvailability::data_result_to_availability(result)
+    }
+
+    /// Returns `true` if enough document data is available to initialize the document's
+    /// interactive form, or if the document does not contain a form.
+    ///
+    /// If not, the byte ranges that are still needed are reported to the fetcher's
+    /// [PdfRangeFetcher::request_range()] function. This function should be called again
+    /// whenever new data arrives, until it returns `true` or an error.
+    pub fn is_form_available(&mut self) -> Result<bool, PdfiumError> {
+        let result = self.availability.bindings.FPDFAvail_IsFormAvail(
+            self.availability.handle,
+            self.availability.hints.as_fx_download_hints_mut_ptr(),
+        );
+
+        if result == PDF_FORM_ERROR {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        } else {
+            // Both PDF_FORM_AVAIL and PDF_FORM_NOTEXIST indicate there is nothing
+            // further to wait for.
+
+            Ok(result != PDF_FORM_NOTAVAIL as c_int)
+        }
+    }
+}
+
+/// Owns a Pdfium `FPDF_AVAIL` document availability provider, along with the
+/// callback structs that Pdfium retains pointers to for the provider's lifetime.
+struct PdfDocumentAvailability<'a> {
+    handle: FPDF_AVAIL,
+
+    #[allow(dead_code)]
+    // Never read directly, but Pdfium calls back into it until the provider is destroyed.
+    file_avail: Box<FxFileAvailExt<'a>>,
+
+    #[allow(dead_code)]
+    // Never read directly, but Pdfium reads document data through it until the provider
+    // and any document loaded from it are destroyed.
+    file_access: Box<FpdfFileAccessExt<'a>>,
+
+    hints: Box<FxDownloadHintsExt<'a>>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfDocumentAvailability<'a> {
+    fn is_document_available(&mut self) -> Result<bool, PdfiumError> {
+        let result = self
+            .bindings
+            .FPDFAvail_IsDocAvail(self.handle, self.hints.as_fx_download_hints_mut_ptr());
+
+        Self::data_result_to_availability(result)
+    }
+
+    #[inline]
+    fn data_result_to_availability(result: c_int) -> Result<bool, PdfiumError> {
+        if result == PDF_DATA_ERROR {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        } else {
+            Ok(result == PDF_DATA_AVAIL as c_int)
+        }
+    }
+}
+
+impl<'a> Drop for PdfDocumentAvailability<'a> {
+    /// Destroys the Pdfium document availability provider, releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFAvail_Destroy(self.handle);
+    }
+}
+
+/// Adapts a shared [PdfRangeFetcher] to the `Read` and `Seek` traits, so that
+/// Pdfium can read document data from it using a standard `FPDF_FILEACCESS` struct.
+struct PdfRangeFetcherReader<'a> {
+    fetcher: Rc<RefCell<dyn PdfRangeFetcher + 'a>>,
+    position: u64,
+}
+
+impl<'a> Read for PdfRangeFetcherReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        // This function is called from inside a Pdfium callback, so any panic in the
+        // user-provided fetcher must not be allowed to unwind into Pdfium.
+
+        let count = catch_unwind(AssertUnwindSafe(|| {
+            self.fetcher.borrow_mut().read_range(self.position, buf)
+        }))
+        .unwrap_or(0);
+
+        if count < buf.len() {
+            // Pdfium treats any non-zero result as a successful read of the entire block,
+            // so a partial read must be reported as a failure.
+
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "requested document data is not available",
+            ));
+        }
+
+        self.position += count as u64;
+
+        Ok(count)
+    }
+}
+
+impl<'a> Seek for PdfRangeFetcherReader<'a> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => (self.fetcher.borrow().content_length() as i64)
+                .checked_add(offset)
+                .and_then(|position| u64::try_from(position).ok()),
+            SeekFrom::Current(offset) => (self.position as i64)
+                .checked_add(offset)
+                .and_then(|position| u64::try_from(position).ok()),
+        };
+
+        match position {
+            Some(position) => {
+                self.position = position;
+
+                Ok(position)
+            }
+            None => Err(Error::new(
+                ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// An in-memory download that only makes byte ranges available once they
+    /// have been requested and subsequently fetched.
+    struct TestDownload {
+        bytes: Vec<u8>,
+        available: Vec<bool>,
+        requests: Vec<(u64, u64)>,
+    }
+
+    impl TestDownload {
+        fn fetch(&mut self, offset: u64, length: u64) {
+            let start = (offset as usize).min(self.bytes.len());
+            let end = ((offset + length) as usize).min(self.bytes.len());
+
+            self.available[start..end]
+                .iter_mut()
+                .for_each(|b| *b = true);
+        }
+
+        fn fetch_requested_ranges(&mut self) -> bool {
+            let requests = std::mem::take(&mut self.requests);
+
+            for (offset, length) in requests.iter() {
+                self.fetch(*offset, *length);
+            }
+
+            !requests.is_empty()
+        }
+    }
+
+    struct TestRangeFetcher(Rc<RefCell<TestDownload>>);
+
+    impl PdfRangeFetcher for TestRangeFetcher {
+        fn content_length(&self) -> u64 {
+            self.0.borrow().bytes.len() as u64
+        }
+
+        fn is_range_available(&self, offset: u64, length: u64) -> bool {
+            let download = self.0.borrow();
+
+            let start = offset as usize;
+            let end = (offset + length) as usize;
+
+            end <= download.bytes.len() && download.available[start..end].iter().all(|b| *b)
+        }
+
+        fn read_range(&mut self, offset: u64, buffer: &mut [u8]) -> usize {
+            let download = self.0.borrow();
+
+            let start = (offset as usize).min(download.bytes.len());
+            let end = (start + buffer.len()).min(download.bytes.len());
+
+            buffer[..end - start].copy_from_slice(&download.bytes[start..end]);
+
+            end - start
+        }
+
+        fn request_range(&mut self, offset: u64, length: u64) {
+            self.0.borrow_mut().requests.push((offset, length));
+        }
+    }
+
+    #[test]
+    fn test_load_pdf_progressively() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = {
+            let mut document = pdfium.create_new_pdf()?;
+
+            document
+                .pages_mut()
+                .create_page_at_start(PdfPagePaperSize::a4())?;
+
+            document.save_to_bytes()?
+        };
+
+        let len = bytes.len();
+
+        let download = Rc::new(RefCell::new(TestDownload {
+            bytes,
+            available: vec![false; len],
+            requests: Vec::new(),
+        }));
+
+        // Pdfium needs the first 1 KB of data before it can determine whether
+        // the document is linearized.
+
+        download.borrow_mut().fetch(0, 1024);
+
+        let mut loader = pdfium.load_pdf_progressively(TestRangeFetcher(download.clone()))?;
+
+        assert_eq!(loader.linearization(), PdfLinearization::NotLinearized);
+
+        while !loader.is_document_available()? {
+            assert!(download.borrow_mut().fetch_requested_ranges());
+        }
+
+        let mut document = loader.load_document(None)?;
+
+        assert_eq!(document.first_available_page_index(), 0);
+        assert!(document.is_page_available(0)?);
+        assert_eq!(document.document().pages().len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_linearized_pdf_progressively() -> Result<(), PdfiumError> {
+        // The second page of the test document carries a large content stream that is
+        // not needed to display the first page.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = std::fs::read("./test/linearized-test.pdf").unwrap();
+
+        let len = bytes.len();
+
+        let download = Rc::new(RefCell::new(TestDownload {
+            bytes,
+            available: vec![false; len],
+            requests: Vec::new(),
+        }));
+
+        download.borrow_mut().fetch(0, 1024);
+
+        let mut loader = pdfium.load_pdf_progressively(TestRangeFetcher(download.clone()))?;
+
+        assert_eq!(loader.linearization(), PdfLinearization::Linearized);
+
+        while !loader.is_document_available()? {
+            assert!(download.borrow_mut().fetch_requested_ranges());
+        }
+
+        let mut document = loader.load_document(None)?;
+
+        assert_eq!(document.linearization(), PdfLinearization::Linearized);
+        assert_eq!(document.first_available_page_index(), 0);
+
+        while !document.is_page_available(0)? {
+            assert!(download.borrow_mut().fetch_requested_ranges());
+        }
+
+        // The first page should be loadable before the whole document has arrived.
+
+        assert!(!download.borrow().available.iter().all(|b| *b));
+        assert_eq!(document.document().pages().len(), 2);
+        assert_eq!(
+            document.document().pages().get(0)?.text()?.all().trim(),
+            "First page"
+        );
+
+        while !document.is_page_available(1)? {
+            assert!(download.borrow_mut().fetch_requested_ranges());
+        }
+
+        assert_eq!(
+            document.document().pages().get(1)?.text()?.all().trim(),
+            "Second page"
+        );
+
+        Ok(())
+    }
+}
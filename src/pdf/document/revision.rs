@@ -0,0 +1,392 @@
+//! Defines the [PdfRevision] struct, exposing functionality related to a single
+//! incremental update in a `PdfRevisions` collection.
+
+use crate::bindgen::{
+    FPDF_DOCUMENT, FPDF_DWORD, FPDF_FILEIDTYPE, FPDF_FILEIDTYPE_FILEIDTYPE_CHANGING,
+    FPDF_FILEIDTYPE_FILEIDTYPE_PERMANENT, FPDF_INCREMENTAL,
+};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::revisions::PdfRevisionIndex;
+use crate::pdf::document::PdfDocument;
+use crate::pdfium::Pdfium;
+use crate::utils::files::get_pdfium_file_writer_from_writer;
+use crate::utils::mem::create_byte_buffer;
+use std::io::Cursor;
+use std::os::raw::c_void;
+
+/// A single revision of a `PdfDocument`, created either when the document was first written
+/// or by a later incremental update to the document.
+///
+/// Each revision comprises all the bytes of the document's file data up to and including the
+/// end-of-file marker following that revision's trailer. The bytes of an earlier revision can be
+/// retrieved using the [PdfRevision::to_bytes()] function, or loaded as a separate [PdfDocument]
+/// using the [PdfRevision::to_document()] function. This allows, for example, the document
+/// as it was when a digital signature was applied to be compared with the document as it is now.
+pub struct PdfRevision<'a> {
+    document_handle: FPDF_DOCUMENT,
+    index: PdfRevisionIndex,
+    trailer_end: u64,
+    is_latest: bool,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfRevision<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        document_handle: FPDF_DOCUMENT,
+        index: PdfRevisionIndex,
+        trailer_end: u64,
+        is_latest: bool,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfRevision {
+            document_handle,
+            index,
+            trailer_end,
+            is_latest,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfRevision].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the zero-based index of this [PdfRevision] in its containing
+    /// `PdfRevisions` collection. The original revision of a document has index 0.
+    #[inline]
+    pub fn index(&self) -> PdfRevisionIndex {
+        self.index
+    }
+
+    /// Returns the byte offset of the end of this [PdfRevision]'s trailer in the file data
+    /// of the containing `PdfDocument`. This is also the length in bytes of this revision.
+    #[inline]
+    pub fn trailer_end(&self) -> u64 {
+        self.trailer_end
+    }
+
+    /// Returns `true` if this [PdfRevision] is the most recent revision of the
+    /// containing `PdfDocument`.
+    #[inline]
+    pub fn is_latest(&self) -> bool {
+        self.is_latest
+    }
+
+    /// Returns the permanent file identifier recorded in this [PdfRevision]'s trailer, if any.
+    ///
+    /// The permanent identifier is the first element of the trailer's /ID array. It is set
+    /// when a document is first created and should not change when the document is updated.
+    ///
+    /// Retrieving the identifier of any revision other than the most recent requires
+    /// the revision to be loaded as a separate document; if the document is encrypted,
+    /// `None` will be returned. To retrieve the identifier of an earlier revision of an encrypted
+    /// document, load the revision using the [PdfRevision::to_document()] function, then
+    /// retrieve the identifier of the most recent revision of the loaded document.
+    #[inline]
+    pub fn permanent_identifier(&self) -> Option<Vec<u8>> {
+        self.file_identifier(FPDF_FILEIDTYPE_FILEIDTYPE_PERMANENT)
+    }
+
+    /// Returns the changing file identifier recorded in this [PdfRevision]'s trailer, if any.
+    ///
+    /// The changing identifier is the second element of the trailer's /ID array. It should
+    /// be updated each time a document is modified, so it can be used to tell revisions apart.
+    ///
+    /// Retrieving the identifier of any revision other than the most recent requires
+    /// the revision to be loaded as a separate document; if the document is encrypted,
+    /// `None` will be returned. To retrieve the identifier of an earlier revision of an encrypted
+    /// document, load the revision using the [PdfRevision::to_document()] function, then
+    /// retrieve the identifier of the most recent revision of the loaded document.
+    #[inline]
+    pub fn changing_identifier(&self) -> Option<Vec<u8>> {
+        self.file_identifier(FPDF_FILEIDTYPE_FILEIDTYPE_CHANGING)
+    }
+
+    /// Returns the file identifier of the given type recorded in this [PdfRevision]'s trailer.
+    fn file_identifier(&self, id_type: FPDF_FILEIDTYPE) -> Option<Vec<u8>> {
+        if self.is_latest {
+            Self::file_identifier_from_pdfium(self.document_handle, id_type, self.bindings())
+        } else {
+            let document = self.to_document(None).ok()?;
+
+            Self::file_identifier_from_pdfium(document.handle(), id_type, self.bindings())
+        }
+    }
+
+    fn file_identifier_from_pdfium(
+        document_handle: FPDF_DOCUMENT,
+        id_type: FPDF_FILEIDTYPE,
+        bindings: &dyn PdfiumLibraryBindings,
+    ) -> Option<Vec<u8>> {
+        // Retrieving the identifier from Pdfium is a two-step operation. First, we call
+        // FPDF_GetFileIdentifier() with a null buffer; this will retrieve the length of
+        // the identifier in bytes, including the trailing nul. If the length is zero, then
+        // the trailer has no /ID entry.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDF_GetFileIdentifier() again with a pointer to the buffer;
+        // this will write the identifier to the buffer. File identifiers are arbitrary
+        // byte strings, so we return them as raw bytes.
+
+        let buffer_length =
+            bindings.FPDF_GetFileIdentifier(document_handle, id_type, std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = bindings.FPDF_GetFileIdentifier(
+            document_handle,
+            id_type,
+            buffer.as_mut_ptr() as *mut c_void,
+            buffer_length,
+        );
+
+        if result != buffer_length {
+            return None;
+        }
+
+        // Remove the trailing nul.
+
+        buffer.truncate(buffer_length as usize - 1);
+
+        if buffer.is_empty() {
+            None
+        } else {
+            Some(buffer)
+        }
+    }
+
+    /// Returns the file data of the containing `PdfDocument` as it was when this
+    /// [PdfRevision] was written, i.e. all bytes up to and including the end-of-file marker
+    /// following this revision's trailer.
+    ///
+    /// The file data is retrieved by incrementally saving the containing document, which
+    /// preserves the bytes of all existing revisions unchanged. Before the saved data is
+    /// truncated, it is checked to ensure this revision still ends at the expected position,
+    /// i.e. that the end-of-file marker and the `startxref` offset preceding it are both
+    /// present and intact. An error will be returned if Pdfium does not save the document
+    /// incrementally, as may happen if the document's security settings have been changed
+    /// since it was loaded.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, PdfiumError> {
+        let mut cursor = Cursor::new(Vec::new());
+
+        {
+            let mut pdfium_file_writer = get_pdfium_file_writer_from_writer(&mut cursor);
+
+            if !self.bindings().is_true(self.bindings().FPDF_SaveAsCopy(
+                self.document_handle,
+                pdfium_file_writer.as_fpdf_file_write_mut_ptr(),
+                FPDF_INCREMENTAL as FPDF_DWORD,
+            )) {
+                return Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ));
+            }
+
+            pdfium_file_writer.flush().map_err(PdfiumError::IoError)?;
+        }
+
+        let mut bytes = cursor.into_inner();
+
+        if (bytes.len() as u64) < self.trailer_end
+            || !is_revision_end(bytes.as_slice(), self.trailer_end as usize)
+        {
+            return Err(PdfiumError::RevisionDataNotAvailable);
+        }
+
+        bytes.truncate(self.trailer_end as usize);
+
+        Ok(bytes)
+    }
+
+    /// Loads the file data of the containing `PdfDocument` as it was when this [PdfRevision]
+    /// was written into a new, separate [PdfDocument].
+    ///
+    /// If the document is password protected, the given password will be used to unlock it.
+    pub fn to_document(&self, password: Option<&str>) -> Result<PdfDocument<'a>, PdfiumError> {
+        let bytes = self.to_bytes()?;
+
+        let mut document = Pdfium::pdfium_document_handle_to_result(
+            self.bindings()
+                .FPDF_LoadMemDocument64(bytes.as_slice(), password),
+            self.bindings(),
+        )?;
+
+        // Give the newly-created document ownership of the byte buffer, so that Pdfium can
+        // continue to read from it on an as-needed basis throughout the lifetime of the document.
+
+        document.set_source_byte_buffer(bytes);
+
+        Ok(document)
+    }
+}
+
+/// Returns `true` if the given file data contains the end of a revision at the given byte offset,
+/// i.e. if the data up to the offset ends with an end-of-file marker preceded by a `startxref`
+/// keyword and the offset of a cross-reference section lying before the marker.
+///
+/// Pdfium's incremental save copies the original file data unchanged before appending any
+/// updates, so the end of every existing revision will be found at the same offset in the
+/// saved data. If Pdfium instead rewrote the document in full, the offset will almost certainly
+/// fall somewhere else.
+fn is_revision_end(bytes: &[u8], end: usize) -> bool {
+    const END_OF_FILE: &[u8] = b"%%EOF";
+
+    const START_XREF: &[u8] = b"startxref";
+
+    if end > bytes.len() {
+        return false;
+    }
+
+    let revision = &bytes[..end];
+
+    let revision = match revision
+        .iter()
+        .rposition(|byte| !byte.is_ascii_whitespace())
+    {
+        Some(position) => &revision[..=position],
+        None => return false,
+    };
+
+    if !revision.ends_with(END_OF_FILE) {
+        return false;
+    }
+
+    let revision = &revision[..revision.len() - END_OF_FILE.len()];
+
+    let start_xref = match revision
+        .windows(START_XREF.len())
+        .rposition(|window| window == START_XREF)
+    {
+        Some(position) => position,
+        None => return false,
+    };
+
+    let offset = revision[start_xref + START_XREF.len()..]
+        .iter()
+        .filter(|byte| !byte.is_ascii_whitespace())
+        .map(|byte| *byte as char)
+        .collect::<String>();
+
+    match offset.parse::<usize>() {
+        Ok(offset) => offset < start_xref,
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_revision_end, PdfRevision};
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_is_revision_end() {
+        let original = b"%PDF-1.7\n1 0 obj\n<< >>\nendobj\nxref\n0 2\ntrailer\n<< /Size 2 >>\nstartxref\n31\n%%EOF\n";
+
+        let mut updated = original.to_vec();
+
+        updated.extend_from_slice(
+            b"2 0 obj\n<< >>\nendobj\nxref\n2 1\ntrailer\n<< /Size 3 /Prev 31 >>\nstartxref\n90\n%%EOF\n",
+        );
+
+        // The original revision ends at the same offset in the updated data.
+
+        assert!(is_revision_end(original, original.len()));
+        assert!(is_revision_end(updated.as_slice(), original.len()));
+        assert!(is_revision_end(updated.as_slice(), original.len() - 1));
+        assert!(is_revision_end(updated.as_slice(), updated.len()));
+
+        // Offsets that do not follow an end-of-file marker are rejected.
+
+        assert!(!is_revision_end(updated.as_slice(), original.len() - 3));
+        assert!(!is_revision_end(updated.as_slice(), original.len() + 10));
+        assert!(!is_revision_end(updated.as_slice(), updated.len() + 1));
+
+        // An end-of-file marker without a valid startxref offset is rejected.
+
+        assert!(!is_revision_end(b"%PDF-1.7\n%%EOF\n", 15));
+        assert!(!is_revision_end(b"%PDF-1.7\nstartxref\n%%EOF\n", 25));
+        assert!(!is_revision_end(b"%PDF-1.7\nstartxref\n900\n%%EOF\n", 29));
+    }
+
+    #[test]
+    fn test_incremental_revisions() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // The original revision has a single page; an incremental update adds a second page
+        // and changes the trailer's changing file identifier.
+
+        let bytes = std::fs::read("./test/revisions-test.pdf").unwrap();
+
+        let document = pdfium.load_pdf_from_byte_slice(&bytes, None)?;
+
+        assert_eq!(document.pages().len(), 2);
+
+        let revisions = document.revisions();
+
+        assert_eq!(revisions.len(), 2);
+
+        let original = revisions.first()?;
+
+        let update = revisions.last()?;
+
+        assert!(!original.is_latest());
+        assert!(update.is_latest());
+        assert!(original.trailer_end() < update.trailer_end());
+        assert!(update.trailer_end() <= bytes.len() as u64);
+
+        // The bytes of the original revision can be extracted from the updated document
+        // and loaded as a document in their own right.
+
+        let original_bytes = original.to_bytes()?;
+
+        assert_eq!(
+            original_bytes.as_slice(),
+            &bytes[..original.trailer_end() as usize]
+        );
+        assert!(is_revision_end(&original_bytes, original_bytes.len()));
+
+        let original_document = pdfium.load_pdf_from_byte_vec(original_bytes, None)?;
+
+        assert_eq!(original_document.pages().len(), 1);
+        assert_eq!(original_document.revisions().len(), 1);
+
+        assert_eq!(original.to_document(None)?.pages().len(), 1);
+        assert_eq!(update.to_document(None)?.pages().len(), 2);
+
+        // Both revisions share the same permanent identifier, but each has its own
+        // changing identifier.
+
+        assert_eq!(original.permanent_identifier(), Some(b"PERMANENT".to_vec()));
+        assert_eq!(update.permanent_identifier(), Some(b"PERMANENT".to_vec()));
+        assert_eq!(original.changing_identifier(), Some(b"ORIGINAL".to_vec()));
+        assert_eq!(update.changing_identifier(), Some(b"UPDATED".to_vec()));
+
+        // A revision whose trailer end does not fall on an end-of-file marker
+        // in the saved data is rejected, rather than returning truncated data.
+
+        let misplaced = PdfRevision::from_pdfium(
+            document.handle(),
+            0,
+            original.trailer_end() - 3,
+            false,
+            document.bindings(),
+        );
+
+        assert!(matches!(
+            misplaced.to_bytes(),
+            Err(PdfiumError::RevisionDataNotAvailable)
+        ));
+        assert_eq!(misplaced.changing_identifier(), None);
+
+        Ok(())
+    }
+}
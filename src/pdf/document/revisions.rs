@@ -0,0 +1,208 @@
+//! Defines the [PdfRevisions] struct, a collection of all the `PdfRevision` objects
+//! in a `PdfDocument`.
+
+use crate::bindgen::FPDF_DOCUMENT;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
+use crate::pdf::document::revision::PdfRevision;
+use std::ops::{Range, RangeInclusive};
+use std::os::raw::{c_uint, c_ulong};
+
+pub type PdfRevisionIndex = usize;
+
+/// The collection of [PdfRevision] objects in a `PdfDocument`.
+///
+/// A PDF file can be updated incrementally by appending changed objects, a new cross-reference
+/// section, and a new trailer to the end of the existing file, leaving the original bytes
+/// untouched. Each such update creates a new revision of the document. Revisions are ordered
+/// from the original revision at index 0 to the most recent revision at index `len() - 1`.
+///
+/// Revisions are determined by the positions of the end-of-file markers Pdfium encountered
+/// while parsing the document. A newly created document that has not yet been saved and
+/// reloaded has no revisions. Linearized documents contain an additional end-of-file marker
+/// after their first-page cross-reference section; this is reported as an additional revision.
+pub struct PdfRevisions<'a> {
+    document_handle: FPDF_DOCUMENT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfRevisions<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        document_handle: FPDF_DOCUMENT,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfRevisions {
+            document_handle,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfRevisions] collection.
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the byte offsets of the end of each revision's trailer in the containing
+    /// `PdfDocument`'s file data, ordered from the original revision to the most recent.
+    fn trailer_ends(&self) -> Vec<u64> {
+        // Retrieving the trailer ends from Pdfium is a two-step operation. First, we call
+        // FPDF_GetTrailerEnds() with a null buffer; this will retrieve the number of
+        // trailer ends. We then reserve a buffer of the given length and call
+        // FPDF_GetTrailerEnds() again with a pointer to the buffer.
+
+        let length =
+            self.bindings()
+                .FPDF_GetTrailerEnds(self.document_handle, std::ptr::null_mut(), 0);
+
+        if length == 0 {
+            return Vec::new();
+        }
+
+        let mut buffer: Vec<c_uint> = vec![0; length as usize];
+
+        let result = self.bindings().FPDF_GetTrailerEnds(
+            self.document_handle,
+            buffer.as_mut_ptr(),
+            length as c_ulong,
+        );
+
+        if result != length {
+            return Vec::new();
+        }
+
+        buffer.into_iter().map(|end| end as u64).collect()
+    }
+
+    /// Returns the number of revisions in this [PdfRevisions] collection.
+    #[inline]
+    pub fn len(&self) -> PdfRevisionIndex {
+        self.trailer_ends().len()
+    }
+
+    /// Returns `true` if this [PdfRevisions] collection is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a Range from `0..(number of revisions)` for this [PdfRevisions] collection.
+    #[inline]
+    pub fn as_range(&self) -> Range<PdfRevisionIndex> {
+        0..self.len()
+    }
+
+    /// Returns an inclusive Range from `0..=(number of revisions - 1)`
+    /// for this [PdfRevisions] collection.
+    #[inline]
+    pub fn as_range_inclusive(&self) -> RangeInclusive<PdfRevisionIndex> {
+        if self.is_empty() {
+            0..=0
+        } else {
+            0..=(self.len() - 1)
+        }
+    }
+
+    /// Returns a single [PdfRevision] from this [PdfRevisions] collection.
+    pub fn get(&self, index: PdfRevisionIndex) -> Result<PdfRevision<'a>, PdfiumError> {
+        let trailer_ends = self.trailer_ends();
+
+        match trailer_ends.get(index) {
+            Some(trailer_end) => Ok(PdfRevision::from_pdfium(
+                self.document_handle,
+                index,
+                *trailer_end,
+                index == trailer_ends.len() - 1,
+                self.bindings(),
+            )),
+            None => Err(PdfiumError::RevisionIndexOutOfBounds),
+        }
+    }
+
+    /// Returns the original revision in this [PdfRevisions] collection.
+    #[inline]
+    pub fn first(&self) -> Result<PdfRevision<'a>, PdfiumError> {
+        self.get(0).map_err(|_| PdfiumError::NoRevisionsInDocument)
+    }
+
+    /// Returns the most recent revision in this [PdfRevisions] collection.
+    #[inline]
+    pub fn last(&self) -> Result<PdfRevision<'a>, PdfiumError> {
+        if self.is_empty() {
+            Err(PdfiumError::NoRevisionsInDocument)
+        } else {
+            self.get(self.len() - 1)
+        }
+    }
+
+    /// Returns an iterator over all the revisions in this [PdfRevisions] collection.
+    #[inline]
+    pub fn iter(&self) -> PdfRevisionsIterator<'_> {
+        PdfRevisionsIterator::new(self)
+    }
+}
+
+/// An iterator over all the [PdfRevision] objects in a [PdfRevisions] collection.
+pub struct PdfRevisionsIterator<'a> {
+    revisions: &'a PdfRevisions<'a>,
+    next_index: PdfRevisionIndex,
+}
+
+impl<'a> PdfRevisionsIterator<'a> {
+    #[inline]
+    pub(crate) fn new(revisions: &'a PdfRevisions<'a>) -> Self {
+        PdfRevisionsIterator {
+            revisions,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfRevisionsIterator<'a> {
+    type Item = PdfRevision<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.revisions.get(self.next_index);
+
+        self.next_index += 1;
+
+        next.ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_revisions() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        assert!(document.revisions().is_empty());
+
+        let bytes = document.save_to_bytes()?;
+
+        let document = pdfium.load_pdf_from_byte_slice(&bytes, None)?;
+
+        assert_eq!(document.revisions().len(), 1);
+
+        let revision = document.revisions().last()?;
+
+        assert!(revision.is_latest());
+        assert!(revision.trailer_end() <= bytes.len() as u64);
+        assert_eq!(
+            revision.to_bytes()?,
+            &bytes[..revision.trailer_end() as usize]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+//! Defines the [PdfSaveOptions] struct, a builder-based approach to configuring
+//! how a `PdfDocument` is written by its `save_to_*_with_options()` functions.
+
+use crate::bindgen::{FPDF_DWORD, FPDF_INCREMENTAL, FPDF_NO_INCREMENTAL, FPDF_REMOVE_SECURITY};
+use crate::error::PdfiumError;
+use crate::pdf::document::PdfDocumentVersion;
+
+#[cfg(doc)]
+use crate::pdf::document::PdfDocument;
+
+/// Controls how the objects in a `PdfDocument` are written when the document is saved.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PdfSaveMode {
+    /// Writes a complete new copy of the document, including a new cross-reference table
+    /// and trailer. This is the default.
+    ///
+    /// Rewriting a document changes the byte layout of its file data, which invalidates any
+    /// digital signatures applied to the document.
+    Rewrite,
+
+    /// Writes a complete new copy of the document, as for [PdfSaveMode::Rewrite], but without
+    /// reusing the original file data of any unchanged objects. This corresponds to
+    /// Pdfium's `FPDF_NO_INCREMENTAL` save flag.
+    RewriteWithoutOriginal,
+
+    /// Writes the original file data of the document unchanged, followed by an incremental
+    /// update containing only the objects that have changed since the document was loaded.
+    ///
+    /// Since the bytes covered by any existing digital signatures are preserved, saving
+    /// incrementally allows changes such as filled-in form field values to be added to
+    /// a signed document without invalidating its signatures.
+    ///
+    /// Pdfium ignores this setting, and rewrites the document in full, if the document
+    /// was created rather than loaded, or if the document's security settings have changed
+    /// since it was loaded.
+    Incremental,
+}
+
+/// Configures how a [PdfDocument] is written by the [PdfDocument::save_to_writer_with_options()],
+/// [PdfDocument::save_to_file_with_options()], [PdfDocument::save_to_bytes_with_options()],
+/// and [PdfDocument::save_to_blob_with_options()] functions.
+///
+/// By default, the document is rewritten in full, its security settings are retained, and
+/// the file version set using [PdfDocument::set_version()], if any, is applied.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PdfSaveOptions {
+    mode: PdfSaveMode,
+    do_remove_security: bool,
+    version: Option<PdfDocumentVersion>,
+}
+
+impl PdfSaveOptions {
+    /// Creates a new [PdfSaveOptions] object with all settings initialized with their
+    /// default values.
+    #[inline]
+    pub fn new() -> Self {
+        PdfSaveOptions {
+            mode: PdfSaveMode::Rewrite,
+            do_remove_security: false,
+            version: None,
+        }
+    }
+
+    /// Sets the [PdfSaveMode] that controls how objects in the document are written.
+    /// The default is [PdfSaveMode::Rewrite].
+    #[inline]
+    pub fn set_mode(mut self, mode: PdfSaveMode) -> Self {
+        self.mode = mode;
+
+        self
+    }
+
+    /// Sets the [PdfSaveMode] to [PdfSaveMode::Incremental] if the given value is `true`,
+    /// or [PdfSaveMode::Rewrite] if the given value is `false`.
+    #[inline]
+    pub fn save_incrementally(self, do_save_incrementally: bool) -> Self {
+        self.set_mode(if do_save_incrementally {
+            PdfSaveMode::Incremental
+        } else {
+            PdfSaveMode::Rewrite
+        })
+    }
+
+    /// Controls whether any encryption and security handler applied to the document
+    /// should be removed when it is saved. The default is `false`.
+    ///
+    /// Removing security requires the document to be rewritten in full using
+    /// [PdfSaveMode::Rewrite]; saving will fail if this is combined with either
+    /// [PdfSaveMode::Incremental] or [PdfSaveMode::RewriteWithoutOriginal].
+    #[inline]
+    pub fn remove_security(mut self, do_remove_security: bool) -> Self {
+        self.do_remove_security = do_remove_security;
+
+        self
+    }
+
+    /// Sets the file version that will be written to the saved document, overriding any
+    /// version set using [PdfDocument::set_version()].
+    #[inline]
+    pub fn set_version(mut self, version: PdfDocumentVersion) -> Self {
+        self.version = Some(version);
+
+        self
+    }
+
+    /// Returns the [PdfSaveMode] configured in this [PdfSaveOptions] object.
+    #[inline]
+    pub fn mode(&self) -> PdfSaveMode {
+        self.mode
+    }
+
+    /// Returns `true` if this [PdfSaveOptions] object is configured to remove any
+    /// security handler from the document when it is saved.
+    #[inline]
+    pub fn is_remove_security(&self) -> bool {
+        self.do_remove_security
+    }
+
+    /// Returns the file version configured in this [PdfSaveOptions] object, if any.
+    #[inline]
+    pub fn version(&self) -> Option<PdfDocumentVersion> {
+        self.version
+    }
+
+    /// Returns the Pdfium `FPDF_*` save flags corresponding to this [PdfSaveOptions] object.
+    pub(crate) fn as_pdfium_flags(&self) -> Result<FPDF_DWORD, PdfiumError> {
+        // Pdfium's save flags are mutually exclusive values rather than bitflags.
+
+        let flags = match (self.mode, self.do_remove_security) {
+            (PdfSaveMode::Incremental, true) => {
+                return Err(PdfiumError::IncrementalSaveCannotRemoveSecurity)
+            }
+            (PdfSaveMode::Incremental, false) => FPDF_INCREMENTAL,
+            (PdfSaveMode::RewriteWithoutOriginal, true) => {
+                return Err(PdfiumError::RewriteWithoutOriginalCannotRemoveSecurity)
+            }
+            (PdfSaveMode::RewriteWithoutOriginal, false) => FPDF_NO_INCREMENTAL,
+            (PdfSaveMode::Rewrite, true) => FPDF_REMOVE_SECURITY,
+            (PdfSaveMode::Rewrite, false) => 0,
+        };
+
+        Ok(flags as FPDF_DWORD)
+    }
+}
+
+impl Default for PdfSaveOptions {
+    #[inline]
+    fn default() -> Self {
+        PdfSaveOptions::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_save_options_flags() -> Result<(), PdfiumError> {
+        assert_eq!(PdfSaveOptions::new().as_pdfium_flags()?, 0);
+        assert_eq!(
+            PdfSaveOptions::new()
+                .save_incrementally(true)
+                .as_pdfium_flags()?,
+            FPDF_INCREMENTAL as FPDF_DWORD
+        );
+        assert_eq!(
+            PdfSaveOptions::new()
+                .set_mode(PdfSaveMode::RewriteWithoutOriginal)
+                .as_pdfium_flags()?,
+            FPDF_NO_INCREMENTAL as FPDF_DWORD
+        );
+        assert_eq!(
+            PdfSaveOptions::new()
+                .remove_security(true)
+                .as_pdfium_flags()?,
+            FPDF_REMOVE_SECURITY as FPDF_DWORD
+        );
+        assert!(PdfSaveOptions::new()
+            .set_mode(PdfSaveMode::RewriteWithoutOriginal)
+            .remove_security(true)
+            .as_pdfium_flags()
+            .is_err());
+        assert!(PdfSaveOptions::new()
+            .save_incrementally(true)
+            .remove_security(true)
+            .as_pdfium_flags()
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_incremental_save_preserves_original_bytes() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let original = std::fs::read("./test/signatures-test.pdf").map_err(PdfiumError::IoError)?;
+
+        let document = pdfium.load_pdf_from_byte_slice(original.as_slice(), None)?;
+
+        let saved =
+            document.save_to_bytes_with_options(&PdfSaveOptions::new().save_incrementally(true))?;
+
+        assert!(saved.len() >= original.len());
+        assert!(saved.starts_with(original.as_slice()));
+
+        let rewritten = document.save_to_bytes()?;
+
+        assert!(!rewritten.starts_with(original.as_slice()));
+
+        let document = pdfium.load_pdf_from_byte_vec(saved, None)?;
+
+        assert_eq!(document.signatures().len(), 3);
+
+        Ok(())
+    }
+}
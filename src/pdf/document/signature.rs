@@ -1,12 +1,54 @@
 //! Defines the [PdfSignature] struct, exposing functionality related to a single
 //! digital signature in a `PdfSignatures` collection.
 
+#[cfg(feature = "verify")]
+pub mod verification;
+
+#[cfg(feature = "verify")]
+use crate::error::PdfiumError;
+
+#[cfg(feature = "verify")]
+use crate::pdf::document::signature::verification::{
+    verify_signature, PdfSignatureTrustAnchors, PdfSignatureVerification,
+};
+
+#[cfg(feature = "verify")]
+use std::time::SystemTime;
+
 use crate::bindgen::FPDF_SIGNATURE;
 use crate::bindings::PdfiumLibraryBindings;
 use crate::utils::mem::create_byte_buffer;
 use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
 use std::ffi::CString;
-use std::os::raw::{c_char, c_void};
+use std::ops::Range;
+use std::os::raw::{c_char, c_int, c_uint, c_ulong, c_void};
+
+/// The changes that may be made to a `PdfDocument` after it has been certified by a
+/// [PdfSignature] without invalidating the certification, as recorded in the /P entry
+/// of the signature's DocMDP transform parameters dictionary.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PdfSignatureModificationDetectionPermission {
+    /// No changes to the document are permitted.
+    NoChanges,
+
+    /// Filling in forms, instantiating page templates, and signing are permitted.
+    FormFillingAndSigning,
+
+    /// Filling in forms, instantiating page templates, signing, and creating, deleting,
+    /// and modifying annotations are permitted.
+    FormFillingSigningAndAnnotating,
+}
+
+impl PdfSignatureModificationDetectionPermission {
+    pub(crate) fn from_pdfium(value: c_uint) -> Option<Self> {
+        match value {
+            1 => Some(PdfSignatureModificationDetectionPermission::NoChanges),
+            2 => Some(PdfSignatureModificationDetectionPermission::FormFillingAndSigning),
+            3 => Some(PdfSignatureModificationDetectionPermission::FormFillingSigningAndAnnotating),
+            _ => None,
+        }
+    }
+}
 
 /// A single digital signature in a `PdfDocument`.
 pub struct PdfSignature<'a> {
@@ -66,6 +108,104 @@ impl<'a> PdfSignature<'a> {
         buffer
     }
 
+    /// Returns the byte ranges of the containing document's file data covered by this
+    /// [PdfSignature], as recorded in the signature's /ByteRange entry.
+    ///
+    /// The signature's digest is calculated over the concatenation of all the returned
+    /// byte ranges. For a signature that covers the entire file, the returned ranges will
+    /// span all bytes of the file except the signature's own /Contents value.
+    pub fn byte_range(&self) -> Vec<Range<usize>> {
+        // Retrieving the byte range from Pdfium is a two-step operation. First, we call
+        // FPDFSignatureObj_GetByteRange() with a null buffer; this will retrieve the number
+        // of integers in the byte range. If the length is zero, then there is no byte range
+        // associated with this signature.
+
+        // If the length is non-zero, then we reserve a buffer of the given length and call
+        // FPDFSignatureObj_GetByteRange() again with a pointer to the buffer; this will write
+        // pairs of (starting byte offset, length in bytes) integers to the buffer.
+
+        let buffer_length =
+            self.bindings()
+                .FPDFSignatureObj_GetByteRange(self.handle, std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            // There is no byte range given for this signature.
+
+            return Vec::new();
+        }
+
+        let mut buffer: Vec<c_int> = vec![0; buffer_length as usize];
+
+        let result = self.bindings().FPDFSignatureObj_GetByteRange(
+            self.handle,
+            buffer.as_mut_ptr(),
+            buffer_length as c_ulong,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        buffer
+            .chunks_exact(2)
+            .filter(|pair| pair[0] >= 0 && pair[1] >= 0)
+            .map(|pair| pair[0] as usize..(pair[0] as usize + pair[1] as usize))
+            .collect()
+    }
+
+    /// Returns the name of the encoding of this [PdfSignature]'s value, as recorded in the
+    /// signature's /SubFilter entry, if any.
+    ///
+    /// Common values include `adbe.pkcs7.detached`, `adbe.pkcs7.sha1`, `adbe.x509.rsa_sha1`,
+    /// `ETSI.CAdES.detached`, and `ETSI.RFC3161`.
+    pub fn sub_filter(&self) -> Option<String> {
+        // Retrieving the sub-filter from Pdfium is a two-step operation. First, we call
+        // FPDFSignatureObj_GetSubFilter() with a null buffer; this will retrieve the length of
+        // the sub-filter name in bytes, including the trailing nul. If the length is zero,
+        // then there is no sub-filter associated with this signature.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDFSignatureObj_GetSubFilter() again with a pointer to the buffer;
+        // this will write the sub-filter name to the buffer as an array of 7-bit ASCII characters.
+
+        let buffer_length =
+            self.bindings()
+                .FPDFSignatureObj_GetSubFilter(self.handle, std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            // There is no sub-filter given for this signature.
+
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings().FPDFSignatureObj_GetSubFilter(
+            self.handle,
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        if let Ok(result) = CString::from_vec_with_nul(buffer) {
+            result.into_string().ok()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the changes that may be made to the containing document without invalidating
+    /// this [PdfSignature], if this signature is a certification signature with a DocMDP
+    /// transform. Returns `None` for ordinary approval signatures.
+    #[inline]
+    pub fn modification_detection_permission(
+        &self,
+    ) -> Option<PdfSignatureModificationDetectionPermission> {
+        PdfSignatureModificationDetectionPermission::from_pdfium(
+            self.bindings()
+                .FPDFSignatureObj_GetDocMDPPermission(self.handle),
+        )
+    }
+
     /// Returns the reason for the signing, if any, as a plain text description provided by the
     /// creator of this [PdfSignature].
     pub fn reason(&self) -> Option<String> {
@@ -142,4 +282,108 @@ impl<'a> PdfSignature<'a> {
             None
         }
     }
+
+    /// Verifies this [PdfSignature] against the given file data, which must be the exact bytes
+    /// of the file from which the containing `PdfDocument` was loaded, and the given set
+    /// of trusted certificates, checking the validity of certificates at the current time.
+    ///
+    /// Verification takes place entirely offline. It checks that the signature's byte range
+    /// covers the entire file except for the signature's own /Contents value, parses the
+    /// signature, checks that the message digest recorded in the signature matches the signed
+    /// bytes, verifies the cryptographic signature using the signer's certificate, checks that
+    /// the signer's certificate may be used to sign documents, and builds a certificate chain
+    /// from the signer's certificate to one of the given trust anchors through certificate
+    /// authorities permitted to issue certificates. The result of each check is reported
+    /// separately in the returned [PdfSignatureVerification]. No revocation checking
+    /// is performed.
+    ///
+    /// Signatures with the `adbe.pkcs7.detached`, `ETSI.CAdES.detached`, and `adbe.pkcs7.sha1`
+    /// sub-filters are supported, using RSA PKCS#1 v1.5 or ECDSA (P-256 or P-384) keys and
+    /// SHA-1 or SHA-2 digests, as are signatures with the `adbe.x509.rsa_sha1` sub-filter.
+    /// An error will be returned for any other kind of signature, for signatures with more than
+    /// one signer, or if the signature cannot be parsed.
+    ///
+    /// Certificates that have since expired will not be trusted. To validate the signature
+    /// as of the time it was made, use the [PdfSignature::verify_at()] function.
+    ///
+    /// This function is only available when this crate's `verify` feature is enabled.
+    #[cfg(feature = "verify")]
+    #[inline]
+    pub fn verify(
+        &self,
+        file_data: &[u8],
+        trust_anchors: &PdfSignatureTrustAnchors,
+    ) -> Result<PdfSignatureVerification, PdfiumError> {
+        self.verify_at(file_data, trust_anchors, SystemTime::now())
+    }
+
+    /// Verifies this [PdfSignature] in the same way as the [PdfSignature::verify()] function,
+    /// but checks the validity of certificates at the given time rather than the current time.
+    ///
+    /// The signing time recorded in the signature is chosen by the signer and cannot be
+    /// trusted, so it is never used as the validation time. To validate a signature as of
+    /// the time it was made, pass a time taken from an RFC 3161 timestamp that you have
+    /// verified separately.
+    ///
+    /// This function is only available when this crate's `verify` feature is enabled.
+    #[cfg(feature = "verify")]
+    #[inline]
+    pub fn verify_at(
+        &self,
+        file_data: &[u8],
+        trust_anchors: &PdfSignatureTrustAnchors,
+        validation_time: SystemTime,
+    ) -> Result<PdfSignatureVerification, PdfiumError> {
+        verify_signature(self, file_data, trust_anchors, validation_time)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "verify")]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn test_verify_x509_rsa_sha1_signature() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let file_data =
+            std::fs::read("./test/signatures-test.pdf").map_err(PdfiumError::IoError)?;
+
+        let document = pdfium.load_pdf_from_byte_slice(&file_data, None)?;
+
+        let signature = document.signatures().get(2)?;
+
+        assert_eq!(
+            signature.sub_filter().as_deref(),
+            Some("adbe.x509.rsa_sha1")
+        );
+
+        // The document is signed with a self-signed certificate, valid from 2009 to 2014.
+
+        let verification = signature.verify(&file_data, &PdfSignatureTrustAnchors::new())?;
+
+        assert!(verification.is_digest_valid());
+        assert!(verification.is_signature_valid());
+        assert!(!verification.is_trusted());
+
+        let mut trust_anchors = PdfSignatureTrustAnchors::new();
+
+        trust_anchors.add_der_certificate(verification.signer_certificate().unwrap())?;
+
+        let validation_time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_262_304_000);
+
+        let verification = signature.verify_at(&file_data, &trust_anchors, validation_time)?;
+
+        assert!(verification.is_valid());
+        assert_eq!(verification.validation_time(), validation_time);
+
+        // The signer's certificate has since expired.
+
+        assert!(!signature.verify(&file_data, &trust_anchors)?.is_trusted());
+
+        Ok(())
+    }
 }
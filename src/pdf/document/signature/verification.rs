@@ -0,0 +1,1637 @@
+//! Defines the [PdfSignatureTrustAnchors] and [PdfSignatureVerification] structs, exposing
+//! functionality related to verifying a single `PdfSignature` offline against a set of
+//! trusted certificates.
+
+use crate::error::PdfiumError;
+use crate::pdf::document::signature::PdfSignature;
+use cms::cert::CertificateChoices;
+use cms::content_info::ContentInfo;
+use cms::signed_data::{SignedData, SignerIdentifier, SignerInfo};
+use p256::ecdsa::signature::hazmat::PrehashVerifier;
+use rsa::pkcs1::DecodeRsaPublicKey;
+use rsa::{Pkcs1v15Sign, RsaPublicKey};
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use std::ops::Range;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use x509_cert::der::asn1::{ObjectIdentifier, OctetString};
+use x509_cert::der::{Decode, Encode, SliceReader};
+use x509_cert::ext::pkix::{BasicConstraints, ExtendedKeyUsage, KeyUsage, SubjectKeyIdentifier};
+use x509_cert::spki::{AlgorithmIdentifierOwned, SubjectPublicKeyInfoOwned};
+use x509_cert::time::Time;
+use x509_cert::Certificate;
+
+const ID_SIGNED_DATA: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.7.2");
+const ID_MESSAGE_DIGEST: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.4");
+const ID_SIGNING_TIME: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.9.5");
+const ID_SUBJECT_KEY_IDENTIFIER: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.29.14");
+
+// Extended key usage purposes that permit a certificate to be used to sign documents.
+const ID_ANY_EXTENDED_KEY_USAGE: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.5.29.37.0");
+const ID_KP_EMAIL_PROTECTION: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7.3.4");
+const ID_KP_DOCUMENT_SIGNING: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.6.1.5.5.7.3.36");
+const ID_ADOBE_AUTHENTIC_DOCUMENTS_TRUST: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113583.1.1.5");
+const ID_MICROSOFT_DOCUMENT_SIGNING: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.6.1.4.1.311.10.3.12");
+
+const ID_SHA_1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.14.3.2.26");
+const ID_SHA_256: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.1");
+const ID_SHA_384: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.2");
+const ID_SHA_512: ObjectIdentifier = ObjectIdentifier::new_unwrap("2.16.840.1.101.3.4.2.3");
+
+const RSA_ENCRYPTION: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+const SHA_1_WITH_RSA: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.5");
+const SHA_256_WITH_RSA: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.11");
+const SHA_384_WITH_RSA: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.12");
+const SHA_512_WITH_RSA: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.13");
+
+const ID_EC_PUBLIC_KEY: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+const ECDSA_WITH_SHA_1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.1");
+const ECDSA_WITH_SHA_256: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.2");
+const ECDSA_WITH_SHA_384: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.3");
+const ECDSA_WITH_SHA_512: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.4");
+const SECP_256_R_1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+const SECP_384_R_1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.34");
+
+/// The maximum number of intermediate certificates followed when building a certificate chain
+/// from a signer's certificate to a trust anchor.
+const MAXIMUM_CHAIN_LENGTH: usize = 16;
+
+/// A set of trusted root or intermediate certificates against which a `PdfSignature`
+/// can be verified using the `PdfSignature::verify()` or `PdfSignature::verify_at()` functions.
+///
+/// A signature is trusted if a chain of valid certificates can be built from the signer's
+/// certificate to any certificate in this set, using the certificates embedded in the
+/// signature itself. No network access is required; in particular, no revocation checking
+/// (via either CRLs or OCSP) is performed.
+#[derive(Clone, Debug, Default)]
+pub struct PdfSignatureTrustAnchors {
+    certificates: Vec<Certificate>,
+}
+
+impl PdfSignatureTrustAnchors {
+    /// Creates a new, empty [PdfSignatureTrustAnchors] set.
+    #[inline]
+    pub fn new() -> Self {
+        PdfSignatureTrustAnchors {
+            certificates: Vec::new(),
+        }
+    }
+
+    /// Adds the given DER-encoded X.509 certificate to this [PdfSignatureTrustAnchors] set.
+    pub fn add_der_certificate(&mut self, der: &[u8]) -> Result<(), PdfiumError> {
+        let certificate = Certificate::from_der(der)
+            .map_err(|err| PdfiumError::SignatureVerificationError(err.to_string()))?;
+
+        self.certificates.push(certificate);
+
+        Ok(())
+    }
+
+    /// Adds all the PEM-encoded X.509 certificates in the given buffer to this
+    /// [PdfSignatureTrustAnchors] set.
+    pub fn add_pem_certificates(&mut self, pem: &[u8]) -> Result<(), PdfiumError> {
+        let certificates = Certificate::load_pem_chain(pem)
+            .map_err(|err| PdfiumError::SignatureVerificationError(err.to_string()))?;
+
+        self.certificates.extend(certificates);
+
+        Ok(())
+    }
+
+    /// Returns the number of certificates in this [PdfSignatureTrustAnchors] set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.certificates.len()
+    }
+
+    /// Returns `true` if this [PdfSignatureTrustAnchors] set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.certificates.is_empty()
+    }
+}
+
+/// The outcome of verifying a single `PdfSignature` using the `PdfSignature::verify()` or
+/// `PdfSignature::verify_at()` functions.
+///
+/// Each check is reported separately, so that a caller can distinguish, for instance, a document
+/// that was changed after signing from a signature made with an untrusted certificate.
+/// Use the [PdfSignatureVerification::is_valid()] function to test whether all checks passed.
+#[derive(Clone, Debug)]
+pub struct PdfSignatureVerification {
+    is_entire_file_covered: bool,
+    is_digest_valid: bool,
+    is_signature_valid: bool,
+    is_signer_certificate_usage_valid: bool,
+    is_trusted: bool,
+    signer_certificate: Option<Vec<u8>>,
+    signing_time: Option<SystemTime>,
+    validation_time: SystemTime,
+}
+
+impl PdfSignatureVerification {
+    /// Returns `true` if the signature's byte range covers the entire file, except for the
+    /// signature's own /Contents value.
+    ///
+    /// Returns `false` if bytes were appended to the file after the signature was applied,
+    /// for instance by a later incremental update, or if the byte range leaves any other part
+    /// of the file unsigned. The signature itself may still be valid for the revision
+    /// of the document it covers.
+    #[inline]
+    pub fn is_entire_file_covered(&self) -> bool {
+        self.is_entire_file_covered
+    }
+
+    /// Returns `true` if the message digest recorded in the signature matches the digest
+    /// of the bytes covered by the signature's byte range, i.e. if the signed bytes have not
+    /// been changed since the signature was applied.
+    ///
+    /// For `adbe.x509.rsa_sha1` signatures, the message digest is embedded in the cryptographic
+    /// signature itself, so this check and the [PdfSignatureVerification::is_signature_valid()]
+    /// check always have the same result.
+    #[inline]
+    pub fn is_digest_valid(&self) -> bool {
+        self.is_digest_valid
+    }
+
+    /// Returns `true` if the cryptographic signature was made by the private key
+    /// corresponding to the signer's certificate.
+    #[inline]
+    pub fn is_signature_valid(&self) -> bool {
+        self.is_signature_valid
+    }
+
+    /// Returns `true` if the signer's certificate may be used to sign documents.
+    ///
+    /// If the certificate has a key usage extension, it must permit digital signatures or
+    /// non-repudiation. If the certificate has an extended key usage extension, it must include
+    /// either any extended key usage, email protection, document signing, Adobe Authentic
+    /// Documents Trust, or Microsoft document signing.
+    #[inline]
+    pub fn is_signer_certificate_usage_valid(&self) -> bool {
+        self.is_signer_certificate_usage_valid
+    }
+
+    /// Returns `true` if a chain of certificates, each valid at the validation time, could be
+    /// built from the signer's certificate to one of the given trust anchors.
+    ///
+    /// Every certificate in the chain other than the signer's certificate must be a certificate
+    /// authority: it must have a basic constraints extension that identifies it as such and
+    /// whose path length constraint, if any, is satisfied, and, if it has a key usage extension,
+    /// that extension must permit certificate signing. A trust anchor that is itself the
+    /// signer's certificate is trusted directly.
+    #[inline]
+    pub fn is_trusted(&self) -> bool {
+        self.is_trusted
+    }
+
+    /// Returns `true` if all checks passed: the signature covers the entire file, the signed
+    /// bytes are unchanged, the cryptographic signature is valid, the signer's certificate
+    /// may be used to sign documents, and the signer is trusted.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.is_entire_file_covered
+            && self.is_digest_valid
+            && self.is_signature_valid
+            && self.is_signer_certificate_usage_valid
+            && self.is_trusted
+    }
+
+    /// Returns the DER-encoded X.509 certificate of the signer, if it could be found
+    /// among the certificates embedded in the signature.
+    #[inline]
+    pub fn signer_certificate(&self) -> Option<&[u8]> {
+        self.signer_certificate.as_deref()
+    }
+
+    /// Returns the signing time recorded in the signature's signed attributes, if any.
+    ///
+    /// Signers can set this value freely; it is not a trusted timestamp, and it is never
+    /// used as the validation time.
+    #[inline]
+    pub fn signing_time(&self) -> Option<SystemTime> {
+        self.signing_time
+    }
+
+    /// Returns the time at which the validity of each certificate in the signer's certificate
+    /// chain was checked.
+    #[inline]
+    pub fn validation_time(&self) -> SystemTime {
+        self.validation_time
+    }
+}
+
+/// The outcome of checking the cryptographic signature of a single `PdfSignature`,
+/// along with the certificates needed to check whether the signer is trusted.
+struct PdfSignatureContentVerification {
+    is_digest_valid: bool,
+    is_signature_valid: bool,
+    signer_certificate: Option<Certificate>,
+    certificates: Vec<Certificate>,
+    signing_time: Option<SystemTime>,
+}
+
+/// Verifies the given [PdfSignature] against the given file data and trust anchors,
+/// checking the validity of certificates at the given time.
+pub(crate) fn verify_signature(
+    signature: &PdfSignature,
+    file_data: &[u8],
+    trust_anchors: &PdfSignatureTrustAnchors,
+    validation_time: SystemTime,
+) -> Result<PdfSignatureVerification, PdfiumError> {
+    verify_signature_data(
+        &signature.bytes(),
+        &signature.byte_range(),
+        signature.sub_filter().as_deref(),
+        file_data,
+        trust_anchors,
+        validation_time,
+    )
+}
+
+/// Verifies a signature with the given /Contents value, byte range, and sub-filter
+/// against the given file data and trust anchors, checking the validity of certificates
+/// at the given time.
+fn verify_signature_data(
+    contents: &[u8],
+    byte_range: &[Range<usize>],
+    sub_filter: Option<&str>,
+    file_data: &[u8],
+    trust_anchors: &PdfSignatureTrustAnchors,
+    validation_time: SystemTime,
+) -> Result<PdfSignatureVerification, PdfiumError> {
+    if byte_range.is_empty() {
+        return Err(error("signature has no byte range"));
+    }
+
+    if byte_range.iter().any(|range| range.end > file_data.len()) {
+        return Err(error("signature byte range exceeds the given file data"));
+    }
+
+    let is_entire_file_covered = is_entire_file_covered(byte_range, file_data, contents);
+
+    let signed_bytes = byte_range
+        .iter()
+        .map(|range| &file_data[range.clone()])
+        .collect::<Vec<_>>();
+
+    let content = match sub_filter {
+        None | Some("adbe.pkcs7.detached") | Some("ETSI.CAdES.detached") => {
+            verify_pkcs7(contents, &signed_bytes, false)?
+        }
+        Some("adbe.pkcs7.sha1") => verify_pkcs7(contents, &signed_bytes, true)?,
+        Some("adbe.x509.rsa_sha1") => verify_x509_rsa_sha1(
+            contents,
+            &signed_bytes,
+            find_certificates(file_data, byte_range)?,
+        )?,
+        Some(sub_filter) => {
+            return Err(error(&format!(
+                "unsupported signature sub-filter {}",
+                sub_filter
+            )))
+        }
+    };
+
+    let is_signer_certificate_usage_valid = content
+        .signer_certificate
+        .as_ref()
+        .map(is_signing_certificate)
+        .unwrap_or(false);
+
+    let is_trusted = content
+        .signer_certificate
+        .as_ref()
+        .map(|certificate| {
+            is_trusted(
+                certificate,
+                &content.certificates,
+                &trust_anchors.certificates,
+                validation_time,
+            )
+        })
+        .unwrap_or(false);
+
+    Ok(PdfSignatureVerification {
+        is_entire_file_covered,
+        is_digest_valid: content.is_digest_valid,
+        is_signature_valid: content.is_signature_valid,
+        is_signer_certificate_usage_valid,
+        is_trusted,
+        signer_certificate: content
+            .signer_certificate
+            .and_then(|certificate| certificate.to_der().ok()),
+        signing_time: content.signing_time,
+        validation_time,
+    })
+}
+
+/// Checks a PKCS#7 / CMS signature with the given /Contents value over the given signed bytes.
+/// If `is_sha1_sub_filter` is `true`, the signature is an `adbe.pkcs7.sha1` signature, whose
+/// signed content is an encapsulated SHA-1 digest of the signed bytes.
+fn verify_pkcs7(
+    contents: &[u8],
+    signed_bytes: &[&[u8]],
+    is_sha1_sub_filter: bool,
+) -> Result<PdfSignatureContentVerification, PdfiumError> {
+    // The /Contents value is zero-padded to a fixed length, so we decode only the first
+    // DER-encoded object in the value and ignore any trailing bytes.
+
+    let content_info = SliceReader::new(contents)
+        .and_then(|mut reader| ContentInfo::decode(&mut reader))
+        .map_err(|err| error(&err.to_string()))?;
+
+    if content_info.content_type != ID_SIGNED_DATA {
+        return Err(error("signature is not a CMS signed-data object"));
+    }
+
+    let signed_data = content_info
+        .content
+        .decode_as::<SignedData>()
+        .map_err(|err| error(&err.to_string()))?;
+
+    let mut signer_infos = signed_data.signer_infos.0.iter();
+
+    let signer_info = signer_infos
+        .next()
+        .ok_or_else(|| error("signature has no signer information"))?;
+
+    if signer_infos.next().is_some() {
+        // A PDF signature is made by a single signer. Rather than report the outcome of
+        // verifying only one of several signers, we refuse to verify the signature at all.
+
+        return Err(error(
+            "signatures with more than one signer are not supported",
+        ));
+    }
+
+    let digest_algorithm = DigestAlgorithm::from_oid(&signer_info.digest_alg.oid)
+        .ok_or_else(|| error("unsupported signature digest algorithm"))?;
+
+    // For detached signatures, the signed content is the data covered by the byte range.
+    // For adbe.pkcs7.sha1 signatures, the signed content is an encapsulated SHA-1 digest
+    // of the data covered by the byte range.
+
+    let (content_digest, is_content_valid) = if is_sha1_sub_filter {
+        let encapsulated = signed_data
+            .encap_content_info
+            .econtent
+            .as_ref()
+            .and_then(|econtent| econtent.decode_as::<OctetString>().ok())
+            .ok_or_else(|| error("signature has no encapsulated content"))?;
+
+        (
+            digest_algorithm.digest(&[encapsulated.as_bytes()]),
+            encapsulated.as_bytes() == DigestAlgorithm::Sha1.digest(signed_bytes).as_slice(),
+        )
+    } else {
+        (digest_algorithm.digest(signed_bytes), true)
+    };
+
+    let (is_digest_valid, signed_message, signing_time) = match signer_info.signed_attrs.as_ref() {
+        Some(signed_attrs) => {
+            // When signed attributes are present, the signature is calculated over the
+            // DER encoding of the attributes as a SET OF, and the attributes carry
+            // the digest of the signed content.
+
+            let message_digest = signed_attrs
+                .iter()
+                .find(|attribute| attribute.oid == ID_MESSAGE_DIGEST)
+                .and_then(|attribute| attribute.values.iter().next())
+                .and_then(|value| value.decode_as::<OctetString>().ok());
+
+            let signing_time = signed_attrs
+                .iter()
+                .find(|attribute| attribute.oid == ID_SIGNING_TIME)
+                .and_then(|attribute| attribute.values.iter().next())
+                .and_then(|value| value.to_der().ok())
+                .and_then(|der| Time::from_der(&der).ok())
+                .map(|time| time.to_system_time());
+
+            (
+                is_content_valid
+                    && message_digest
+                        .map(|digest| digest.as_bytes() == content_digest.as_slice())
+                        .unwrap_or(false),
+                signed_attrs
+                    .to_der()
+                    .map_err(|err| error(&err.to_string()))?,
+                signing_time,
+            )
+        }
+        None => (
+            is_content_valid,
+            if is_sha1_sub_filter {
+                signed_data
+                    .encap_content_info
+                    .econtent
+                    .as_ref()
+                    .and_then(|econtent| econtent.decode_as::<OctetString>().ok())
+                    .map(|encapsulated| encapsulated.as_bytes().to_vec())
+                    .unwrap_or_default()
+            } else {
+                signed_bytes.concat()
+            },
+            None,
+        ),
+    };
+
+    let certificates = signed_data
+        .certificates
+        .as_ref()
+        .map(|certificates| {
+            certificates
+                .0
+                .iter()
+                .filter_map(|choice| match choice {
+                    CertificateChoices::Certificate(certificate) => Some(certificate.clone()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let signer_certificate = certificates
+        .iter()
+        .find(|certificate| is_signer_certificate(certificate, signer_info))
+        .cloned();
+
+    let is_signature_valid = signer_certificate
+        .as_ref()
+        .map(|certificate| {
+            verify(
+                &certificate.tbs_certificate.subject_public_key_info,
+                &signer_info.signature_algorithm,
+                Some(digest_algorithm),
+                &signed_message,
+                signer_info.signature.as_bytes(),
+            )
+        })
+        .unwrap_or(false);
+
+    Ok(PdfSignatureContentVerification {
+        is_digest_valid,
+        is_signature_valid,
+        signer_certificate,
+        certificates,
+        signing_time,
+    })
+}
+
+/// Checks an `adbe.x509.rsa_sha1` signature with the given /Contents value over the given
+/// signed bytes, using the given certificates taken from the signature's /Cert entry.
+/// The first certificate is the signer's certificate.
+fn verify_x509_rsa_sha1(
+    contents: &[u8],
+    signed_bytes: &[&[u8]],
+    certificates: Vec<Certificate>,
+) -> Result<PdfSignatureContentVerification, PdfiumError> {
+    // The /Contents value is a zero-padded DER-encoded octet string containing
+    // a PKCS#1 v1.5 RSA signature of the SHA-1 digest of the signed bytes.
+
+    let signature = SliceReader::new(contents)
+        .and_then(|mut reader| OctetString::decode(&mut reader))
+        .map_err(|err| error(&err.to_string()))?;
+
+    let signer_certificate = certificates
+        .first()
+        .cloned()
+        .ok_or_else(|| error("signature has no signer certificate"))?;
+
+    let public_key = &signer_certificate.tbs_certificate.subject_public_key_info;
+
+    if public_key.algorithm.oid != RSA_ENCRYPTION {
+        return Err(error(
+            "adbe.x509.rsa_sha1 signer certificate is not an RSA certificate",
+        ));
+    }
+
+    let key = RsaPublicKey::from_pkcs1_der(public_key.subject_public_key.raw_bytes())
+        .map_err(|err| error(&err.to_string()))?;
+
+    // The message digest is embedded in the cryptographic signature rather than recorded
+    // separately, so the digest and the signature can only be checked together.
+
+    let is_valid = key
+        .verify(
+            Pkcs1v15Sign::new::<Sha1>(),
+            &DigestAlgorithm::Sha1.digest(signed_bytes),
+            signature.as_bytes(),
+        )
+        .is_ok();
+
+    Ok(PdfSignatureContentVerification {
+        is_digest_valid: is_valid,
+        is_signature_valid: is_valid,
+        signer_certificate: Some(signer_certificate),
+        certificates,
+        signing_time: None,
+    })
+}
+
+/// Returns the certificates recorded in the /Cert entry of the signature dictionary
+/// containing the signature with the given byte range.
+fn find_certificates(
+    file_data: &[u8],
+    byte_range: &[Range<usize>],
+) -> Result<Vec<Certificate>, PdfiumError> {
+    find_certificate_strings(file_data, byte_range)?
+        .iter()
+        .map(|der| Certificate::from_der(der).map_err(|err| error(&err.to_string())))
+        .collect()
+}
+
+/// Returns the strings recorded in the /Cert entry of the signature dictionary containing
+/// the signature with the given byte range.
+///
+/// Pdfium does not provide access to the /Cert entry, so the entry is read from the given file
+/// data instead. The signature dictionary is taken to be the dictionary that opens the indirect
+/// object enclosing the signature's /Contents value, which lies in the gap between the first
+/// two parts of the byte range; only the top-level entries of that dictionary are considered.
+/// This works only if the signature dictionary is written as an uncompressed indirect object
+/// whose /Cert entry is a direct string or array of strings. An error is returned if the
+/// signature dictionary is stored in an object stream, or if the /Cert entry is an
+/// indirect reference.
+fn find_certificate_strings(
+    file_data: &[u8],
+    byte_range: &[Range<usize>],
+) -> Result<Vec<Vec<u8>>, PdfiumError> {
+    if byte_range.len() < 2 || byte_range[0].end > byte_range[1].start {
+        return Err(error("signature byte range does not enclose its contents"));
+    }
+
+    let contents = byte_range[0].end..byte_range[1].start;
+
+    let object_start = find_last(&file_data[..contents.start], b"obj")
+        .filter(|position| *position < 3 || &file_data[position - 3..*position] != b"end")
+        .ok_or_else(signature_dictionary_not_found)?;
+
+    let value = find_dictionary_value(file_data, object_start + 3, b"/Cert", contents)?
+        .ok_or_else(|| error("signature dictionary has no /Cert entry"))?;
+
+    parse_strings(&file_data[value..]).ok_or_else(|| {
+        error(
+            "signature dictionary /Cert entry is not a string or an array of strings; \
+            indirect references are not supported",
+        )
+    })
+}
+
+#[inline]
+fn signature_dictionary_not_found() -> PdfiumError {
+    error(
+        "signature dictionary could not be located in the file data; \
+        signature dictionaries stored in object streams are not supported",
+    )
+}
+
+/// Returns the position in the given data of the value of the given key among the top-level
+/// entries of the dictionary starting at the given position, if any. An error is returned if
+/// the data at the given position is not a dictionary, or if the dictionary does not contain
+/// the hexadecimal string occupying the given range.
+fn find_dictionary_value(
+    data: &[u8],
+    start: usize,
+    key: &[u8],
+    contents: Range<usize>,
+) -> Result<Option<usize>, PdfiumError> {
+    let mut position = skip_whitespace(data, start);
+
+    if !data[position..].starts_with(b"<<") {
+        return Err(signature_dictionary_not_found());
+    }
+
+    position += 2;
+
+    // The nesting depth of dictionaries and arrays, and the number of complete objects seen
+    // so far in the top-level dictionary. A name is a key if an even number of objects
+    // precedes it.
+
+    let mut depth = 1;
+
+    let mut count = 0;
+
+    let mut value = None;
+
+    let mut is_contents_enclosed = false;
+
+    loop {
+        position = skip_whitespace(data, position);
+
+        let token_start = position;
+
+        let byte = *data
+            .get(position)
+            .ok_or_else(signature_dictionary_not_found)?;
+
+        match byte {
+            b'<' if data.get(position + 1) == Some(&b'<') => {
+                depth += 1;
+
+                position += 2;
+
+                continue;
+            }
+            b'>' if data.get(position + 1) == Some(&b'>') => {
+                depth -= 1;
+
+                position += 2;
+
+                if depth == 0 {
+                    return if is_contents_enclosed {
+                        Ok(value)
+                    } else {
+                        Err(signature_dictionary_not_found())
+                    };
+                }
+            }
+            b'[' => {
+                depth += 1;
+
+                position += 1;
+
+                continue;
+            }
+            b']' => {
+                depth -= 1;
+
+                position += 1;
+            }
+            b'(' => {
+                let (_, length) = parse_literal_string(&data[position..])
+                    .ok_or_else(signature_dictionary_not_found)?;
+
+                position += length;
+            }
+            b'<' => {
+                position += data[position..]
+                    .iter()
+                    .position(|byte| *byte == b'>')
+                    .ok_or_else(signature_dictionary_not_found)?
+                    + 1;
+
+                if depth == 1 && token_start == contents.start && position == contents.end {
+                    is_contents_enclosed = true;
+                }
+            }
+            _ => {
+                // A name or other regular token.
+
+                position += 1;
+
+                while let Some(byte) = data.get(position) {
+                    if byte.is_ascii_whitespace() || b"()<>[]{}/%".contains(byte) {
+                        break;
+                    }
+
+                    position += 1;
+                }
+
+                let token = &data[token_start..position];
+
+                if depth == 1 {
+                    if token == key && count % 2 == 0 && value.is_none() {
+                        value = Some(position);
+                    }
+
+                    if token == b"R" && count >= 2 {
+                        // An indirect reference is a single object spanning three tokens,
+                        // the first two of which have already been counted.
+
+                        count -= 1;
+
+                        continue;
+                    }
+                }
+            }
+        }
+
+        if depth == 1 {
+            count += 1;
+        }
+    }
+}
+
+/// Returns the position of the first byte at or after the given position in the given data
+/// that is not whitespace or part of a comment.
+fn skip_whitespace(data: &[u8], mut position: usize) -> usize {
+    while let Some(byte) = data.get(position) {
+        if *byte == b'%' {
+            while let Some(byte) = data.get(position) {
+                if *byte == b'\r' || *byte == b'\n' {
+                    break;
+                }
+
+                position += 1;
+            }
+        } else if byte.is_ascii_whitespace() || *byte == 0 {
+            position += 1;
+        } else {
+            break;
+        }
+    }
+
+    position
+}
+
+/// Returns the position of the last occurrence of the given pattern in the given data.
+fn find_last(data: &[u8], pattern: &[u8]) -> Option<usize> {
+    data.windows(pattern.len())
+        .rposition(|window| window == pattern)
+}
+
+/// Parses the PDF string, or array of PDF strings, at the start of the given data,
+/// ignoring any leading whitespace.
+fn parse_strings(data: &[u8]) -> Option<Vec<Vec<u8>>> {
+    let start = data.iter().position(|byte| !byte.is_ascii_whitespace())?;
+
+    if data[start] == b'[' {
+        let mut strings = Vec::new();
+
+        let mut position = start + 1;
+
+        loop {
+            position += data[position..]
+                .iter()
+                .position(|byte| !byte.is_ascii_whitespace())?;
+
+            if data[position] == b']' {
+                return Some(strings);
+            }
+
+            let (string, length) = parse_string(&data[position..])?;
+
+            strings.push(string);
+
+            position += length;
+        }
+    } else {
+        parse_string(&data[start..]).map(|(string, _)| vec![string])
+    }
+}
+
+/// Parses the literal or hexadecimal PDF string at the start of the given data, returning
+/// the string's bytes and the number of bytes of data the string occupied.
+fn parse_string(data: &[u8]) -> Option<(Vec<u8>, usize)> {
+    match data.first() {
+        Some(b'(') => parse_literal_string(data),
+        Some(b'<') if data.get(1) != Some(&b'<') => {
+            let end = data.iter().position(|byte| *byte == b'>')?;
+
+            decode_hex(&data[1..end]).map(|string| (string, end + 1))
+        }
+        _ => None,
+    }
+}
+
+/// Parses the literal PDF string at the start of the given data, returning the string's bytes
+/// and the number of bytes of data the string occupied.
+fn parse_literal_string(data: &[u8]) -> Option<(Vec<u8>, usize)> {
+    let mut string = Vec::new();
+
+    let mut depth = 0;
+
+    let mut position = 0;
+
+    while position < data.len() {
+        let byte = data[position];
+
+        position += 1;
+
+        match byte {
+            b'(' => {
+                depth += 1;
+
+                if depth == 1 {
+                    continue;
+                }
+            }
+            b')' => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some((string, position));
+                }
+            }
+            b'\\' => {
+                let escaped = *data.get(position)?;
+
+                position += 1;
+
+                match escaped {
+                    b'n' => string.push(b'\n'),
+                    b'r' => string.push(b'\r'),
+                    b't' => string.push(b'\t'),
+                    b'b' => string.push(0x08),
+                    b'f' => string.push(0x0c),
+                    b'0'..=b'7' => {
+                        // An octal character code of one to three digits.
+
+                        let mut value = (escaped - b'0') as u32;
+
+                        for _ in 0..2 {
+                            match data.get(position) {
+                                Some(digit @ b'0'..=b'7') => {
+                                    value = value * 8 + (digit - b'0') as u32;
+
+                                    position += 1;
+                                }
+                                _ => break,
+                            }
+                        }
+
+                        string.push(value as u8);
+                    }
+                    b'\r' => {
+                        // A backslash at the end of a line continues the string on the next line.
+
+                        if data.get(position) == Some(&b'\n') {
+                            position += 1;
+                        }
+                    }
+                    b'\n' => {}
+                    _ => string.push(escaped),
+                }
+
+                continue;
+            }
+            b'\r' => {
+                // An unescaped end-of-line marker is read as a single line feed.
+
+                if data.get(position) == Some(&b'\n') {
+                    position += 1;
+                }
+
+                string.push(b'\n');
+
+                continue;
+            }
+            _ => {}
+        }
+
+        string.push(byte);
+    }
+
+    None
+}
+
+/// Returns `true` if the given byte range covers the given file data in two parts,
+/// separated only by the hex-encoded form of the given signature contents.
+fn is_entire_file_covered(byte_range: &[Range<usize>], file_data: &[u8], contents: &[u8]) -> bool {
+    if byte_range.len() != 2
+        || byte_range[0].start != 0
+        || byte_range[1].end != file_data.len()
+        || byte_range[0].end >= byte_range[1].start
+    {
+        return false;
+    }
+
+    let gap = &file_data[byte_range[0].end..byte_range[1].start];
+
+    if gap.len() < 2 || gap[0] != b'<' || gap[gap.len() - 1] != b'>' {
+        return false;
+    }
+
+    decode_hex(&gap[1..gap.len() - 1]).as_deref() == Some(contents)
+}
+
+/// Decodes the given hexadecimal string, ignoring any whitespace.
+fn decode_hex(hex: &[u8]) -> Option<Vec<u8>> {
+    let digits = hex
+        .iter()
+        .filter(|byte| !byte.is_ascii_whitespace())
+        .map(|byte| (*byte as char).to_digit(16).map(|digit| digit as u8))
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(
+        digits
+            .chunks(2)
+            .map(|pair| (pair[0] << 4) | pair.get(1).copied().unwrap_or(0))
+            .collect(),
+    )
+}
+
+/// Returns `true` if the given certificate is identified by the given signer information.
+fn is_signer_certificate(certificate: &Certificate, signer_info: &SignerInfo) -> bool {
+    match &signer_info.sid {
+        SignerIdentifier::IssuerAndSerialNumber(id) => {
+            certificate.tbs_certificate.issuer == id.issuer
+                && certificate.tbs_certificate.serial_number == id.serial_number
+        }
+        SignerIdentifier::SubjectKeyIdentifier(id) => certificate
+            .tbs_certificate
+            .extensions
+            .as_ref()
+            .and_then(|extensions| {
+                extensions
+                    .iter()
+                    .find(|extension| extension.extn_id == ID_SUBJECT_KEY_IDENTIFIER)
+            })
+            .and_then(|extension| {
+                SubjectKeyIdentifier::from_der(extension.extn_value.as_bytes()).ok()
+            })
+            .map(|key_identifier| &key_identifier == id)
+            .unwrap_or(false),
+    }
+}
+
+/// Returns `true` if a chain of certificates, each valid at the given time, can be built
+/// from the given certificate to any of the given trust anchors, using the given
+/// intermediate certificates. Every certificate in the chain other than the given
+/// certificate must be permitted to issue certificates.
+fn is_trusted(
+    certificate: &Certificate,
+    intermediates: &[Certificate],
+    trust_anchors: &[Certificate],
+    time: SystemTime,
+) -> bool {
+    let mut current = certificate;
+
+    // The number of intermediate certificates between the current certificate and
+    // the given certificate.
+
+    for depth in 0..MAXIMUM_CHAIN_LENGTH {
+        if !is_valid_at(current, time) {
+            return false;
+        }
+
+        if trust_anchors.contains(current) {
+            return true;
+        }
+
+        if trust_anchors.iter().any(|anchor| {
+            is_valid_at(anchor, time)
+                && can_issue_certificates(anchor, depth)
+                && is_issued_by(current, anchor)
+        }) {
+            return true;
+        }
+
+        match intermediates.iter().find(|issuer| {
+            *issuer != current
+                && can_issue_certificates(issuer, depth)
+                && is_issued_by(current, issuer)
+        }) {
+            Some(issuer) => current = issuer,
+            None => return false,
+        }
+    }
+
+    false
+}
+
+/// Returns `true` if the given certificate belongs to a certificate authority permitted to issue
+/// certificates, with the given number of intermediate certificates lying between it and the
+/// end of the certificate chain.
+fn can_issue_certificates(certificate: &Certificate, intermediate_count: usize) -> bool {
+    let is_certificate_authority = match certificate.tbs_certificate.get::<BasicConstraints>() {
+        Ok(Some((_, constraints))) => {
+            constraints.ca
+                && constraints
+                    .path_len_constraint
+                    .map(|length| length as usize >= intermediate_count)
+                    .unwrap_or(true)
+        }
+        _ => false,
+    };
+
+    is_certificate_authority
+        && match certificate.tbs_certificate.get::<KeyUsage>() {
+            Ok(Some((_, key_usage))) => key_usage.key_cert_sign(),
+            Ok(None) => true,
+            Err(_) => false,
+        }
+}
+
+/// Returns `true` if the key usage and extended key usage extensions of the given certificate,
+/// if present, permit the certificate to be used to sign documents.
+fn is_signing_certificate(certificate: &Certificate) -> bool {
+    let is_key_usage_valid = match certificate.tbs_certificate.get::<KeyUsage>() {
+        Ok(Some((_, key_usage))) => key_usage.digital_signature() || key_usage.non_repudiation(),
+        Ok(None) => true,
+        Err(_) => false,
+    };
+
+    is_key_usage_valid
+        && match certificate.tbs_certificate.get::<ExtendedKeyUsage>() {
+            Ok(Some((_, extended_key_usage))) => extended_key_usage.0.iter().any(|purpose| {
+                *purpose == ID_ANY_EXTENDED_KEY_USAGE
+                    || *purpose == ID_KP_EMAIL_PROTECTION
+                    || *purpose == ID_KP_DOCUMENT_SIGNING
+                    || *purpose == ID_ADOBE_AUTHENTIC_DOCUMENTS_TRUST
+                    || *purpose == ID_MICROSOFT_DOCUMENT_SIGNING
+            }),
+            Ok(None) => true,
+            Err(_) => false,
+        }
+}
+
+/// Returns `true` if the given certificate names the given issuer as its issuer,
+/// and is signed by the issuer's key.
+fn is_issued_by(certificate: &Certificate, issuer: &Certificate) -> bool {
+    if certificate.tbs_certificate.issuer != issuer.tbs_certificate.subject {
+        return false;
+    }
+
+    match certificate.tbs_certificate.to_der() {
+        Ok(tbs_certificate) => verify(
+            &issuer.tbs_certificate.subject_public_key_info,
+            &certificate.signature_algorithm,
+            None,
+            &tbs_certificate,
+            certificate.signature.raw_bytes(),
+        ),
+        Err(_) => false,
+    }
+}
+
+/// Returns `true` if the given time falls within the validity period of the given certificate.
+fn is_valid_at(certificate: &Certificate, time: SystemTime) -> bool {
+    let time = time.duration_since(UNIX_EPOCH).unwrap_or(Duration::ZERO);
+
+    let validity = &certificate.tbs_certificate.validity;
+
+    validity.not_before.to_unix_duration() <= time && time <= validity.not_after.to_unix_duration()
+}
+
+/// Verifies the given signature over the given message using the given public key and
+/// signature algorithm. If the signature algorithm does not itself name a digest algorithm,
+/// the given default digest algorithm is used.
+fn verify(
+    public_key: &SubjectPublicKeyInfoOwned,
+    signature_algorithm: &AlgorithmIdentifierOwned,
+    default_digest_algorithm: Option<DigestAlgorithm>,
+    message: &[u8],
+    signature: &[u8],
+) -> bool {
+    let oid = signature_algorithm.oid;
+
+    let digest_algorithm = if oid == SHA_1_WITH_RSA || oid == ECDSA_WITH_SHA_1 {
+        Some(DigestAlgorithm::Sha1)
+    } else if oid == SHA_256_WITH_RSA || oid == ECDSA_WITH_SHA_256 {
+        Some(DigestAlgorithm::Sha256)
+    } else if oid == SHA_384_WITH_RSA || oid == ECDSA_WITH_SHA_384 {
+        Some(DigestAlgorithm::Sha384)
+    } else if oid == SHA_512_WITH_RSA || oid == ECDSA_WITH_SHA_512 {
+        Some(DigestAlgorithm::Sha512)
+    } else if oid == RSA_ENCRYPTION || oid == ID_EC_PUBLIC_KEY {
+        default_digest_algorithm
+    } else {
+        // RSASSA-PSS and other signature algorithms are not supported.
+
+        None
+    };
+
+    let digest_algorithm = match digest_algorithm {
+        Some(digest_algorithm) => digest_algorithm,
+        None => return false,
+    };
+
+    let digest = digest_algorithm.digest(&[message]);
+
+    let key = public_key.subject_public_key.raw_bytes();
+
+    if public_key.algorithm.oid == RSA_ENCRYPTION {
+        match RsaPublicKey::from_pkcs1_der(key) {
+            Ok(key) => key
+                .verify(digest_algorithm.pkcs1v15(), &digest, signature)
+                .is_ok(),
+            Err(_) => false,
+        }
+    } else if public_key.algorithm.oid == ID_EC_PUBLIC_KEY {
+        let curve = public_key
+            .algorithm
+            .parameters
+            .as_ref()
+            .and_then(|parameters| parameters.decode_as::<ObjectIdentifier>().ok());
+
+        if curve == Some(SECP_256_R_1) {
+            match (
+                p256::ecdsa::VerifyingKey::from_sec1_bytes(key),
+                p256::ecdsa::Signature::from_der(signature),
+            ) {
+                (Ok(key), Ok(signature)) => key.verify_prehash(&digest, &signature).is_ok(),
+                _ => false,
+            }
+        } else if curve == Some(SECP_384_R_1) {
+            match (
+                p384::ecdsa::VerifyingKey::from_sec1_bytes(key),
+                p384::ecdsa::Signature::from_der(signature),
+            ) {
+                (Ok(key), Ok(signature)) => key.verify_prehash(&digest, &signature).is_ok(),
+                _ => false,
+            }
+        } else {
+            false
+        }
+    } else {
+        false
+    }
+}
+
+/// The message digest algorithms supported when verifying signatures.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum DigestAlgorithm {
+    Sha1,
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl DigestAlgorithm {
+    fn from_oid(oid: &ObjectIdentifier) -> Option<Self> {
+        if *oid == ID_SHA_1 {
+            Some(DigestAlgorithm::Sha1)
+        } else if *oid == ID_SHA_256 {
+            Some(DigestAlgorithm::Sha256)
+        } else if *oid == ID_SHA_384 {
+            Some(DigestAlgorithm::Sha384)
+        } else if *oid == ID_SHA_512 {
+            Some(DigestAlgorithm::Sha512)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the digest of the concatenation of the given byte slices.
+    fn digest(&self, parts: &[&[u8]]) -> Vec<u8> {
+        fn digest_parts<D: Digest>(parts: &[&[u8]]) -> Vec<u8> {
+            let mut hasher = D::new();
+
+            for part in parts {
+                hasher.update(part);
+            }
+
+            hasher.finalize().to_vec()
+        }
+
+        match self {
+            DigestAlgorithm::Sha1 => digest_parts::<Sha1>(parts),
+            DigestAlgorithm::Sha256 => digest_parts::<Sha256>(parts),
+            DigestAlgorithm::Sha384 => digest_parts::<Sha384>(parts),
+            DigestAlgorithm::Sha512 => digest_parts::<Sha512>(parts),
+        }
+    }
+
+    /// Returns the PKCS#1 v1.5 signature scheme for this digest algorithm.
+    fn pkcs1v15(&self) -> Pkcs1v15Sign {
+        match self {
+            DigestAlgorithm::Sha1 => Pkcs1v15Sign::new::<Sha1>(),
+            DigestAlgorithm::Sha256 => Pkcs1v15Sign::new::<Sha256>(),
+            DigestAlgorithm::Sha384 => Pkcs1v15Sign::new::<Sha384>(),
+            DigestAlgorithm::Sha512 => Pkcs1v15Sign::new::<Sha512>(),
+        }
+    }
+}
+
+#[inline]
+fn error(message: &str) -> PdfiumError {
+    PdfiumError::SignatureVerificationError(message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::slice;
+
+    #[test]
+    fn test_is_entire_file_covered() {
+        let file_data = b"%PDF-1.7 /Contents <0a0B> %%EOF";
+
+        let start = file_data.iter().position(|byte| *byte == b'<').unwrap();
+
+        let end = file_data.iter().position(|byte| *byte == b'>').unwrap() + 1;
+
+        let byte_range = vec![0..start, end..file_data.len()];
+
+        assert!(is_entire_file_covered(
+            &byte_range,
+            file_data,
+            &[0x0a, 0x0b]
+        ));
+        assert!(!is_entire_file_covered(&byte_range, file_data, &[0x0a]));
+        assert!(!is_entire_file_covered(
+            &[0..start, end..file_data.len() - 1],
+            file_data,
+            &[0x0a, 0x0b]
+        ));
+    }
+
+    #[test]
+    fn test_digest_algorithm() {
+        assert_eq!(
+            DigestAlgorithm::from_oid(&ID_SHA_256),
+            Some(DigestAlgorithm::Sha256)
+        );
+        assert_eq!(
+            DigestAlgorithm::Sha256.digest(&[b"ab", b"c"]),
+            DigestAlgorithm::Sha256.digest(&[b"abc"])
+        );
+    }
+    /// Returns the certificates in the test certificate chain bundle, in order: a root
+    /// certificate authority, an intermediate certificate authority with a path length
+    /// constraint of zero, a signer issued by the intermediate, a leaf issued by the root,
+    /// a signer issued by the leaf, a certificate authority without certificate signing
+    /// key usage, a signer issued by that certificate authority, a server certificate issued by
+    /// the intermediate, a sub-intermediate certificate authority issued by the intermediate,
+    /// and a signer issued by the sub-intermediate.
+    fn load_chain() -> Vec<Certificate> {
+        let pem = std::fs::read("./test/signature-chain-test.pem").unwrap();
+
+        Certificate::load_pem_chain(&pem).unwrap()
+    }
+
+    /// Returns a time within the validity period of every certificate in the test
+    /// certificate chain bundle.
+    fn chain_validation_time() -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(1_900_000_000)
+    }
+
+    #[test]
+    fn test_is_trusted() {
+        let chain = load_chain();
+
+        let time = chain_validation_time();
+
+        let (root, intermediate, signer) = (&chain[0], &chain[1], &chain[2]);
+
+        assert!(is_trusted(
+            signer,
+            slice::from_ref(intermediate),
+            slice::from_ref(root),
+            time
+        ));
+        assert!(is_trusted(signer, &[], slice::from_ref(intermediate), time));
+        assert!(is_trusted(signer, &[], slice::from_ref(signer), time));
+
+        // Untrusted roots.
+
+        assert!(!is_trusted(
+            signer,
+            slice::from_ref(intermediate),
+            &[],
+            time
+        ));
+        assert!(!is_trusted(
+            signer,
+            slice::from_ref(intermediate),
+            &[chain[3].clone()],
+            time
+        ));
+
+        // Expired or not yet valid certificates.
+
+        assert!(!is_trusted(
+            signer,
+            slice::from_ref(intermediate),
+            slice::from_ref(root),
+            SystemTime::UNIX_EPOCH
+        ));
+    }
+
+    #[test]
+    fn test_is_trusted_rejects_issuers_that_are_not_certificate_authorities() {
+        let chain = load_chain();
+
+        let time = chain_validation_time();
+
+        let root = &chain[0];
+
+        // A leaf certificate used as an issuer, both as an intermediate and as a trust anchor.
+
+        let (leaf, leaf_signer) = (&chain[3], &chain[4]);
+
+        assert!(is_issued_by(leaf_signer, leaf));
+        assert!(!is_trusted(
+            leaf_signer,
+            slice::from_ref(leaf),
+            slice::from_ref(root),
+            time
+        ));
+        assert!(!is_trusted(leaf_signer, &[], slice::from_ref(leaf), time));
+
+        // A certificate authority whose key usage does not permit certificate signing.
+
+        let (no_cert_sign, no_cert_sign_signer) = (&chain[5], &chain[6]);
+
+        assert!(is_issued_by(no_cert_sign_signer, no_cert_sign));
+        assert!(!is_trusted(
+            no_cert_sign_signer,
+            slice::from_ref(no_cert_sign),
+            slice::from_ref(root),
+            time
+        ));
+
+        // A chain exceeding the intermediate's path length constraint.
+
+        let (intermediate, sub_intermediate, sub_signer) = (&chain[1], &chain[8], &chain[9]);
+
+        assert!(is_trusted(
+            sub_intermediate,
+            slice::from_ref(intermediate),
+            slice::from_ref(root),
+            time
+        ));
+        assert!(!is_trusted(
+            sub_signer,
+            &[intermediate.clone(), sub_intermediate.clone()],
+            slice::from_ref(root),
+            time
+        ));
+    }
+
+    #[test]
+    fn test_is_signing_certificate() {
+        let chain = load_chain();
+
+        assert!(is_signing_certificate(&chain[2]));
+        assert!(is_signing_certificate(&chain[3]));
+        assert!(!is_signing_certificate(&chain[1]));
+        assert!(!is_signing_certificate(&chain[7]));
+    }
+
+    #[test]
+    fn test_parse_strings() {
+        assert_eq!(
+            parse_strings(b" (a\\(b\\)c(d)\\101\\1\\\r\ne)"),
+            Some(vec![b"a(b)c(d)A\x01e".to_vec()])
+        );
+        assert_eq!(
+            parse_strings(b"[<0a0B> (x)]"),
+            Some(vec![vec![0x0a, 0x0b], b"x".to_vec()])
+        );
+        assert_eq!(parse_strings(b"12 0 R"), None);
+    }
+
+    /// The byte range of the last of the three signatures in signatures-test.pdf,
+    /// an `adbe.x509.rsa_sha1` signature that covers the entire file.
+    const LAST_SIGNATURE_BYTE_RANGE: [usize; 4] = [0, 285210, 285474, 7217];
+
+    /// The byte range of the first of the three signatures in signatures-test.pdf.
+    const FIRST_SIGNATURE_BYTE_RANGE: [usize; 4] = [0, 210013, 210277, 7790];
+
+    /// Verifies the signature with the given byte range in the given signatures-test.pdf
+    /// file data, taking the signature's /Contents value from the gap in the byte range.
+    fn verify_test_signature(
+        file_data: &[u8],
+        byte_range: [usize; 4],
+        trust_anchors: &PdfSignatureTrustAnchors,
+        validation_time: SystemTime,
+    ) -> PdfSignatureVerification {
+        let byte_range = vec![
+            byte_range[0]..byte_range[0] + byte_range[1],
+            byte_range[2]..byte_range[2] + byte_range[3],
+        ];
+
+        let contents =
+            decode_hex(&file_data[byte_range[0].end + 1..byte_range[1].start - 1]).unwrap();
+
+        verify_signature_data(
+            &contents,
+            &byte_range,
+            Some("adbe.x509.rsa_sha1"),
+            file_data,
+            trust_anchors,
+            validation_time,
+        )
+        .unwrap()
+    }
+
+    /// Returns a set of trust anchors containing the self-signed certificate that signed
+    /// the signatures in signatures-test.pdf.
+    fn load_test_signer_trust_anchors(file_data: &[u8]) -> PdfSignatureTrustAnchors {
+        let verification = verify_test_signature(
+            file_data,
+            LAST_SIGNATURE_BYTE_RANGE,
+            &PdfSignatureTrustAnchors::new(),
+            SystemTime::now(),
+        );
+
+        let mut trust_anchors = PdfSignatureTrustAnchors::new();
+
+        trust_anchors
+            .add_der_certificate(verification.signer_certificate().unwrap())
+            .unwrap();
+
+        trust_anchors
+    }
+
+    /// A time within the validity period of the certificate that signed the signatures
+    /// in signatures-test.pdf.
+    fn signature_validation_time() -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(1_262_304_000) // 2010-01-01
+    }
+
+    #[test]
+    fn test_verify_valid_signature() {
+        let file_data = std::fs::read("./test/signatures-test.pdf").unwrap();
+
+        let trust_anchors = load_test_signer_trust_anchors(&file_data);
+
+        let verification = verify_test_signature(
+            &file_data,
+            LAST_SIGNATURE_BYTE_RANGE,
+            &trust_anchors,
+            signature_validation_time(),
+        );
+
+        assert!(verification.is_entire_file_covered());
+        assert!(verification.is_digest_valid());
+        assert!(verification.is_signature_valid());
+        assert!(verification.is_signer_certificate_usage_valid());
+        assert!(verification.is_trusted());
+        assert!(verification.is_valid());
+        assert_eq!(verification.validation_time(), signature_validation_time());
+
+        // The signer's certificate has since expired.
+
+        let verification = verify_test_signature(
+            &file_data,
+            LAST_SIGNATURE_BYTE_RANGE,
+            &trust_anchors,
+            SystemTime::now(),
+        );
+
+        assert!(verification.is_digest_valid());
+        assert!(verification.is_signature_valid());
+        assert!(!verification.is_trusted());
+        assert!(!verification.is_valid());
+
+        // An earlier signature does not cover the entire file.
+
+        let verification = verify_test_signature(
+            &file_data,
+            FIRST_SIGNATURE_BYTE_RANGE,
+            &trust_anchors,
+            signature_validation_time(),
+        );
+
+        assert!(!verification.is_entire_file_covered());
+        assert!(verification.is_digest_valid());
+        assert!(verification.is_signature_valid());
+        assert!(verification.is_trusted());
+        assert!(!verification.is_valid());
+    }
+
+    #[test]
+    fn test_verify_tampered_byte_range() {
+        let mut file_data = std::fs::read("./test/signatures-test.pdf").unwrap();
+
+        let trust_anchors = load_test_signer_trust_anchors(&file_data);
+
+        file_data[100] ^= 0x01;
+
+        let verification = verify_test_signature(
+            &file_data,
+            LAST_SIGNATURE_BYTE_RANGE,
+            &trust_anchors,
+            signature_validation_time(),
+        );
+
+        assert!(verification.is_entire_file_covered());
+        assert!(!verification.is_digest_valid());
+        assert!(!verification.is_signature_valid());
+        assert!(verification.is_trusted());
+        assert!(!verification.is_valid());
+    }
+
+    #[test]
+    fn test_verify_forged_signature() {
+        let file_data = std::fs::read("./test/signatures-test.pdf").unwrap();
+
+        let trust_anchors = load_test_signer_trust_anchors(&file_data);
+
+        // Change one byte of the RSA signature in the signature's /Contents value.
+
+        let mut forged = file_data.clone();
+
+        let position = LAST_SIGNATURE_BYTE_RANGE[1] + 20;
+
+        forged[position] = if forged[position] == b'0' { b'1' } else { b'0' };
+
+        let verification = verify_test_signature(
+            &forged,
+            LAST_SIGNATURE_BYTE_RANGE,
+            &trust_anchors,
+            signature_validation_time(),
+        );
+
+        assert!(verification.is_entire_file_covered());
+        assert!(!verification.is_digest_valid());
+        assert!(!verification.is_signature_valid());
+        assert!(!verification.is_valid());
+    }
+
+    #[test]
+    fn test_find_certificate_strings() {
+        let file_data = b"%PDF-1.7\n\
+            7 0 obj\n\
+            << /Type /Sig /Filter /Adobe.PPKLite /SubFilter /adbe.x509.rsa_sha1\n\
+            /Prop_Build << /Cert (nested) >> /Name (/Cert (in a string)) % /Cert (comment)\n\
+            /Reference [ << /Cert (in an array) >> ] /Parent 3 0 R /Label /Cert\n\
+            /ByteRange [ 0 0 0 0 ] /Contents <0a0b> /Cert [ (first) <7365636f6e64> ] >>\n\
+            endobj\n\
+            %%EOF";
+
+        let contents_start = file_data.iter().position(|byte| *byte == b'<').unwrap();
+
+        let contents_start = contents_start
+            + file_data[contents_start..]
+                .windows(6)
+                .position(|window| window == b"<0a0b>")
+                .unwrap();
+
+        let byte_range = vec![0..contents_start, contents_start + 6..file_data.len()];
+
+        assert_eq!(
+            find_certificate_strings(file_data, &byte_range).unwrap(),
+            vec![b"first".to_vec(), b"second".to_vec()]
+        );
+
+        // A /Cert entry given as an indirect reference.
+
+        let file_data = b"7 0 obj << /Cert 12 0 R /Contents <0a0b> >> endobj";
+
+        let contents_start = file_data
+            .windows(6)
+            .position(|window| window == b"<0a0b>")
+            .unwrap();
+
+        assert!(find_certificate_strings(
+            file_data,
+            &[0..contents_start, contents_start + 6..file_data.len()]
+        )
+        .is_err());
+
+        // A signature dictionary stored in an object stream, so that the nearest
+        // indirect object does not contain the signature's /Contents value.
+
+        let file_data = b"7 0 obj << /Type /ObjStm >> stream\n<< /Cert (x) /Contents <0a0b> >>";
+
+        let contents_start = file_data
+            .windows(6)
+            .position(|window| window == b"<0a0b>")
+            .unwrap();
+
+        assert!(find_certificate_strings(
+            file_data,
+            &[0..contents_start, contents_start + 6..file_data.len()]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_verify_rejects_multiple_signers() {
+        // A detached CMS signature over the bytes "two signers", made by two signers.
+
+        let contents = std::fs::read("./test/signature-two-signers-test.p7s").unwrap();
+
+        let hex = contents
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+
+        let file_data = format!("two <{}> signers", hex).into_bytes();
+
+        let byte_range = vec![0..4, file_data.len() - 8..file_data.len()];
+
+        let result = verify_signature_data(
+            &contents,
+            &byte_range,
+            Some("adbe.pkcs7.detached"),
+            &file_data,
+            &PdfSignatureTrustAnchors::new(),
+            chain_validation_time(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(PdfiumError::SignatureVerificationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_verify_untrusted_root() {
+        let file_data = std::fs::read("./test/signatures-test.pdf").unwrap();
+
+        let mut trust_anchors = PdfSignatureTrustAnchors::new();
+
+        trust_anchors
+            .add_pem_certificates(&std::fs::read("./test/signature-chain-test.pem").unwrap())
+            .unwrap();
+
+        let verification = verify_test_signature(
+            &file_data,
+            LAST_SIGNATURE_BYTE_RANGE,
+            &trust_anchors,
+            signature_validation_time(),
+        );
+
+        assert!(verification.is_digest_valid());
+        assert!(verification.is_signature_valid());
+        assert!(!verification.is_trusted());
+        assert!(!verification.is_valid());
+    }
+}
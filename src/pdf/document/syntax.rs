@@ -0,0 +1,1323 @@
+//! Defines a minimal reader and writer for the object syntax of PDF files.
+//!
+//! Pdfium does not provide a way to read or write arbitrary entries in the dictionaries
+//! that make up a document. Where `pdfium-render` needs access to such entries, it asks Pdfium
+//! to write the document to a byte buffer, reads the entries from the written data using
+//! a [PdfSyntaxDocument], and writes changed entries by appending an incremental update
+//! to the written data using [append_incremental_update()].
+//!
+//! Only the subset of the file syntax written by Pdfium is supported: objects must be listed
+//! in cross-reference tables rather than cross-reference streams, and must not be stored
+//! in object streams. Pdfium never writes cross-reference streams or object streams when
+//! rewriting a document.
+//!
+//! The [PdfSyntaxParser] is also used on its own to read the objects in FDF files.
+
+use crate::bindgen::FPDF_DOCUMENT;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::utils::files::get_pdfium_file_writer_from_writer;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::ops::Range;
+
+/// The maximum nesting depth of arrays and dictionaries, and the maximum depth of the page tree,
+/// accepted when reading document data.
+const MAXIMUM_DEPTH: usize = 64;
+
+/// A single object parsed from document data.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum PdfSyntaxObject {
+    Null,
+    Boolean(bool),
+    Number(f32),
+    String(Vec<u8>),
+    Name(Vec<u8>),
+    Array(Vec<PdfSyntaxObject>),
+    Dictionary(PdfSyntaxDictionary),
+    Reference(u32, u16),
+}
+
+impl PdfSyntaxObject {
+    /// Returns the value of this object, if it is a number.
+    #[inline]
+    pub(crate) fn as_number(&self) -> Option<f32> {
+        match self {
+            PdfSyntaxObject::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of this object, if it is a name.
+    #[inline]
+    pub(crate) fn as_name(&self) -> Option<&[u8]> {
+        match self {
+            PdfSyntaxObject::Name(value) => Some(value.as_slice()),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements of this object, if it is an array.
+    #[inline]
+    pub(crate) fn as_array(&self) -> Option<&[PdfSyntaxObject]> {
+        match self {
+            PdfSyntaxObject::Array(value) => Some(value.as_slice()),
+            _ => None,
+        }
+    }
+
+    /// Returns this object as a [PdfSyntaxDictionary], if it is a dictionary.
+    #[inline]
+    pub(crate) fn as_dictionary(&self) -> Option<&PdfSyntaxDictionary> {
+        match self {
+            PdfSyntaxObject::Dictionary(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the object number and generation number of this object, if it is
+    /// an indirect reference.
+    #[inline]
+    pub(crate) fn as_reference(&self) -> Option<(u32, u16)> {
+        match self {
+            PdfSyntaxObject::Reference(number, generation) => Some((*number, *generation)),
+            _ => None,
+        }
+    }
+}
+
+/// A single dictionary parsed from document data, retaining the positions of the dictionary
+/// and its entries in the data so that the dictionary can be rewritten with changed entries.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PdfSyntaxDictionary {
+    entries: Vec<(Vec<u8>, PdfSyntaxObject, Range<usize>)>,
+    span: Range<usize>,
+}
+
+impl PdfSyntaxDictionary {
+    /// Returns the value of the entry with the given key in this dictionary, if any.
+    pub(crate) fn get(&self, key: &[u8]) -> Option<&PdfSyntaxObject> {
+        self.entries
+            .iter()
+            .find(|(entry_key, _, _)| entry_key == key)
+            .map(|(_, value, _)| value)
+    }
+
+    /// Returns the position of this dictionary, including its delimiters, in the data
+    /// from which it was parsed.
+    #[inline]
+    pub(crate) fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns the text of this dictionary, as written in the given data, after applying
+    /// the given changes. Each change replaces the entry with the given key by the given value,
+    /// written in PDF object syntax, or removes the entry if the value is `None`.
+    pub(crate) fn to_bytes_with_changes(
+        &self,
+        data: &[u8],
+        changes: &[(Vec<u8>, Option<Vec<u8>>)],
+    ) -> Vec<u8> {
+        let mut result = b"<<".to_vec();
+
+        for (key, _, span) in self.entries.iter() {
+            if !changes.iter().any(|(changed_key, _)| changed_key == key) {
+                result.push(b' ');
+                result.extend_from_slice(&data[span.clone()]);
+            }
+        }
+
+        for (key, value) in changes.iter() {
+            if let Some(value) = value {
+                result.push(b' ');
+                result.extend_from_slice(&name_to_bytes(key));
+                result.push(b' ');
+                result.extend_from_slice(value);
+            }
+        }
+
+        result.extend_from_slice(b" >>");
+
+        result
+    }
+}
+
+/// Returns the given name written in PDF object syntax, including its leading solidus.
+pub(crate) fn name_to_bytes(name: &[u8]) -> Vec<u8> {
+    let mut result = vec![b'/'];
+
+    for byte in name.iter() {
+        if is_regular(*byte) && *byte != b'#' && (b'!'..=b'~').contains(byte) {
+            result.push(*byte);
+        } else {
+            result.extend_from_slice(format!("#{:02X}", byte).as_bytes());
+        }
+    }
+
+    result
+}
+
+/// Decodes the given bytes as a PDF text string. Text strings are either UTF-16BE or UTF-8 with
+/// a leading byte order mark, or PDFDocEncoding. PDFDocEncoding is treated as Latin-1; the two
+/// encodings only differ for a handful of rarely used characters.
+pub(crate) fn decode_text_string(bytes: &[u8]) -> String {
+    if bytes.starts_with(&[0xFE, 0xFF]) {
+        let units = bytes[2..]
+            .chunks(2)
+            .map(|pair| u16::from_be_bytes([pair[0], *pair.get(1).unwrap_or(&0)]))
+            .collect::<Vec<_>>();
+
+        String::from_utf16_lossy(&units)
+    } else if bytes.starts_with(&[0xEF, 0xBB, 0xBF]) {
+        String::from_utf8_lossy(&bytes[3..]).into_owned()
+    } else {
+        bytes.iter().map(|byte| *byte as char).collect()
+    }
+}
+
+/// Returns the given number written in PDF object syntax.
+#[inline]
+pub(crate) fn number_to_bytes(value: f32) -> Vec<u8> {
+    // Rust never uses exponential notation when displaying floating point values,
+    // so the result is always a valid PDF number.
+
+    format!("{}", value).into_bytes()
+}
+
+/// Returns `true` if the given byte is a PDF whitespace character.
+#[inline]
+fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b'\0' | b'\t' | b'\n' | b'\x0c' | b'\r' | b' ')
+}
+
+/// Returns `true` if the given byte is neither a PDF whitespace character nor a PDF delimiter.
+#[inline]
+fn is_regular(byte: u8) -> bool {
+    !is_whitespace(byte) && !b"()<>[]{}/%".contains(&byte)
+}
+
+#[inline]
+fn syntax_error(message: &str) -> PdfiumError {
+    PdfiumError::ParseDocumentDataError(message.to_string())
+}
+
+/// Parses objects written in PDF object syntax, starting at a given position in some data.
+pub(crate) struct PdfSyntaxParser<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> PdfSyntaxParser<'a> {
+    #[inline]
+    pub(crate) fn new(data: &'a [u8], position: usize) -> Self {
+        PdfSyntaxParser { data, position }
+    }
+
+    /// Returns the current position of this parser in its data.
+    #[inline]
+    pub(crate) fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Advances this parser past any whitespace and comments, returning `true` if
+    /// there is no more data to parse.
+    #[inline]
+    pub(crate) fn is_at_end(&mut self) -> bool {
+        self.skip_whitespace();
+
+        self.position >= self.data.len()
+    }
+
+    /// Advances this parser past any whitespace and comments.
+    fn skip_whitespace(&mut self) {
+        while let Some(byte) = self.data.get(self.position) {
+            if *byte == b'%' {
+                while let Some(byte) = self.data.get(self.position) {
+                    if *byte == b'\r' || *byte == b'\n' {
+                        break;
+                    }
+
+                    self.position += 1;
+                }
+            } else if is_whitespace(*byte) {
+                self.position += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Advances this parser past any whitespace and returns the run of regular characters
+    /// that follows, leaving the parser positioned after the run.
+    fn next_token(&mut self) -> &'a [u8] {
+        self.skip_whitespace();
+
+        let start = self.position;
+
+        while self
+            .data
+            .get(self.position)
+            .map(|byte| is_regular(*byte))
+            .unwrap_or(false)
+        {
+            self.position += 1;
+        }
+
+        &self.data[start..self.position]
+    }
+
+    /// Advances this parser past the given keyword, returning `true` if the keyword was found
+    /// at the next position after any whitespace. The parser is not moved if the keyword
+    /// was not found.
+    pub(crate) fn parse_keyword(&mut self, keyword: &[u8]) -> bool {
+        let start = self.position;
+
+        if self.next_token() == keyword {
+            true
+        } else {
+            self.position = start;
+
+            false
+        }
+    }
+
+    /// Parses a non-negative integer at the next position after any whitespace.
+    pub(crate) fn parse_integer(&mut self) -> Result<u64, PdfiumError> {
+        std::str::from_utf8(self.next_token())
+            .ok()
+            .and_then(|token| token.parse::<u64>().ok())
+            .ok_or_else(|| syntax_error("expected an integer"))
+    }
+
+    /// Parses the object at the next position after any whitespace.
+    #[inline]
+    pub(crate) fn parse_object(&mut self) -> Result<PdfSyntaxObject, PdfiumError> {
+        self.parse_object_at_depth(0)
+    }
+
+    fn parse_object_at_depth(&mut self, depth: usize) -> Result<PdfSyntaxObject, PdfiumError> {
+        if depth > MAXIMUM_DEPTH {
+            return Err(syntax_error("arrays or dictionaries nested too deeply"));
+        }
+
+        self.skip_whitespace();
+
+        let byte = *self
+            .data
+            .get(self.position)
+            .ok_or_else(|| syntax_error("unexpected end of data"))?;
+
+        match byte {
+            b'/' => {
+                self.position += 1;
+
+                Ok(PdfSyntaxObject::Name(self.parse_name()))
+            }
+            b'(' => {
+                self.position += 1;
+
+                self.parse_literal_string().map(PdfSyntaxObject::String)
+            }
+            b'<' if self.data.get(self.position + 1) == Some(&b'<') => self
+                .parse_dictionary_at_depth(depth)
+                .map(PdfSyntaxObject::Dictionary),
+            b'<' => {
+                self.position += 1;
+
+                self.parse_hex_string().map(PdfSyntaxObject::String)
+            }
+            b'[' => {
+                self.position += 1;
+
+                let mut elements = Vec::new();
+
+                loop {
+                    self.skip_whitespace();
+
+                    match self.data.get(self.position) {
+                        Some(b']') => {
+                            self.position += 1;
+
+                            return Ok(PdfSyntaxObject::Array(elements));
+                        }
+                        Some(_) => elements.push(self.parse_object_at_depth(depth + 1)?),
+                        None => return Err(syntax_error("unterminated array")),
+                    }
+                }
+            }
+            _ if is_regular(byte) => self.parse_regular_object(),
+            _ => Err(syntax_error("unexpected delimiter")),
+        }
+    }
+
+    /// Parses the dictionary at the next position after any whitespace.
+    #[inline]
+    pub(crate) fn parse_dictionary(&mut self) -> Result<PdfSyntaxDictionary, PdfiumError> {
+        self.skip_whitespace();
+
+        self.parse_dictionary_at_depth(0)
+    }
+
+    fn parse_dictionary_at_depth(
+        &mut self,
+        depth: usize,
+    ) -> Result<PdfSyntaxDictionary, PdfiumError> {
+        let start = self.position;
+
+        if !self.data[start..].starts_with(b"<<") {
+            return Err(syntax_error("expected a dictionary"));
+        }
+
+        self.position += 2;
+
+        let mut entries = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+
+            let entry_start = self.position;
+
+            match self.data.get(self.position) {
+                Some(b'>') if self.data.get(self.position + 1) == Some(&b'>') => {
+                    self.position += 2;
+
+                    return Ok(PdfSyntaxDictionary {
+                        entries,
+                        span: start..self.position,
+                    });
+                }
+                Some(b'/') => {
+                    self.position += 1;
+
+                    let key = self.parse_name();
+
+                    let value = self.parse_object_at_depth(depth + 1)?;
+
+                    entries.push((key, value, entry_start..self.position));
+                }
+                Some(_) => return Err(syntax_error("expected a dictionary key")),
+                None => return Err(syntax_error("unterminated dictionary")),
+            }
+        }
+    }
+
+    /// Parses a name, excluding its leading solidus, decoding any escaped characters.
+    fn parse_name(&mut self) -> Vec<u8> {
+        let start = self.position;
+
+        while self
+            .data
+            .get(self.position)
+            .map(|byte| is_regular(*byte))
+            .unwrap_or(false)
+        {
+            self.position += 1;
+        }
+
+        let token = &self.data[start..self.position];
+
+        let mut name = Vec::with_capacity(token.len());
+
+        let mut index = 0;
+
+        while index < token.len() {
+            if token[index] == b'#' {
+                if let Some(byte) = token
+                    .get(index + 1..index + 3)
+                    .and_then(|hex| std::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                {
+                    name.push(byte);
+
+                    index += 3;
+
+                    continue;
+                }
+            }
+
+            name.push(token[index]);
+
+            index += 1;
+        }
+
+        name
+    }
+
+    /// Parses a literal string, excluding its opening parenthesis, decoding any escape sequences.
+    fn parse_literal_string(&mut self) -> Result<Vec<u8>, PdfiumError> {
+        let mut result = Vec::new();
+
+        let mut depth = 1;
+
+        loop {
+            let byte = *self
+                .data
+                .get(self.position)
+                .ok_or_else(|| syntax_error("unterminated string"))?;
+
+            self.position += 1;
+
+            match byte {
+                b'(' => {
+                    depth += 1;
+
+                    result.push(byte);
+                }
+                b')' => {
+                    depth -= 1;
+
+                    if depth == 0 {
+                        return Ok(result);
+                    }
+
+                    result.push(byte);
+                }
+                b'\\' => {
+                    let escaped = *self
+                        .data
+                        .get(self.position)
+                        .ok_or_else(|| syntax_error("unterminated string"))?;
+
+                    self.position += 1;
+
+                    match escaped {
+                        b'n' => result.push(b'\n'),
+                        b'r' => result.push(b'\r'),
+                        b't' => result.push(b'\t'),
+                        b'b' => result.push(b'\x08'),
+                        b'f' => result.push(b'\x0c'),
+                        b'0'..=b'7' => {
+                            let mut value = (escaped - b'0') as u32;
+
+                            for _ in 0..2 {
+                                match self.data.get(self.position) {
+                                    Some(digit @ b'0'..=b'7') => {
+                                        value = value * 8 + (digit - b'0') as u32;
+
+                                        self.position += 1;
+                                    }
+                                    _ => break,
+                                }
+                            }
+
+                            result.push(value as u8);
+                        }
+                        b'\r' => {
+                            // A line continuation.
+
+                            if self.data.get(self.position) == Some(&b'\n') {
+                                self.position += 1;
+                            }
+                        }
+                        b'\n' => {
+                            // A line continuation.
+                        }
+                        _ => result.push(escaped),
+                    }
+                }
+                b'\r' => {
+                    // An unescaped end-of-line marker is read as a single line feed.
+
+                    if self.data.get(self.position) == Some(&b'\n') {
+                        self.position += 1;
+                    }
+
+                    result.push(b'\n');
+                }
+                _ => result.push(byte),
+            }
+        }
+    }
+
+    /// Parses a hexadecimal string, excluding its opening angle bracket.
+    fn parse_hex_string(&mut self) -> Result<Vec<u8>, PdfiumError> {
+        let mut digits = Vec::new();
+
+        loop {
+            let byte = *self
+                .data
+                .get(self.position)
+                .ok_or_else(|| syntax_error("unterminated hexadecimal string"))?;
+
+            self.position += 1;
+
+            match byte {
+                b'>' => break,
+                b'0'..=b'9' => digits.push(byte - b'0'),
+                b'a'..=b'f' => digits.push(byte - b'a' + 10),
+                b'A'..=b'F' => digits.push(byte - b'A' + 10),
+                _ if is_whitespace(byte) => {}
+                _ => return Err(syntax_error("invalid hexadecimal string")),
+            }
+        }
+
+        // A final odd digit is read as if it were followed by a zero.
+
+        if digits.len() % 2 == 1 {
+            digits.push(0);
+        }
+
+        Ok(digits
+            .chunks(2)
+            .map(|pair| (pair[0] << 4) | pair[1])
+            .collect())
+    }
+
+    /// Parses a boolean, the null object, a number, or an indirect reference.
+    fn parse_regular_object(&mut self) -> Result<PdfSyntaxObject, PdfiumError> {
+        let token = self.next_token();
+
+        match token {
+            b"true" => return Ok(PdfSyntaxObject::Boolean(true)),
+            b"false" => return Ok(PdfSyntaxObject::Boolean(false)),
+            b"null" => return Ok(PdfSyntaxObject::Null),
+            _ => {}
+        }
+
+        let text = std::str::from_utf8(token).map_err(|_| syntax_error("invalid number"))?;
+
+        let value = text
+            .parse::<f32>()
+            .map_err(|_| syntax_error("invalid number"))?;
+
+        if let Ok(number) = text.parse::<u32>() {
+            // An object number may be followed by a generation number and the R keyword,
+            // together forming an indirect reference.
+
+            let start = self.position;
+
+            if let Ok(generation) = self.parse_integer() {
+                if generation <= u16::MAX as u64 && self.parse_keyword(b"R") {
+                    return Ok(PdfSyntaxObject::Reference(number, generation as u16));
+                }
+            }
+
+            self.position = start;
+        }
+
+        Ok(PdfSyntaxObject::Number(value))
+    }
+}
+
+/// A single indirect object parsed from document data.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PdfSyntaxIndirectObject {
+    pub(crate) number: u32,
+    pub(crate) generation: u16,
+    pub(crate) value: PdfSyntaxObject,
+
+    /// The position of the object's value in the document data, excluding the surrounding
+    /// `obj` and `endobj` keywords.
+    pub(crate) span: Range<usize>,
+}
+
+/// The location of a single annotation dictionary in document data.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PdfSyntaxAnnotation {
+    /// The annotation dictionary.
+    pub(crate) dictionary: PdfSyntaxDictionary,
+
+    /// The object number and generation number of the annotation dictionary, if the
+    /// dictionary is an indirect object.
+    pub(crate) reference: Option<(u32, u16)>,
+
+    /// The indirect object containing the annotation dictionary. This is the annotation
+    /// dictionary itself if the dictionary is an indirect object; otherwise, it is the
+    /// object containing the page's array of annotations.
+    pub(crate) container: PdfSyntaxIndirectObject,
+}
+
+/// Provides access to the indirect objects in document data written by Pdfium.
+pub(crate) struct PdfSyntaxDocument<'a> {
+    data: &'a [u8],
+    offsets: HashMap<u32, usize>,
+    trailer: PdfSyntaxDictionary,
+}
+
+impl<'a> PdfSyntaxDocument<'a> {
+    /// Reads the cross-reference tables and trailer of the given document data.
+    pub(crate) fn new(data: &'a [u8]) -> Result<Self, PdfiumError> {
+        let mut offset = last_cross_reference_offset(data)?;
+
+        let mut offsets = HashMap::new();
+
+        let mut trailer = None;
+
+        let mut visited = Vec::new();
+
+        loop {
+            if visited.contains(&offset) || offset >= data.len() {
+                return Err(syntax_error("invalid cross-reference table offset"));
+            }
+
+            visited.push(offset);
+
+            let mut parser = PdfSyntaxParser::new(data, offset);
+
+            if !parser.parse_keyword(b"xref") {
+                return Err(syntax_error("cross-reference streams are not supported"));
+            }
+
+            while !parser.parse_keyword(b"trailer") {
+                let first = parser.parse_integer()?;
+
+                let count = parser.parse_integer()?;
+
+                for number in first..first + count {
+                    let entry_offset = parser.parse_integer()?;
+
+                    parser.parse_integer()?;
+
+                    let is_in_use = match parser.next_token() {
+                        b"n" => true,
+                        b"f" => false,
+                        _ => return Err(syntax_error("invalid cross-reference entry")),
+                    };
+
+                    // Entries in later sections of the file take precedence over entries
+                    // in earlier sections, which are read afterwards.
+
+                    if is_in_use && number <= u32::MAX as u64 {
+                        offsets
+                            .entry(number as u32)
+                            .or_insert(entry_offset as usize);
+                    }
+                }
+            }
+
+            let section_trailer = parser.parse_dictionary()?;
+
+            let previous = section_trailer
+                .get(b"Prev")
+                .and_then(|value| value.as_number());
+
+            if trailer.is_none() {
+                trailer = Some(section_trailer);
+            }
+
+            match previous {
+                Some(previous) if previous >= 0.0 => offset = previous as usize,
+                _ => break,
+            }
+        }
+
+        Ok(PdfSyntaxDocument {
+            data,
+            offsets,
+            trailer: trailer.ok_or_else(|| syntax_error("no trailer"))?,
+        })
+    }
+
+    /// Returns the indirect object with the given object number.
+    pub(crate) fn object(&self, number: u32) -> Result<PdfSyntaxIndirectObject, PdfiumError> {
+        let offset = *self
+            .offsets
+            .get(&number)
+            .ok_or_else(|| syntax_error("object not found"))?;
+
+        let mut parser = PdfSyntaxParser::new(self.data, offset);
+
+        if parser.parse_integer()? != number as u64 {
+            return Err(syntax_error("object number mismatch"));
+        }
+
+        let generation = parser.parse_integer()?;
+
+        if generation > u16::MAX as u64 || !parser.parse_keyword(b"obj") {
+            return Err(syntax_error("invalid object header"));
+        }
+
+        parser.skip_whitespace();
+
+        let start = parser.position();
+
+        let value = parser.parse_object()?;
+
+        Ok(PdfSyntaxIndirectObject {
+            number,
+            generation: generation as u16,
+            value,
+            span: start..parser.position(),
+        })
+    }
+
+    /// Returns the given object, or the object it refers to if the given object is
+    /// an indirect reference.
+    pub(crate) fn resolve<'b>(
+        &self,
+        object: &'b PdfSyntaxObject,
+    ) -> Result<Cow<'b, PdfSyntaxObject>, PdfiumError> {
+        match object {
+            PdfSyntaxObject::Reference(number, _) => {
+                let mut value = self.object(*number)?.value;
+
+                // A reference to a reference is permitted, if unusual.
+
+                for _ in 0..MAXIMUM_DEPTH {
+                    match value {
+                        PdfSyntaxObject::Reference(number, _) => value = self.object(number)?.value,
+                        _ => return Ok(Cow::Owned(value)),
+                    }
+                }
+
+                Err(syntax_error("indirect references nested too deeply"))
+            }
+            _ => Ok(Cow::Borrowed(object)),
+        }
+    }
+
+    /// Returns a copy of the given object in which all indirect references, including those
+    /// inside arrays and dictionaries, are replaced by the objects they refer to.
+    pub(crate) fn resolve_all(
+        &self,
+        object: &PdfSyntaxObject,
+    ) -> Result<PdfSyntaxObject, PdfiumError> {
+        self.resolve_all_at_depth(object, 0)
+    }
+
+    fn resolve_all_at_depth(
+        &self,
+        object: &PdfSyntaxObject,
+        depth: usize,
+    ) -> Result<PdfSyntaxObject, PdfiumError> {
+        if depth > MAXIMUM_DEPTH {
+            return Err(syntax_error("indirect references nested too deeply"));
+        }
+
+        Ok(match self.resolve(object)?.as_ref() {
+            PdfSyntaxObject::Array(elements) => PdfSyntaxObject::Array(
+                elements
+                    .iter()
+                    .map(|element| self.resolve_all_at_depth(element, depth + 1))
+                    .collect::<Result<_, _>>()?,
+            ),
+            PdfSyntaxObject::Dictionary(dictionary) => {
+                PdfSyntaxObject::Dictionary(PdfSyntaxDictionary {
+                    entries: dictionary
+                        .entries
+                        .iter()
+                        .map(|(key, value, span)| {
+                            self.resolve_all_at_depth(value, depth + 1)
+                                .map(|value| (key.clone(), value, span.clone()))
+                        })
+                        .collect::<Result<_, _>>()?,
+                    span: dictionary.span(),
+                })
+            }
+            other => other.clone(),
+        })
+    }
+
+    /// Returns the page object at the given zero-based index in the document's page tree.
+    pub(crate) fn page(&self, index: usize) -> Result<PdfSyntaxIndirectObject, PdfiumError> {
+        let root = self
+            .trailer
+            .get(b"Root")
+            .and_then(|root| root.as_reference())
+            .ok_or_else(|| syntax_error("trailer has no /Root entry"))?;
+
+        let pages = self
+            .object(root.0)?
+            .value
+            .as_dictionary()
+            .and_then(|catalog| catalog.get(b"Pages"))
+            .and_then(|pages| pages.as_reference())
+            .ok_or_else(|| syntax_error("catalog has no /Pages entry"))?;
+
+        let mut node = self.object(pages.0)?;
+
+        let mut remaining = index;
+
+        for _ in 0..MAXIMUM_DEPTH {
+            let kids = match node
+                .value
+                .as_dictionary()
+                .and_then(|node| node.get(b"Kids"))
+            {
+                Some(kids) => self.resolve(kids)?.into_owned(),
+                None => return Err(syntax_error("page tree node has no /Kids entry")),
+            };
+
+            let mut next = None;
+
+            for kid in kids.as_array().unwrap_or(&[]) {
+                let kid = self.object(
+                    kid.as_reference()
+                        .ok_or_else(|| syntax_error("page tree kid is not a reference"))?
+                        .0,
+                )?;
+
+                let dictionary = kid
+                    .value
+                    .as_dictionary()
+                    .ok_or_else(|| syntax_error("page tree kid is not a dictionary"))?;
+
+                if dictionary.get(b"Kids").is_some() {
+                    // This kid is an intermediate node. Descend into it only if it contains
+                    // the page we are looking for.
+
+                    let count = match dictionary.get(b"Count") {
+                        Some(count) => self.resolve(count)?.as_number().unwrap_or(0.0) as usize,
+                        None => 0,
+                    };
+
+                    if remaining < count {
+                        next = Some(kid);
+
+                        break;
+                    }
+
+                    remaining -= count;
+                } else if remaining == 0 {
+                    return Ok(kid);
+                } else {
+                    remaining -= 1;
+                }
+            }
+
+            node = next.ok_or_else(|| syntax_error("page not found in page tree"))?;
+        }
+
+        Err(syntax_error("page tree nested too deeply"))
+    }
+
+    /// Returns the location of the annotation at the given zero-based index in the
+    /// `/Annots` array of the page at the given zero-based index.
+    pub(crate) fn annotation(
+        &self,
+        page_index: usize,
+        annotation_index: usize,
+    ) -> Result<PdfSyntaxAnnotation, PdfiumError> {
+        let page = self.page(page_index)?;
+
+        let annotations = page
+            .value
+            .as_dictionary()
+            .and_then(|page| page.get(b"Annots"))
+            .ok_or_else(|| syntax_error("page has no /Annots entry"))?
+            .clone();
+
+        let (annotations, container) = match annotations {
+            PdfSyntaxObject::Reference(number, _) => {
+                let container = self.object(number)?;
+
+                (container.value.clone(), container)
+            }
+            _ => (annotations, page),
+        };
+
+        let annotation = annotations
+            .as_array()
+            .and_then(|annotations| annotations.get(annotation_index))
+            .ok_or_else(|| syntax_error("annotation not found in /Annots array"))?;
+
+        match annotation {
+            PdfSyntaxObject::Reference(number, generation) => {
+                let container = self.object(*number)?;
+
+                match container.value.as_dictionary() {
+                    Some(dictionary) => Ok(PdfSyntaxAnnotation {
+                        dictionary: dictionary.clone(),
+                        reference: Some((*number, *generation)),
+                        container,
+                    }),
+                    None => Err(syntax_error("annotation is not a dictionary")),
+                }
+            }
+            PdfSyntaxObject::Dictionary(dictionary) => Ok(PdfSyntaxAnnotation {
+                dictionary: dictionary.clone(),
+                reference: None,
+                container,
+            }),
+            _ => Err(syntax_error("annotation is not a dictionary")),
+        }
+    }
+}
+
+/// Returns the offset of the last cross-reference section in the given document data,
+/// as recorded after the final `startxref` keyword in the data.
+fn last_cross_reference_offset(data: &[u8]) -> Result<usize, PdfiumError> {
+    let position = data
+        .windows(b"startxref".len())
+        .rposition(|window| window == b"startxref")
+        .ok_or_else(|| syntax_error("no startxref keyword"))?;
+
+    PdfSyntaxParser::new(data, position + b"startxref".len())
+        .parse_integer()
+        .map(|offset| offset as usize)
+}
+
+/// Returns the trailer dictionary of the last cross-reference section in the given
+/// document data, along with the offset of that section. If the last section is a
+/// cross-reference stream, the stream dictionary is returned.
+fn last_trailer(data: &[u8]) -> Result<(PdfSyntaxDictionary, usize), PdfiumError> {
+    let offset = last_cross_reference_offset(data)?;
+
+    let mut parser = PdfSyntaxParser::new(data, offset);
+
+    if parser.parse_keyword(b"xref") {
+        while !parser.parse_keyword(b"trailer") {
+            let _first = parser.parse_integer()?;
+
+            let count = parser.parse_integer()?;
+
+            for _ in 0..count * 3 {
+                if parser.next_token().is_empty() {
+                    return Err(syntax_error("invalid cross-reference entry"));
+                }
+            }
+        }
+    } else {
+        parser.parse_integer()?;
+        parser.parse_integer()?;
+
+        if !parser.parse_keyword(b"obj") {
+            return Err(syntax_error("invalid cross-reference section"));
+        }
+    }
+
+    Ok((parser.parse_dictionary()?, offset))
+}
+
+/// Returns the file data of the given document, rewritten in full by Pdfium.
+pub(crate) fn save_document(
+    bindings: &dyn PdfiumLibraryBindings,
+    document: FPDF_DOCUMENT,
+) -> Result<Vec<u8>, PdfiumError> {
+    let mut cursor = Cursor::new(Vec::new());
+
+    let mut pdfium_file_writer = get_pdfium_file_writer_from_writer(&mut cursor);
+
+    if !bindings.is_true(bindings.FPDF_SaveAsCopy(
+        document,
+        pdfium_file_writer.as_fpdf_file_write_mut_ptr(),
+        0,
+    )) {
+        return Err(PdfiumError::PdfiumLibraryInternalError(
+            PdfiumInternalError::Unknown,
+        ));
+    }
+
+    pdfium_file_writer.flush().map_err(PdfiumError::IoError)?;
+
+    Ok(cursor.into_inner())
+}
+
+/// Appends an incremental update to the given document data, replacing each of the given
+/// indirect objects, identified by object number and generation number, with the given
+/// value written in PDF object syntax.
+pub(crate) fn append_incremental_update(
+    data: &mut Vec<u8>,
+    objects: &[(u32, u16, Vec<u8>)],
+) -> Result<(), PdfiumError> {
+    if objects.is_empty() {
+        return Ok(());
+    }
+
+    let (trailer, previous) = last_trailer(data)?;
+
+    // Carry over the entries of the previous trailer, except those describing the previous
+    // cross-reference section itself.
+
+    const SECTION_KEYS: [&[u8]; 8] = [
+        b"Prev",
+        b"XRefStm",
+        b"Type",
+        b"W",
+        b"Index",
+        b"Filter",
+        b"DecodeParms",
+        b"Length",
+    ];
+
+    let mut trailer_entries = Vec::new();
+
+    for (key, _, span) in trailer.entries.iter() {
+        if !SECTION_KEYS.contains(&key.as_slice()) {
+            trailer_entries.push(b' ');
+            trailer_entries.extend_from_slice(&data[span.clone()]);
+        }
+    }
+
+    if !data.ends_with(b"\n") && !data.ends_with(b"\r") {
+        data.push(b'\n');
+    }
+
+    let mut objects = objects.to_vec();
+
+    objects.sort_by_key(|(number, _, _)| *number);
+
+    let mut offsets = Vec::with_capacity(objects.len());
+
+    for (number, generation, value) in objects.iter() {
+        offsets.push(data.len());
+
+        data.extend_from_slice(format!("{} {} obj\n", number, generation).as_bytes());
+        data.extend_from_slice(value);
+        data.extend_from_slice(b"\nendobj\n");
+    }
+
+    let cross_reference_offset = data.len();
+
+    data.extend_from_slice(b"xref\n");
+
+    for ((number, generation, _), offset) in objects.iter().zip(offsets.iter()) {
+        data.extend_from_slice(
+            format!("{} 1\n{:010} {:05} n\r\n", number, offset, generation).as_bytes(),
+        );
+    }
+
+    data.extend_from_slice(b"trailer\n<<");
+    data.extend_from_slice(&trailer_entries);
+    data.extend_from_slice(
+        format!(
+            " /Prev {} >>\nstartxref\n{}\n%%EOF\n",
+            previous, cross_reference_offset
+        )
+        .as_bytes(),
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(data: &[u8]) -> PdfSyntaxObject {
+        PdfSyntaxParser::new(data, 0).parse_object().unwrap()
+    }
+
+    #[test]
+    fn test_parse_objects() {
+        assert_eq!(parse(b" null"), PdfSyntaxObject::Null);
+        assert_eq!(parse(b"true"), PdfSyntaxObject::Boolean(true));
+        assert_eq!(parse(b"-.5"), PdfSyntaxObject::Number(-0.5));
+        assert_eq!(parse(b"12 0 R"), PdfSyntaxObject::Reference(12, 0));
+        assert_eq!(
+            parse(b"[1 2 3]"),
+            PdfSyntaxObject::Array(vec![
+                PdfSyntaxObject::Number(1.0),
+                PdfSyntaxObject::Number(2.0),
+                PdfSyntaxObject::Number(3.0),
+            ])
+        );
+        assert_eq!(
+            parse(b"[1 0 R 2]"),
+            PdfSyntaxObject::Array(vec![
+                PdfSyntaxObject::Reference(1, 0),
+                PdfSyntaxObject::Number(2.0),
+            ])
+        );
+        assert_eq!(parse(b"/A#20B"), PdfSyntaxObject::Name(b"A B".to_vec()));
+        assert_eq!(
+            parse(b"(a(b)\\051\\n\\\nc)"),
+            PdfSyntaxObject::String(b"a(b))\nc".to_vec())
+        );
+        assert_eq!(
+            parse(b"<48 65 6c6C 6>"),
+            PdfSyntaxObject::String(b"Hell`".to_vec())
+        );
+
+        let dictionary = parse(b"<< /Type /Border % comment\n /D [3 2] /W 2 >>");
+
+        let dictionary = dictionary.as_dictionary().unwrap();
+
+        assert_eq!(
+            dictionary.get(b"Type"),
+            Some(&PdfSyntaxObject::Name(b"Border".to_vec()))
+        );
+        assert_eq!(dictionary.get(b"W"), Some(&PdfSyntaxObject::Number(2.0)));
+        assert_eq!(dictionary.get(b"S"), None);
+
+        assert!(PdfSyntaxParser::new(b"<< /A 1", 0).parse_object().is_err());
+        assert!(PdfSyntaxParser::new(b"<< 1 2 >>", 0)
+            .parse_object()
+            .is_err());
+        assert!(PdfSyntaxParser::new(&[b'['; 100], 0)
+            .parse_object()
+            .is_err());
+    }
+
+    #[test]
+    fn test_dictionary_changes() {
+        let data = b"<< /Type /Annot /BS << /S /D >> /C [1 0 0] >>";
+
+        let dictionary = parse(data);
+
+        let dictionary = dictionary.as_dictionary().unwrap();
+
+        let changed = dictionary.to_bytes_with_changes(
+            data,
+            &[
+                (b"BS".to_vec(), Some(b"<< /S /B >>".to_vec())),
+                (b"C".to_vec(), None),
+                (b"BE".to_vec(), None),
+            ],
+        );
+
+        assert_eq!(changed, b"<< /Type /Annot /BS << /S /B >> >>".to_vec());
+
+        assert_eq!(name_to_bytes(b"A B#"), b"/A#20B#23".to_vec());
+        assert_eq!(number_to_bytes(2.5), b"2.5".to_vec());
+        assert_eq!(number_to_bytes(3.0), b"3".to_vec());
+    }
+
+    #[test]
+    fn test_document_annotations() -> Result<(), PdfiumError> {
+        let data = std::fs::read("./test/border-style-test.pdf").unwrap();
+
+        let document = PdfSyntaxDocument::new(&data)?;
+
+        let annotation = document.annotation(0, 0)?;
+
+        assert_eq!(annotation.reference, Some((4, 0)));
+        assert_eq!(annotation.container.number, 4);
+        assert_eq!(
+            annotation.dictionary.get(b"Subtype"),
+            Some(&PdfSyntaxObject::Name(b"Square".to_vec()))
+        );
+
+        // Indirect references inside the annotation dictionary can be resolved.
+
+        let annotation = document.annotation(0, 5)?;
+
+        let style = document.resolve_all(annotation.dictionary.get(b"BS").unwrap())?;
+
+        assert_eq!(
+            style.as_dictionary().unwrap().get(b"S"),
+            Some(&PdfSyntaxObject::Name(b"D".to_vec()))
+        );
+
+        assert!(document.annotation(0, 100).is_err());
+        assert!(document.page(1).is_err());
+
+        Ok(())
+    }
+
+    /// Returns the file data of a document containing the given objects, numbered from 1,
+    /// with object 1 as the document catalog.
+    fn build(objects: &[&str]) -> Vec<u8> {
+        let mut data = b"%PDF-1.7\n".to_vec();
+
+        let mut offsets = Vec::new();
+
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(data.len());
+
+            data.extend_from_slice(format!("{} 0 obj\n{}\nendobj\n", index + 1, object).as_bytes());
+        }
+
+        let cross_reference_offset = data.len();
+
+        data.extend_from_slice(
+            format!("xref\n0 {}\n0000000000 65535 f\r\n", objects.len() + 1).as_bytes(),
+        );
+
+        for offset in offsets {
+            data.extend_from_slice(format!("{:010} 00000 n\r\n", offset).as_bytes());
+        }
+
+        data.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+                objects.len() + 1,
+                cross_reference_offset
+            )
+            .as_bytes(),
+        );
+
+        data
+    }
+
+    #[test]
+    fn test_document_page_tree() -> Result<(), PdfiumError> {
+        let data = build(&[
+            "<< /Type /Catalog /Pages 2 0 R >>",
+            "<< /Type /Pages /Kids [3 0 R 4 0 R] /Count 3 >>",
+            "<< /Type /Pages /Parent 2 0 R /Kids [5 0 R 6 0 R] /Count 2 >>",
+            "<< /Type /Page /Parent 2 0 R /Annots 7 0 R >>",
+            "<< /Type /Page /Parent 3 0 R >>",
+            "<< /Type /Page /Parent 3 0 R /Annots [<< /Subtype /Text /NM (a) >> 8 0 R] >>",
+            "[<< /Subtype /Square >>]",
+            "<< /Subtype /Circle >>",
+        ]);
+
+        let document = PdfSyntaxDocument::new(&data)?;
+
+        assert_eq!(document.page(0)?.number, 5);
+        assert_eq!(document.page(1)?.number, 6);
+        assert_eq!(document.page(2)?.number, 4);
+        assert!(document.page(3).is_err());
+
+        // A direct annotation dictionary is contained by the page...
+
+        let annotation = document.annotation(1, 0)?;
+
+        assert_eq!(annotation.reference, None);
+        assert_eq!(annotation.container.number, 6);
+        assert_eq!(
+            annotation.dictionary.get(b"NM"),
+            Some(&PdfSyntaxObject::String(b"a".to_vec()))
+        );
+
+        // ... or by the annotations array, if the array is an indirect object.
+
+        let annotation = document.annotation(2, 0)?;
+
+        assert_eq!(annotation.reference, None);
+        assert_eq!(annotation.container.number, 7);
+
+        let annotation = document.annotation(1, 1)?;
+
+        assert_eq!(annotation.reference, Some((8, 0)));
+        assert_eq!(annotation.container.number, 8);
+
+        assert!(document.annotation(0, 0).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_append_incremental_update() -> Result<(), PdfiumError> {
+        let mut data = std::fs::read("./test/border-style-test.pdf").unwrap();
+
+        let original_length = data.len();
+
+        let (number, generation, value) = {
+            let document = PdfSyntaxDocument::new(&data)?;
+
+            let annotation = document.annotation(0, 0)?;
+
+            let value = annotation
+                .dictionary
+                .to_bytes_with_changes(&data, &[(b"BE".to_vec(), None)]);
+
+            (
+                annotation.container.number,
+                annotation.container.generation,
+                value,
+            )
+        };
+
+        append_incremental_update(&mut data, &[(number, generation, value)])?;
+
+        assert!(data.len() > original_length);
+
+        let document = PdfSyntaxDocument::new(&data)?;
+
+        let annotation = document.annotation(0, 0)?;
+
+        assert!(annotation.container.span.start > original_length);
+        assert_eq!(annotation.dictionary.get(b"BE"), None);
+        assert!(annotation.dictionary.get(b"BS").is_some());
+
+        let (trailer, _) = last_trailer(&data)?;
+
+        assert!(trailer.get(b"Root").is_some());
+        assert!(trailer.get(b"Prev").is_some());
+
+        Ok(())
+    }
+}
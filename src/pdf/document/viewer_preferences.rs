@@ -0,0 +1,292 @@
+//! Defines the [PdfViewerPreferences] struct, exposing functionality related to the
+//! viewer preferences and print settings of a single `PdfDocument`.
+
+use crate::bindgen::{
+    _FPDF_DUPLEXTYPE__DuplexFlipLongEdge, _FPDF_DUPLEXTYPE__DuplexFlipShortEdge,
+    _FPDF_DUPLEXTYPE__DuplexUndefined, _FPDF_DUPLEXTYPE__Simplex, FPDF_DOCUMENT, FPDF_DUPLEXTYPE,
+};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::pages::PdfPageIndex;
+use crate::pdf::document::PdfDocument;
+use crate::utils::mem::create_byte_buffer;
+use std::ops::RangeInclusive;
+use std::os::raw::{c_char, c_ulong};
+
+/// The paper handling option that should be used when printing a `PdfDocument`,
+/// as recorded in the /Duplex entry of the document's viewer preferences dictionary.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PdfPrintDuplexMode {
+    /// Print single-sided.
+    Simplex,
+
+    /// Print double-sided, flipping the paper on the short edge of the sheet.
+    DuplexFlipShortEdge,
+
+    /// Print double-sided, flipping the paper on the long edge of the sheet.
+    DuplexFlipLongEdge,
+}
+
+impl PdfPrintDuplexMode {
+    #[allow(non_upper_case_globals)]
+    pub(crate) fn from_pdfium(value: FPDF_DUPLEXTYPE) -> Option<Self> {
+        match value {
+            _FPDF_DUPLEXTYPE__Simplex => Some(PdfPrintDuplexMode::Simplex),
+            _FPDF_DUPLEXTYPE__DuplexFlipShortEdge => Some(PdfPrintDuplexMode::DuplexFlipShortEdge),
+            _FPDF_DUPLEXTYPE__DuplexFlipLongEdge => Some(PdfPrintDuplexMode::DuplexFlipLongEdge),
+            _FPDF_DUPLEXTYPE__DuplexUndefined => None,
+            _ => None,
+        }
+    }
+}
+
+/// The viewer preferences of a single `PdfDocument`, as recorded in the
+/// /ViewerPreferences dictionary of the document catalog.
+///
+/// Viewer preferences describe how a conforming viewer should present the document on screen,
+/// and which settings it should pre-select in its print dialog when the document is printed.
+/// All preferences are optional; when a preference is not set, a viewer is free to use its
+/// own default.
+///
+/// Pdfium currently only offers support for reading the existing viewer preferences of a
+/// document, or copying the viewer preferences of one document into another. It does not
+/// support setting individual viewer preferences.
+pub struct PdfViewerPreferences<'a> {
+    document_handle: FPDF_DOCUMENT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfViewerPreferences<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        document_handle: FPDF_DOCUMENT,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        Self {
+            document_handle,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfViewerPreferences] object.
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns `true` if a viewer's print dialog should apply its default page scaling
+    /// when printing the containing `PdfDocument`, or `false` if the document should be
+    /// printed without any page scaling.
+    ///
+    /// Returns `true` if the document does not specify a print scaling preference.
+    #[inline]
+    pub fn is_print_scaling_enabled(&self) -> bool {
+        self.bindings().is_true(
+            self.bindings()
+                .FPDF_VIEWERREF_GetPrintScaling(self.document_handle),
+        )
+    }
+
+    /// Returns the number of copies a viewer's print dialog should pre-select when printing
+    /// the containing `PdfDocument`.
+    ///
+    /// Returns `1` if the document does not specify a number of copies.
+    #[inline]
+    pub fn copies(&self) -> u32 {
+        self.bindings()
+            .FPDF_VIEWERREF_GetNumCopies(self.document_handle)
+            .max(1) as u32
+    }
+
+    /// Returns the page ranges a viewer's print dialog should pre-select when printing
+    /// the containing `PdfDocument`.
+    ///
+    /// Page ranges are returned as inclusive ranges of zero-based page indices. Returns an
+    /// empty list if the document does not specify any print page ranges, in which case all
+    /// pages should be printed.
+    pub fn print_page_ranges(&self) -> Vec<RangeInclusive<PdfPageIndex>> {
+        let page_range = self
+            .bindings()
+            .FPDF_VIEWERREF_GetPrintPageRange(self.document_handle);
+
+        if page_range.is_null() {
+            return Vec::new();
+        }
+
+        let count = self
+            .bindings()
+            .FPDF_VIEWERREF_GetPrintPageRangeCount(page_range);
+
+        // The /PrintPageRange entry is an array of (first page, last page) pairs,
+        // using one-based page numbers. Any malformed pair is ignored.
+
+        (0..count / 2)
+            .filter_map(|pair| {
+                let first = self
+                    .bindings()
+                    .FPDF_VIEWERREF_GetPrintPageRangeElement(page_range, pair * 2);
+
+                let last = self
+                    .bindings()
+                    .FPDF_VIEWERREF_GetPrintPageRangeElement(page_range, pair * 2 + 1);
+
+                if first < 1 || last < first {
+                    None
+                } else {
+                    Some((first - 1) as PdfPageIndex..=(last - 1) as PdfPageIndex)
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the paper handling option a viewer's print dialog should pre-select when
+    /// printing the containing `PdfDocument`, if the document specifies one.
+    #[inline]
+    pub fn duplex(&self) -> Option<PdfPrintDuplexMode> {
+        PdfPrintDuplexMode::from_pdfium(
+            self.bindings()
+                .FPDF_VIEWERREF_GetDuplex(self.document_handle),
+        )
+    }
+
+    /// Returns the value of the name-valued viewer preference with the given key, if any.
+    ///
+    /// This can be used to retrieve viewer preferences not otherwise exposed by this struct,
+    /// such as `Direction`, `NonFullScreenPageMode`, or `PrintArea`. The key should be given
+    /// without a leading slash.
+    pub fn get_name(&self, key: &str) -> Option<String> {
+        // Retrieving the value from Pdfium is a two-step operation. First, we call
+        // FPDF_VIEWERREF_GetName() with a null buffer; this will retrieve the length of
+        // the value in bytes, including the trailing nul. If the key does not exist
+        // or its value is not a name, the length will be zero.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDF_VIEWERREF_GetName() again with a pointer to the buffer;
+        // this will write the value to the buffer.
+
+        let buffer_length = self.bindings().FPDF_VIEWERREF_GetName(
+            self.document_handle,
+            key,
+            std::ptr::null_mut(),
+            0,
+        );
+
+        if buffer_length == 0 {
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings().FPDF_VIEWERREF_GetName(
+            self.document_handle,
+            key,
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer_length as c_ulong,
+        );
+
+        if result != buffer_length {
+            return None;
+        }
+
+        let value = String::from_utf8_lossy(&buffer)
+            .trim_end_matches(char::from(0))
+            .to_owned();
+
+        if value.is_empty() {
+            None
+        } else {
+            Some(value)
+        }
+    }
+
+    /// Replaces the viewer preferences of the containing `PdfDocument` with the viewer
+    /// preferences of the given source [PdfDocument].
+    ///
+    /// Returns an error if the source document does not have any viewer preferences.
+    pub fn copy_from_document(&mut self, source: &PdfDocument) -> Result<(), PdfiumError> {
+        if self.bindings().is_true(
+            self.bindings()
+                .FPDF_CopyViewerPreferences(self.document_handle, source.handle()),
+        ) {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_viewer_preferences() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/viewer-preferences-test.pdf", None)?;
+
+        let preferences = document.viewer_preferences();
+
+        assert_eq!(preferences.print_page_ranges(), vec![0..=1, 3..=3]);
+        assert_eq!(
+            preferences.duplex(),
+            Some(PdfPrintDuplexMode::DuplexFlipLongEdge)
+        );
+        assert_eq!(preferences.copies(), 2);
+        assert!(!preferences.is_print_scaling_enabled());
+        assert_eq!(preferences.get_name("Direction").as_deref(), Some("R2L"));
+        assert!(preferences.get_name("PrintArea").is_none());
+
+        let document = pdfium.create_new_pdf()?;
+
+        let preferences = document.viewer_preferences();
+
+        assert!(preferences.print_page_ranges().is_empty());
+        assert!(preferences.duplex().is_none());
+        assert_eq!(preferences.copies(), 1);
+        assert!(preferences.is_print_scaling_enabled());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_append_copies_viewer_preferences_into_empty_document() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let source = pdfium.load_pdf_from_file("./test/viewer-preferences-test.pdf", None)?;
+
+        let other = pdfium.load_pdf_from_file("./test/named-destinations-test.pdf", None)?;
+
+        // Appending to an empty document copies the source document's viewer preferences.
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        document.pages_mut().append(&source)?;
+
+        assert_eq!(document.pages().len(), 4);
+        assert_eq!(
+            document.viewer_preferences().duplex(),
+            Some(PdfPrintDuplexMode::DuplexFlipLongEdge)
+        );
+        assert_eq!(
+            document.viewer_preferences().print_page_ranges(),
+            vec![0..=1, 3..=3]
+        );
+
+        // Appending to a document that already has pages leaves its viewer preferences unchanged.
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        document.pages_mut().append(&other)?;
+        document.pages_mut().append(&source)?;
+
+        assert_eq!(document.pages().len(), 7);
+        assert!(document.viewer_preferences().duplex().is_none());
+        assert!(document.viewer_preferences().print_page_ranges().is_empty());
+
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@
 use crate::bindgen::FS_QUADPOINTSF;
 use crate::pdf::points::PdfPoints;
 use crate::pdf::rect::PdfRect;
+use itertools::{max, min};
 use std::fmt::{Display, Formatter};
 
 /// A set of four coordinates expressed in [PdfPoints] that outline the bounds of a
@@ -112,6 +113,21 @@ impl PdfQuadPoints {
         )
     }
 
+    /// Returns the smallest [PdfRect] that contains all four vertices of this [PdfQuadPoints].
+    #[inline]
+    pub fn to_rect(&self) -> PdfRect {
+        let xs = [self.x1, self.x2, self.x3, self.x4];
+
+        let ys = [self.y1, self.y2, self.y3, self.y4];
+
+        PdfRect::new(
+            min(ys).unwrap_or(PdfPoints::ZERO),
+            min(xs).unwrap_or(PdfPoints::ZERO),
+            max(ys).unwrap_or(PdfPoints::ZERO),
+            max(xs).unwrap_or(PdfPoints::ZERO),
+        )
+    }
+
     #[inline]
     pub(crate) fn as_pdfium(&self) -> FS_QUADPOINTSF {
         FS_QUADPOINTSF {
@@ -16,6 +16,7 @@ use crate::bindings::static_bindings::StaticPdfiumBindings;
 
 #[cfg(not(target_arch = "wasm32"))]
 use {
+    crate::pdf::document::progressive::{PdfProgressiveLoader, PdfRangeFetcher},
     crate::utils::files::get_pdfium_file_accessor_from_reader,
     std::fs::File,
     std::io::{Read, Seek},
@@ -307,6 +308,26 @@ impl Pdfium {
         })
     }
 
+    /// Starts loading a [PdfDocument] progressively from the given [PdfRangeFetcher],
+    /// returning a [PdfProgressiveLoader] that reports which byte ranges of the document
+    /// are still needed.
+    ///
+    /// Unlike the other `load_pdf_*()` functions, the document data need not be available
+    /// in full. Pdfium checks which byte ranges have already arrived using the given fetcher,
+    /// and reports any byte ranges it still needs back to the fetcher. For linearized documents,
+    /// this allows the first page to be displayed before the remainder of the document
+    /// has been downloaded.
+    ///
+    /// This function is not available when compiling to WASM.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    pub fn load_pdf_progressively<'a>(
+        &'a self,
+        fetcher: impl PdfRangeFetcher + 'a,
+    ) -> Result<PdfProgressiveLoader<'a>, PdfiumError> {
+        PdfProgressiveLoader::from_fetcher(fetcher, self.bindings())
+    }
+
     /// Attempts to open a [PdfDocument] by loading document data from the given URL.
     /// The Javascript `fetch()` API is used to download data over the network.
     ///
@@ -156,6 +156,9 @@ pub(crate) mod dates {
 }
 
 pub(crate) mod mem {
+    use crate::bindgen::FPDF_BOOL;
+    use std::os::raw::{c_ulong, c_void};
+
     /// Creates an empty byte buffer of the given length.
     #[inline]
     pub(crate) fn create_byte_buffer(length: usize) -> Vec<u8> {
@@ -175,6 +178,37 @@ pub(crate) mod mem {
 
         buffer
     }
+
+    /// Retrieves a byte buffer from Pdfium using the given accessor function, which is expected
+    /// to follow Pdfium's convention of reporting the required buffer length via an out parameter.
+    /// The accessor is called twice: first with a null buffer to retrieve the required length,
+    /// then with a buffer of that length to retrieve the data itself.
+    ///
+    /// Returns `None` if either call fails or if the required buffer length is zero.
+    pub(crate) fn get_pdfium_byte_buffer(
+        accessor: impl Fn(*mut c_void, c_ulong, *mut c_ulong) -> FPDF_BOOL,
+    ) -> Option<Vec<u8>> {
+        let mut buffer_length: c_ulong = 0;
+
+        if accessor(std::ptr::null_mut(), 0, &mut buffer_length) == 0 || buffer_length == 0 {
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        if accessor(
+            buffer.as_mut_ptr() as *mut c_void,
+            buffer_length,
+            &mut buffer_length,
+        ) == 0
+        {
+            return None;
+        }
+
+        buffer.truncate(buffer_length as usize);
+
+        Some(buffer)
+    }
 }
 
 pub(crate) mod utf16le {
@@ -243,6 +277,15 @@ pub(crate) mod files {
     use std::ptr::null_mut;
     use std::slice;
 
+    #[cfg(not(target_arch = "wasm32"))]
+    use {
+        crate::bindgen::{FPDF_BOOL, FX_DOWNLOADHINTS, FX_FILEAVAIL},
+        crate::pdf::document::progressive::PdfRangeFetcher,
+        std::cell::RefCell,
+        std::panic::{catch_unwind, AssertUnwindSafe},
+        std::rc::Rc,
+    };
+
     // These functions return wrapped versions of Pdfium's file access structs. They are used
     // in callback functions to connect Pdfium's file access operations to an underlying
     // Rust reader or writer.
@@ -359,6 +402,129 @@ pub(crate) mod files {
         }
     }
 
+    /// Returns a wrapped Pdfium `FX_FILEAVAIL` struct that uses the given fetcher to answer
+    /// Pdfium's queries as to whether a given range of document data has been downloaded.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn get_pdfium_file_avail_from_fetcher<'a>(
+        fetcher: Rc<RefCell<dyn PdfRangeFetcher + 'a>>,
+    ) -> Box<FxFileAvailExt<'a>> {
+        // Pdfium retains the FX_FILEAVAIL pointer for the lifetime of the availability
+        // provider, so the struct must be boxed to keep its memory location stable.
+
+        Box::new(FxFileAvailExt {
+            version: 1,
+            is_data_avail: Some(is_data_avail_from_callback),
+            fetcher,
+        })
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[repr(C)]
+    pub(crate) struct FxFileAvailExt<'a> {
+        // An extension of Pdfium's FX_FILEAVAIL struct that adds an extra field to carry the
+        // user-provided Rust fetcher.
+        version: c_int,
+        is_data_avail: Option<
+            unsafe extern "C" fn(
+                file_avail_ext_ptr: *mut FxFileAvailExt,
+                offset: usize,
+                size: usize,
+            ) -> FPDF_BOOL,
+        >,
+        fetcher: Rc<RefCell<dyn PdfRangeFetcher + 'a>>,
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    impl<'a> FxFileAvailExt<'a> {
+        /// Returns an `FX_FILEAVAIL` pointer suitable for passing to `FPDFAvail_Create()`.
+        #[inline]
+        pub(crate) fn as_fx_file_avail_mut_ptr(&mut self) -> &mut FX_FILEAVAIL {
+            unsafe { &mut *(self as *mut FxFileAvailExt as *mut FX_FILEAVAIL) }
+        }
+    }
+
+    // The callback function invoked by Pdfium.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) extern "C" fn is_data_avail_from_callback(
+        file_avail_ext_ptr: *mut FxFileAvailExt,
+        offset: usize,
+        size: usize,
+    ) -> FPDF_BOOL {
+        // A panic must not unwind across the FFI boundary into Pdfium. If the fetcher panics,
+        // the range is reported as unavailable.
+
+        let is_available = catch_unwind(AssertUnwindSafe(|| unsafe {
+            (*file_avail_ext_ptr)
+                .fetcher
+                .borrow()
+                .is_range_available(offset as u64, size as u64)
+        }))
+        .unwrap_or(false);
+
+        if is_available {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// Returns a wrapped Pdfium `FX_DOWNLOADHINTS` struct that forwards the byte ranges
+    /// Pdfium asks to be downloaded to the given fetcher.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn get_pdfium_download_hints_from_fetcher<'a>(
+        fetcher: Rc<RefCell<dyn PdfRangeFetcher + 'a>>,
+    ) -> Box<FxDownloadHintsExt<'a>> {
+        Box::new(FxDownloadHintsExt {
+            version: 1,
+            add_segment: Some(add_segment_from_callback),
+            fetcher,
+        })
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[repr(C)]
+    pub(crate) struct FxDownloadHintsExt<'a> {
+        // An extension of Pdfium's FX_DOWNLOADHINTS struct that adds an extra field to carry the
+        // user-provided Rust fetcher.
+        version: c_int,
+        add_segment: Option<
+            unsafe extern "C" fn(
+                download_hints_ext_ptr: *mut FxDownloadHintsExt,
+                offset: usize,
+                size: usize,
+            ),
+        >,
+        fetcher: Rc<RefCell<dyn PdfRangeFetcher + 'a>>,
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    impl<'a> FxDownloadHintsExt<'a> {
+        /// Returns an `FX_DOWNLOADHINTS` pointer suitable for passing to `FPDFAvail_IsDocAvail()`,
+        /// `FPDFAvail_IsPageAvail()`, or `FPDFAvail_IsFormAvail()`.
+        #[inline]
+        pub(crate) fn as_fx_download_hints_mut_ptr(&mut self) -> &mut FX_DOWNLOADHINTS {
+            unsafe { &mut *(self as *mut FxDownloadHintsExt as *mut FX_DOWNLOADHINTS) }
+        }
+    }
+
+    // The callback function invoked by Pdfium.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) extern "C" fn add_segment_from_callback(
+        download_hints_ext_ptr: *mut FxDownloadHintsExt,
+        offset: usize,
+        size: usize,
+    ) {
+        // A panic must not unwind across the FFI boundary into Pdfium. If the fetcher panics,
+        // the request is dropped; Pdfium will report the range again on its next availability check.
+
+        let _ = catch_unwind(AssertUnwindSafe(|| unsafe {
+            (*download_hints_ext_ptr)
+                .fetcher
+                .borrow_mut()
+                .request_range(offset as u64, size as u64);
+        }));
+    }
+
     /// Returns a wrapped Pdfium `FPDF_FILEWRITE` struct that uses the given writer as an
     /// output source for Pdfium's file writing callback function.
     pub(crate) fn get_pdfium_file_writer_from_writer<W: Write + 'static>(